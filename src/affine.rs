@@ -0,0 +1,266 @@
+use core::fmt::Display;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::interval::{down, two_sum, up};
+use super::{Closed, Interval};
+
+/// Source of fresh noise symbols, shared by all affine forms
+static NEXT_SYMBOL: AtomicU64 = AtomicU64::new(0);
+
+fn fresh_symbol() -> u64 {
+    NEXT_SYMBOL.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Affine form `x0 + x1 ε1 + ... + xn εn`, each noise symbol `εi` ranging over `[-1, 1]`
+///
+/// Noise symbols are shared between the forms computed from the same inputs, so that correlated
+/// quantities partially cancel out: `x - x` is exactly zero, where interval arithmetic would
+/// return an interval twice as wide as `x`. Only multiplication introduces a fresh symbol, which
+/// bounds its non-linear part.
+///
+/// Conversions from and to intervals are rounded outward, so that the range of a form built
+/// from an interval contains it. Rounding errors of arithmetic operations are not accounted
+/// for.
+///
+/// # Example
+///
+/// ```
+/// use interval::{AffineForm, Interval, Closed};
+///
+/// let x = AffineForm::from_interval(Interval::new(Closed(1.), Closed(3.))).unwrap();
+/// let y = &x * (4. - &x);
+///
+/// assert_eq!((&x - &x).to_interval(), Interval::singleton(0.));
+/// // Exact range is [3, 4], interval arithmetic would give [1, 3] * [1, 3] = [1, 9]
+/// assert_eq!(y.to_interval(), Interval::new(Closed(3.), Closed(5.)));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct AffineForm {
+    center: f64,
+    /// Partial deviations, sorted by noise symbol, without zero deviations
+    deviations: Vec<(u64, f64)>,
+}
+
+impl AffineForm {
+    /// Form of an exact value
+    ///
+    pub fn constant(value: f64) -> Self {
+        AffineForm {
+            center: value,
+            deviations: Vec::new(),
+        }
+    }
+
+    /// Form ranging over given interval, with a fresh noise symbol
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty or unbounded.
+    ///
+    pub fn from_interval(interval: Interval) -> Option<Self> {
+        if !interval.is_bounded() {
+            return None;
+        }
+        let (inf, sup) = (interval.inf()?, interval.sup()?);
+        // center is rounded: radius reaches both endpoints from it, rounded up
+        let center = interval.midpoint()?;
+        let radius = up(two_sum(sup, -center)).max(up(two_sum(center, -inf)));
+        let mut form = AffineForm::constant(center);
+        if radius != 0. {
+            form.deviations.push((fresh_symbol(), radius));
+        }
+        Some(form)
+    }
+
+    /// Central value
+    ///
+    pub fn center(&self) -> f64 {
+        self.center
+    }
+
+    /// Total deviation: sum of absolute partial deviations, rounded up
+    ///
+    pub fn radius(&self) -> f64 {
+        self.deviations
+            .iter()
+            .fold(0., |sum, (_, dev)| up(two_sum(sum, dev.abs())))
+    }
+
+    /// Range of form, as the closed interval `[center - radius, center + radius]` rounded
+    /// outward
+    ///
+    pub fn to_interval(&self) -> Interval {
+        let radius = self.radius();
+        Interval::new(
+            Closed(down(two_sum(self.center, -radius))),
+            Closed(up(two_sum(self.center, radius))),
+        )
+    }
+
+    /// Linear combination `a * self + b * other`
+    fn combine(&self, a: f64, other: &AffineForm, b: f64) -> AffineForm {
+        let (x, y) = (&self.deviations, &other.deviations);
+        let mut deviations = Vec::with_capacity(x.len() + y.len());
+        let (mut i, mut j) = (0, 0);
+        while i < x.len() || j < y.len() {
+            let (symbol, dev) = match (x.get(i), y.get(j)) {
+                (Some(&(s, dx)), Some(&(t, dy))) if s == t => {
+                    i += 1;
+                    j += 1;
+                    (s, a * dx + b * dy)
+                }
+                (Some(&(s, dx)), Some(&(t, _))) if s < t => {
+                    i += 1;
+                    (s, a * dx)
+                }
+                (Some(&(s, dx)), None) => {
+                    i += 1;
+                    (s, a * dx)
+                }
+                (_, Some(&(t, dy))) => {
+                    j += 1;
+                    (t, b * dy)
+                }
+                (None, None) => unreachable!(),
+            };
+            if dev != 0. {
+                deviations.push((symbol, dev));
+            }
+        }
+        AffineForm {
+            center: a * self.center + b * other.center,
+            deviations,
+        }
+    }
+
+    fn product(&self, other: &AffineForm) -> AffineForm {
+        let mut res = self.combine(other.center, other, self.center);
+        res.center = self.center * other.center;
+        let error = self.radius() * other.radius();
+        if error != 0. {
+            res.deviations.push((fresh_symbol(), error));
+        }
+        res
+    }
+
+    fn scale(&self, k: f64) -> AffineForm {
+        self.combine(k, &AffineForm::constant(0.), 0.)
+    }
+}
+
+impl From<f64> for AffineForm {
+    fn from(value: f64) -> Self {
+        AffineForm::constant(value)
+    }
+}
+
+impl From<&AffineForm> for Interval {
+    fn from(form: &AffineForm) -> Self {
+        form.to_interval()
+    }
+}
+
+impl Display for AffineForm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:.2}", self.center)?;
+        for (symbol, dev) in &self.deviations {
+            write!(f, " {:+.2}ε{symbol}", dev)?;
+        }
+        Ok(())
+    }
+}
+
+impl_op_ex!(+|a: &AffineForm, b: &AffineForm| -> AffineForm { a.combine(1., b, 1.) });
+impl_op_ex!(-|a: &AffineForm, b: &AffineForm| -> AffineForm { a.combine(1., b, -1.) });
+impl_op_ex!(*|a: &AffineForm, b: &AffineForm| -> AffineForm { a.product(b) });
+impl_op_ex!(-|a: &AffineForm| -> AffineForm { a.scale(-1.) });
+
+impl_op_ex_commutative!(+|a: &AffineForm, k: f64| -> AffineForm {
+    AffineForm {
+        center: a.center + k,
+        deviations: a.deviations.clone(),
+    }
+});
+impl_op_ex!(-|a: &AffineForm, k: f64| -> AffineForm {
+    AffineForm {
+        center: a.center - k,
+        deviations: a.deviations.clone(),
+    }
+});
+impl_op_ex!(-|k: f64, a: &AffineForm| -> AffineForm { k + a.scale(-1.) });
+impl_op_ex_commutative!(*|a: &AffineForm, k: f64| -> AffineForm { a.scale(k) });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Open, Unbound, EMPTY};
+
+    #[test]
+    fn test_from_interval_1() {
+        assert_eq!(AffineForm::from_interval(EMPTY), None);
+        assert_eq!(
+            AffineForm::from_interval(Interval::new(Closed(0.), Unbound)),
+            None
+        );
+        let a = AffineForm::from_interval(Interval::new(Open(0.), Closed(2.))).unwrap();
+        assert_eq!((a.center(), a.radius()), (1., 1.));
+        assert_eq!(a.to_interval(), Interval::new(Closed(0.), Closed(2.)));
+        let b = AffineForm::from_interval(Interval::singleton(4.)).unwrap();
+        assert_eq!(b, AffineForm::constant(4.));
+    }
+
+    #[test]
+    fn test_from_interval_2() {
+        // Rounded center and radius still enclose ulp-wide intervals
+        for k in [0., 1., 0.1, -3., 1e300, -1e308, 5e-324] {
+            for a in [
+                Interval::new(Closed(k), Closed(k.next_up())),
+                Interval::new(Closed(k.next_down()), Closed(k)),
+                Interval::new(Closed(k.next_down()), Closed(k.next_up())),
+            ] {
+                let range = AffineForm::from_interval(a).unwrap().to_interval();
+                assert_eq!(range.hull(a), range, "{a}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_1() {
+        let x = AffineForm::from_interval(Interval::new(Closed(0.), Closed(2.))).unwrap();
+        let y = AffineForm::from_interval(Interval::new(Closed(10.), Closed(20.))).unwrap();
+        assert_eq!(
+            (&x + &y).to_interval(),
+            Interval::new(Closed(10.), Closed(22.))
+        );
+        assert_eq!(
+            (&x + &y - &y).to_interval(),
+            Interval::new(Closed(0.), Closed(2.))
+        );
+        assert_eq!(
+            (2. * &x - &x + 1.).to_interval(),
+            Interval::new(Closed(1.), Closed(3.))
+        );
+        assert_eq!(-&x + &x, AffineForm::constant(0.));
+    }
+
+    #[test]
+    fn test_mul_1() {
+        let x = AffineForm::from_interval(Interval::new(Closed(-1.), Closed(1.))).unwrap();
+        let y = AffineForm::from_interval(Interval::new(Closed(2.), Closed(4.))).unwrap();
+        assert_eq!(
+            (&x * &y).to_interval(),
+            Interval::new(Closed(-4.), Closed(4.))
+        );
+        // x² is over-estimated as [-1, 1] (interval arithmetic gives the same), but
+        // x² - x² is exact
+        let square = &x * &x;
+        assert_eq!((&square - &square).to_interval(), Interval::singleton(0.));
+        assert_eq!(
+            (&y * 0.5).to_interval(),
+            Interval::new(Closed(1.), Closed(2.))
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use super::{math, Closed, Interval, IntervalSet, Open, EMPTY, INFINITY};
+
+/// Arc of a circle of circumference `period`, such as a range of angles or of hours in a day
+///
+/// Points are taken modulo the period, so that an arc may wrap past the period boundary:
+/// `[22, 26)` with a period of `24` stands for the hours from 22:00 to 02:00. An arc is stored as
+/// a representative interval whose infimum lies in `[0, period)`, its supremum being possibly
+/// greater than `period`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{CircularInterval, Interval, Closed, Open};
+///
+/// let night = CircularInterval::new(Interval::new(Closed(22.), Open(30.)), 24.);
+/// let early = CircularInterval::new(Interval::new(Closed(-19.), Open(-17.)), 24.);
+///
+/// assert!(night.contains(23.) && night.contains(1.) && night.contains(-19.));
+/// assert!(!night.contains(12.));
+/// assert_eq!(early.arc(), Interval::new(Closed(5.), Open(7.)));
+/// assert_eq!(
+///     night.intersection(&early),
+///     vec![CircularInterval::new(Interval::new(Closed(5.), Open(6.)), 24.)]
+/// );
+/// assert_eq!(
+///     night.union(&early),
+///     vec![CircularInterval::new(Interval::new(Closed(22.), Open(31.)), 24.)]
+/// );
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircularInterval {
+    arc: Interval,
+    period: f64,
+}
+
+impl CircularInterval {
+    /// Arc made of the points of `interval` taken modulo `period`
+    ///
+    /// Unbounded intervals, and intervals wider than the period, cover the full circle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not finite and strictly positive.
+    ///
+    pub fn new(interval: Interval, period: f64) -> Self {
+        assert!(
+            period.is_finite() && period > 0.,
+            "period must be finite and strictly positive"
+        );
+        if interval.is_empty() {
+            return CircularInterval { arc: EMPTY, period };
+        }
+        let width = interval.width();
+        let wraps_fully = width > period
+            || (width == period && (interval.is_left_closed() || interval.is_right_closed()));
+        if wraps_fully {
+            let arc = Interval::new(Closed(0.), Open(period));
+            return CircularInterval { arc, period };
+        }
+
+        let inf = interval.inf().unwrap_or(0.);
+        let mut arc = interval.translate(-math::floor(inf / period) * period);
+        match arc.inf() {
+            Some(inf) if inf >= period => arc = arc.translate(-period),
+            Some(inf) if inf < 0. => arc = arc.translate(period),
+            _ => (),
+        }
+        CircularInterval { arc, period }
+    }
+
+    /// Full circle, represented as `[0, period)`
+    ///
+    pub fn full(period: f64) -> Self {
+        CircularInterval::new(INFINITY, period)
+    }
+
+    /// Representative interval of arc, with its infimum in `[0, period)`
+    ///
+    pub fn arc(&self) -> Interval {
+        self.arc
+    }
+
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.arc.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        *self == CircularInterval::full(self.period)
+    }
+
+    /// Check if arc contains `x` modulo the period
+    ///
+    pub fn contains(&self, x: f64) -> bool {
+        let mut y = x % self.period;
+        if y < 0. {
+            y += self.period;
+        }
+        if y >= self.period {
+            y = 0.;
+        }
+        self.arc.contains(y) || self.arc.contains(y + self.period)
+    }
+
+    /// Union of two arcs of the same circle
+    ///
+    /// # Returns
+    ///
+    /// Disjoint arcs, sorted by infimum of their representatives.
+    ///
+    /// # Panics
+    ///
+    /// Panics if arcs have distinct periods.
+    ///
+    pub fn union(&self, other: &CircularInterval) -> Vec<CircularInterval> {
+        self.assert_same_period(other);
+        self.arcs_of(self.pieces().union_intervals(&other.pieces()))
+    }
+
+    /// Intersection of two arcs of the same circle, which may be made of two arcs
+    ///
+    /// # Returns
+    ///
+    /// Disjoint arcs, sorted by infimum of their representatives.
+    ///
+    /// # Panics
+    ///
+    /// Panics if arcs have distinct periods.
+    ///
+    pub fn intersection(&self, other: &CircularInterval) -> Vec<CircularInterval> {
+        self.assert_same_period(other);
+        self.arcs_of(self.pieces().intersection(&other.pieces()))
+    }
+
+    fn assert_same_period(&self, other: &CircularInterval) {
+        assert!(
+            self.period == other.period,
+            "arcs must share the same period"
+        );
+    }
+
+    /// Points of arc as a subset of `[0, period)`
+    pub(crate) fn pieces(&self) -> IntervalSet {
+        let window = Interval::new(Closed(0.), Open(self.period));
+        IntervalSet::from(&[
+            self.arc.intersection(window),
+            self.arc.translate(-self.period).intersection(window),
+        ])
+    }
+
+    /// Arcs made of a subset of `[0, period)`, joining the pieces touching both ends of window
+    fn arcs_of(&self, pieces: IntervalSet) -> Vec<CircularInterval> {
+        let period = self.period;
+        let mut arcs: Vec<Interval> = pieces.iter().collect();
+        let wraps = match (arcs.first(), arcs.last()) {
+            (Some(first), Some(last)) if arcs.len() > 1 => {
+                first.left() == Closed(0.) && last.right() == Open(period)
+            }
+            _ => false,
+        };
+        if wraps {
+            let first = arcs.remove(0);
+            if let Some(last) = arcs.last_mut() {
+                *last = Interval::new(last.left(), first.translate(period).right());
+            }
+        }
+        arcs.into_iter()
+            .map(|arc| CircularInterval::new(arc, period))
+            .collect()
+    }
+}
+
+impl Display for CircularInterval {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} mod {}", self.arc, self.period)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Unbound;
+
+    fn hours(a: f64, b: f64) -> CircularInterval {
+        CircularInterval::new(Interval::new(Closed(a), Open(b)), 24.)
+    }
+
+    #[test]
+    fn test_new_1() {
+        assert!(CircularInterval::new(EMPTY, 24.).is_empty());
+        assert!(hours(0., 24.).is_full());
+        assert!(hours(-5., 30.).is_full());
+        assert!(CircularInterval::new(Interval::new(Closed(3.), Unbound), 24.).is_full());
+        let punctured = CircularInterval::new(Interval::new(Open(1.), Open(25.)), 24.);
+        assert!(!punctured.is_full());
+        assert!(!punctured.contains(1.) && punctured.contains(0.5) && punctured.contains(2.));
+        assert_eq!(hours(46., 50.).arc(), Interval::new(Closed(22.), Open(26.)));
+    }
+
+    #[test]
+    fn test_contains_1() {
+        let a = hours(22., 26.);
+        assert!(a.contains(22.) && a.contains(0.) && a.contains(-0.5) && a.contains(48.));
+        assert!(!a.contains(2.) && !a.contains(26.) && !a.contains(f64::NAN));
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        let a = hours(20., 28.);
+        let b = hours(3., 21.);
+        assert_eq!(a.intersection(&b), vec![hours(3., 4.), hours(20., 21.)]);
+        assert_eq!(a.intersection(&hours(5., 6.)), vec![]);
+        assert_eq!(a.intersection(&hours(0., 24.)), vec![a]);
+    }
+
+    #[test]
+    fn test_union_1() {
+        let a = hours(20., 28.);
+        assert_eq!(a.union(&hours(3., 21.)), vec![hours(0., 24.)]);
+        assert_eq!(a.union(&hours(5., 6.)), vec![hours(5., 6.), a]);
+        assert_eq!(a.union(&hours(23., 25.)), vec![a]);
+        assert_eq!(hours(0., 2.).union(&hours(22., 24.)), vec![hours(22., 26.)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_union_2() {
+        hours(0., 1.).union(&CircularInterval::new(Interval::singleton(0.), 12.));
+    }
+}
@@ -0,0 +1,107 @@
+use core::iter::Peekable;
+
+use super::Interval;
+
+/// Iterator adapter merging a stream of intervals sorted by left bound
+///
+/// Overlapping or adjacent intervals are merged lazily, so that each yielded interval is
+/// maximal, and yielded intervals are sorted and disjoint. Empty intervals are skipped. Only one
+/// interval is buffered, so that huge inputs can be normalized on the fly.
+///
+/// If input is not sorted by left bound, yielded intervals are still correct unions of
+/// consecutive inputs, but may overlap each other.
+///
+/// # Example
+///
+/// ```
+/// use interval::{CoalesceIntervals, Interval, Closed, Open};
+///
+/// let sorted = [
+///     Interval::new(Closed(0.), Open(1.)),
+///     Interval::new(Closed(1.), Closed(2.)),
+///     Interval::new(Open(3.), Closed(4.)),
+///     Interval::new(Closed(3.5), Closed(5.)),
+/// ];
+/// let merged: Vec<Interval> = CoalesceIntervals::new(sorted).collect();
+///
+/// assert_eq!(
+///     merged,
+///     vec![
+///         Interval::new(Closed(0.), Closed(2.)),
+///         Interval::new(Open(3.), Closed(5.)),
+///     ]
+/// );
+/// ```
+///
+pub struct CoalesceIntervals<I: Iterator<Item = Interval>> {
+    inner: Peekable<I>,
+}
+
+impl<I: Iterator<Item = Interval>> CoalesceIntervals<I> {
+    pub fn new(intervals: impl IntoIterator<Item = Interval, IntoIter = I>) -> Self {
+        CoalesceIntervals {
+            inner: intervals.into_iter().peekable(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = Interval>> Iterator for CoalesceIntervals<I> {
+    type Item = Interval;
+
+    fn next(&mut self) -> Option<Interval> {
+        let mut current = self.inner.find(|segment| !segment.is_empty())?;
+        while let Some(next) = self.inner.peek() {
+            match current.union(*next) {
+                (merged, None) => {
+                    current = merged;
+                    self.inner.next();
+                }
+                (_, Some(_)) => break,
+            }
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_coalesce_1() {
+        let merged: Vec<Interval> = CoalesceIntervals::new(Vec::new()).collect();
+        assert!(merged.is_empty());
+        let merged: Vec<Interval> = CoalesceIntervals::new([EMPTY, EMPTY]).collect();
+        assert!(merged.is_empty());
+    }
+
+    #[test]
+    fn test_coalesce_2() {
+        let sorted = [
+            Interval::new(Unbound, Open(0.)),
+            EMPTY,
+            Interval::new(Open(0.), Closed(1.)),
+            Interval::singleton(1.),
+            Interval::new(Open(1.), Open(2.)),
+            Interval::new(Open(2.), Unbound),
+        ];
+        let merged: Vec<Interval> = CoalesceIntervals::new(sorted).collect();
+        assert_eq!(
+            merged,
+            vec![
+                Interval::new(Unbound, Open(0.)),
+                Interval::new(Open(0.), Open(2.)),
+                Interval::new(Open(2.), Unbound),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_3() {
+        let sorted = (0..).map(|i| Interval::new(Closed(i as f64), Open(i as f64 + 1.)));
+        let mut merged = CoalesceIntervals::new(sorted.take(1000).chain([INFINITY]));
+        assert_eq!(merged.next(), Some(INFINITY));
+        assert_eq!(merged.next(), None);
+    }
+}
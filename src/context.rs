@@ -0,0 +1,246 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use super::IntervalSet;
+use super::{Bound, Closed, Interval, Open, Unbound, EMPTY};
+
+/// Tolerance for deciding emptiness, adjacency and equality of intervals
+///
+/// Exact rules are too strict for measured data: `[0,1)` and `[1.0000000001,2]` leave a hole,
+/// and intervals computed from jittery timestamps seldom touch exactly. Within a context, gaps
+/// narrower than `epsilon` are bridged, intervals narrower than `epsilon` are empty, and
+/// endpoints closer than `epsilon` are equal. A zero `epsilon` (the default) gives back exact
+/// rules.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalContext, Closed, Open};
+///
+/// let ctx = IntervalContext::new(1e-6);
+/// let a = Interval::new(Closed(0.), Open(1.));
+/// let b = Interval::new(Closed(1. + 1e-9), Closed(2.));
+///
+/// assert_eq!(a.union(b).1, Some(b));
+/// assert_eq!(ctx.union(a, b), (Interval::new(Closed(0.), Closed(2.)), None));
+/// assert!(ctx.eq(&a, &Interval::new(Closed(1e-9), Closed(1.))));
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct IntervalContext {
+    epsilon: f64,
+}
+
+/// Check if bounds are equal, or have values closer than `epsilon` whatever their kinds
+fn bounds_eq(a: Bound, b: Bound, epsilon: f64) -> bool {
+    match (a, b) {
+        (Closed(x) | Open(x), Closed(y) | Open(y)) => a == b || (x - y).abs() < epsilon,
+        (Unbound, Unbound) => true,
+        _ => false,
+    }
+}
+
+impl IntervalContext {
+    /// Context of given tolerance
+    ///
+    /// # Panics
+    ///
+    /// If `epsilon` is negative or `NaN`.
+    ///
+    pub fn new(epsilon: f64) -> Self {
+        assert!(epsilon >= 0., "epsilon must be non-negative");
+        IntervalContext { epsilon }
+    }
+
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    /// Check if interval is empty, or narrower than `epsilon`
+    ///
+    /// Note that singletons are empty as soon as `epsilon` is positive.
+    ///
+    pub fn is_empty(&self, interval: &Interval) -> bool {
+        interval.is_empty() || interval.width() < self.epsilon
+    }
+
+    /// Check if intervals can be merged: they overlap or are adjacent, or the gap between them
+    /// is narrower than `epsilon`
+    ///
+    pub fn is_adjacent(&self, a: &Interval, b: &Interval) -> bool {
+        a.union(*b).1.is_none() || a.distance(b) < self.epsilon
+    }
+
+    /// Check if intervals are both empty, or have matching endpoints
+    ///
+    /// Endpoints match if they are equal, or if their values are closer than `epsilon` whatever
+    /// their kinds (open or closed).
+    ///
+    pub fn eq(&self, a: &Interval, b: &Interval) -> bool {
+        match (self.is_empty(a), self.is_empty(b)) {
+            (true, true) => true,
+            (false, false) => {
+                bounds_eq(a.left(), b.left(), self.epsilon)
+                    && bounds_eq(a.right(), b.right(), self.epsilon)
+            }
+            _ => false,
+        }
+    }
+
+    /// Union of two intervals, as `Interval::union`, bridging gaps narrower than `epsilon`
+    ///
+    pub fn union(&self, a: Interval, b: Interval) -> (Interval, Option<Interval>) {
+        match a.union(b) {
+            (lower, Some(upper)) if lower.distance(&upper) < self.epsilon => {
+                (lower.hull(upper), None)
+            }
+            res => res,
+        }
+    }
+
+    /// Intersection of two intervals, `EMPTY` if narrower than `epsilon`
+    ///
+    pub fn intersection(&self, a: Interval, b: Interval) -> Interval {
+        let res = a.intersection(b);
+        if self.is_empty(&res) {
+            return EMPTY;
+        }
+        res
+    }
+
+    /// Union of any number of intervals, bridging gaps narrower than `epsilon`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalContext, IntervalSet, Closed};
+    ///
+    /// let ctx = IntervalContext::new(0.01);
+    /// let samples = [
+    ///     Interval::new(Closed(0.), Closed(0.999)),
+    ///     Interval::new(Closed(1.001), Closed(2.)),
+    ///     Interval::new(Closed(3.), Closed(4.)),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     ctx.union_all(samples),
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Closed(2.)),
+    ///         Interval::new(Closed(3.), Closed(4.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn union_all(&self, intervals: impl IntoIterator<Item = Interval>) -> IntervalSet {
+        let mut merged: Vec<Interval> = Vec::new();
+        for segment in IntervalSet::union_all(intervals).iter() {
+            match merged.last_mut() {
+                Some(last) if last.distance(&segment) < self.epsilon => {
+                    *last = last.hull(segment);
+                }
+                _ => merged.push(segment),
+            }
+        }
+        IntervalSet::from(merged.as_slice())
+    }
+
+    /// Check if sets are equal up to `epsilon`
+    ///
+    /// Gaps narrower than `epsilon` are bridged and components narrower than `epsilon` are
+    /// dropped, then remaining components are compared as by `IntervalContext::eq`.
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn set_eq(&self, a: &IntervalSet, b: &IntervalSet) -> bool {
+        let (a, b) = (self.union_all(a.iter()), self.union_all(b.iter()));
+        let mut a = a.iter().filter(|segment| !self.is_empty(segment));
+        let mut b = b.iter().filter(|segment| !self.is_empty(segment));
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => return true,
+                (Some(x), Some(y)) if self.eq(&x, &y) => {}
+                _ => return false,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::INFINITY;
+
+    #[test]
+    fn test_exact_1() {
+        let ctx = IntervalContext::default();
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Open(1.), Closed(2.));
+        assert_eq!(ctx.union(a, b), a.union(b));
+        assert!(!ctx.is_adjacent(&a, &b));
+        assert!(!ctx.is_empty(&Interval::singleton(1.)));
+        assert!(ctx.eq(&a, &a) && !ctx.eq(&a, &Interval::new(Closed(0.), Closed(1.))));
+    }
+
+    #[test]
+    fn test_union_1() {
+        let ctx = IntervalContext::new(0.1);
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Open(1.05), Unbound);
+        assert!(ctx.is_adjacent(&a, &b) && ctx.is_adjacent(&b, &a));
+        assert_eq!(ctx.union(b, a), (Interval::new(Closed(0.), Unbound), None));
+        let c = Interval::new(Closed(1.1), Closed(2.));
+        assert_eq!(ctx.union(a, c), (a, Some(c)));
+        assert_eq!(ctx.union(EMPTY, c), (c, None));
+        assert_eq!(ctx.union(INFINITY, c), (INFINITY, None));
+    }
+
+    #[test]
+    fn test_empty_1() {
+        let ctx = IntervalContext::new(0.1);
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let b = Interval::new(Closed(0.95), Unbound);
+        assert!(ctx.is_empty(&Interval::singleton(1.)) && ctx.is_empty(&EMPTY));
+        assert_eq!(ctx.intersection(a, b), EMPTY);
+        assert_eq!(ctx.intersection(a, INFINITY), a);
+        assert!(ctx.eq(&Interval::new(Open(3.), Open(3.05)), &EMPTY));
+    }
+
+    #[test]
+    fn test_eq_1() {
+        let ctx = IntervalContext::new(0.1);
+        let a = Interval::new(Closed(0.), Open(1.));
+        assert!(ctx.eq(&a, &Interval::new(Open(0.05), Closed(0.95))));
+        assert!(!ctx.eq(&a, &Interval::new(Closed(0.), Closed(1.1))));
+        assert!(!ctx.eq(&a, &Interval::new(Unbound, Open(1.))));
+        assert!(ctx.eq(&INFINITY, &INFINITY));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_set_eq_1() {
+        let ctx = IntervalContext::new(0.1);
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(1.05), Closed(2.)),
+            Interval::singleton(5.),
+        ]);
+        let b = IntervalSet::from(&[Interval::new(Open(0.01), Open(2.))]);
+        assert!(ctx.set_eq(&a, &b) && ctx.set_eq(&b, &a));
+        assert!(ctx.set_eq(
+            &IntervalSet::from(&[Interval::singleton(1.)]),
+            &IntervalSet::new()
+        ));
+        assert!(!ctx.set_eq(
+            &a,
+            &IntervalSet::from(&[Interval::new(Closed(0.), Closed(3.))])
+        ));
+        assert!(!IntervalContext::default().set_eq(&a, &b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_1() {
+        IntervalContext::new(f64::NAN);
+    }
+}
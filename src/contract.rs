@@ -0,0 +1,547 @@
+//! Interval constraint propagation
+//!
+//! A `Network` holds variables, each one with an interval domain, and constraints of the form
+//! `term ∈ range`. Contracting the network narrows the domains by removing values which cannot
+//! satisfy a constraint, without ever removing a solution.
+//!
+//! Each constraint is revised HC4-style: a forward pass evaluates its term bottom-up in interval
+//! arithmetic, then a backward pass intersects the root with `range` and projects it top-down
+//! onto the operands of each operation, down to the variables. Constraints are revised again
+//! whenever one of their variables is narrowed, until a fixpoint is reached.
+//!
+//! # Example
+//!
+//! ```
+//! use interval::{Interval, Closed, Unbound};
+//! use interval::contract::{Network, Term};
+//!
+//! let (x, y) = (Term::var("x"), Term::var("y"));
+//! let mut network = Network::new();
+//! network.add_variable("x", Interval::new(Closed(0.), Closed(10.)));
+//! network.add_variable("y", Interval::new(Closed(0.), Closed(10.)));
+//! network.add_constraint((&x + &y).within(Interval::singleton(10.)));
+//! network.add_constraint((&y - &x).within(Interval::new(Closed(6.), Unbound)));
+//!
+//! assert!(network.contract());
+//! // Solutions have x ∈ [0, 2]: narrowing is sound, but local to each constraint
+//! assert_eq!(network.domain("x"), Some(Interval::new(Closed(0.), Closed(4.))));
+//! assert_eq!(network.domain("y"), Some(Interval::new(Closed(6.), Closed(10.))));
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::{Interval, INFINITY};
+
+/// Domains narrowed by less than this fraction of their width do not trigger new revisions
+const MIN_PROGRESS: f64 = 1e-9;
+/// Revisions after which `Network::contract` gives up, domains being valid but not narrowest
+const MAX_REVISIONS: usize = 100_000;
+
+/// Elementary function of a term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Func {
+    Sqrt,
+    Exp,
+    Ln,
+    Sin,
+    Cos,
+    Abs,
+}
+
+impl Func {
+    pub(crate) const ALL: [Func; 6] = [
+        Func::Sqrt,
+        Func::Exp,
+        Func::Ln,
+        Func::Sin,
+        Func::Cos,
+        Func::Abs,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Func::Sqrt => "sqrt",
+            Func::Exp => "exp",
+            Func::Ln => "ln",
+            Func::Sin => "sin",
+            Func::Cos => "cos",
+            Func::Abs => "abs",
+        }
+    }
+
+    pub(crate) fn apply(self, x: Interval) -> Interval {
+        match self {
+            Func::Sqrt => x.sqrt(),
+            Func::Exp => x.exp(),
+            Func::Ln => x.ln(),
+            Func::Sin => x.sin(),
+            Func::Cos => x.cos(),
+            Func::Abs => x.abs(),
+        }
+    }
+
+    /// Values of argument `a` whose image lies in `n`, or `a` when no narrowing is known
+    fn inverse_within(self, n: Interval, a: Interval) -> Interval {
+        match self {
+            Func::Sqrt => a.intersection(n.sqr()),
+            Func::Exp => a.intersection(n.ln()),
+            Func::Ln => a.intersection(n.exp()),
+            Func::Sin | Func::Cos | Func::Abs => a,
+        }
+    }
+}
+
+/// Expression tree of a term, shared with `expr::Expr`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Tree {
+    Var(String),
+    Const(f64),
+    Add(Box<Tree>, Box<Tree>),
+    Sub(Box<Tree>, Box<Tree>),
+    Mul(Box<Tree>, Box<Tree>),
+    Div(Box<Tree>, Box<Tree>),
+    Neg(Box<Tree>),
+    Sqr(Box<Tree>),
+    Pow(Box<Tree>, i32),
+    Call(Func, Box<Tree>),
+}
+
+/// Arithmetic term over named variables, built from `Term::var`, `Term::constant`, the
+/// `+`, `-`, `*`, `/` operators and `Term::sqr`, or converted from an `expr::Expr`
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Term(pub(crate) Tree);
+
+impl Term {
+    pub fn var(name: &str) -> Self {
+        Term(Tree::Var(name.to_string()))
+    }
+
+    pub fn constant(k: f64) -> Self {
+        Term(Tree::Const(k))
+    }
+
+    /// Square of term, tighter than `self * self` (see `Interval::sqr`)
+    ///
+    pub fn sqr(&self) -> Self {
+        Term(Tree::Sqr(Box::new(self.0.clone())))
+    }
+
+    /// Constraint that term takes its values in `range`
+    ///
+    pub fn within(self, range: Interval) -> Constraint {
+        Constraint { term: self, range }
+    }
+}
+
+impl From<f64> for Term {
+    fn from(k: f64) -> Self {
+        Term::constant(k)
+    }
+}
+
+/// Fully parenthesized notation, which `expr::Expr` parses back to an equivalent term as long
+/// as constants are finite
+impl Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn write_tree(f: &mut std::fmt::Formatter<'_>, tree: &Tree) -> std::fmt::Result {
+            let mut binary = |a: &Tree, op: &str, b: &Tree| {
+                write!(f, "(")?;
+                write_tree(f, a)?;
+                write!(f, " {op} ")?;
+                write_tree(f, b)?;
+                write!(f, ")")
+            };
+            match tree {
+                Tree::Var(name) => write!(f, "{name}"),
+                Tree::Const(k) if *k < 0. => write!(f, "({k})"),
+                Tree::Const(k) => write!(f, "{k}"),
+                Tree::Neg(a) => {
+                    write!(f, "(-")?;
+                    write_tree(f, a)?;
+                    write!(f, ")")
+                }
+                Tree::Add(a, b) => binary(a, "+", b),
+                Tree::Sub(a, b) => binary(a, "-", b),
+                Tree::Mul(a, b) => binary(a, "*", b),
+                Tree::Div(a, b) => binary(a, "/", b),
+                Tree::Sqr(a) | Tree::Pow(a, _) if matches!(**a, Tree::Sqr(_) | Tree::Pow(..)) => {
+                    write!(f, "(")?;
+                    write_tree(f, a)?;
+                    match tree {
+                        Tree::Pow(_, n) => write!(f, ")^{n}"),
+                        _ => write!(f, ")^2"),
+                    }
+                }
+                Tree::Sqr(a) => {
+                    write_tree(f, a)?;
+                    write!(f, "^2")
+                }
+                Tree::Pow(a, n) => {
+                    write_tree(f, a)?;
+                    write!(f, "^{n}")
+                }
+                Tree::Call(func, a) => {
+                    write!(f, "{}(", func.name())?;
+                    write_tree(f, a)?;
+                    write!(f, ")")
+                }
+            }
+        }
+        write_tree(f, &self.0)
+    }
+}
+
+fn boxed(term: &Term) -> Box<Tree> {
+    Box::new(term.0.clone())
+}
+
+impl_op_ex!(+|a: &Term, b: &Term| -> Term { Term(Tree::Add(boxed(a), boxed(b))) });
+impl_op_ex!(-|a: &Term, b: &Term| -> Term { Term(Tree::Sub(boxed(a), boxed(b))) });
+impl_op_ex!(*|a: &Term, b: &Term| -> Term { Term(Tree::Mul(boxed(a), boxed(b))) });
+impl_op_ex!(/|a: &Term, b: &Term| -> Term { Term(Tree::Div(boxed(a), boxed(b))) });
+impl_op_ex!(-|a: &Term| -> Term { Term(Tree::Neg(boxed(a))) });
+
+impl_op_ex_commutative!(+|a: &Term, k: f64| -> Term { a + Term::constant(k) });
+impl_op_ex!(-|a: &Term, k: f64| -> Term { a - Term::constant(k) });
+impl_op_ex!(-|k: f64, a: &Term| -> Term { Term::constant(k) - a });
+impl_op_ex_commutative!(*|a: &Term, k: f64| -> Term { a * Term::constant(k) });
+impl_op_ex!(/|a: &Term, k: f64| -> Term { a / Term::constant(k) });
+impl_op_ex!(/|k: f64, a: &Term| -> Term { Term::constant(k) / a });
+
+/// Constraint `term ∈ range`
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    term: Term,
+    range: Interval,
+}
+
+impl Constraint {
+    pub fn new(term: Term, range: Interval) -> Self {
+        Constraint { term, range }
+    }
+
+    pub fn term(&self) -> &Term {
+        &self.term
+    }
+
+    pub fn range(&self) -> Interval {
+        self.range
+    }
+}
+
+/// Operation of a compiled term, operands being given by their index in the node list
+#[derive(Debug, Clone, Copy)]
+enum Node {
+    Var(usize),
+    Const(f64),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Div(usize, usize),
+    Neg(usize),
+    Sqr(usize),
+    Pow(usize, i32),
+    Call(Func, usize),
+}
+
+/// Constraint compiled to a list of nodes in post-order, the root coming last
+#[derive(Debug, Clone)]
+struct Compiled {
+    nodes: Vec<Node>,
+    range: Interval,
+    vars: Vec<usize>,
+}
+
+/// Values of `a` whose square lies in `n`
+fn sqrt_within(n: Interval, a: Interval) -> Interval {
+    let root = n.sqrt();
+    a.intersection(root).hull(a.intersection(-root))
+}
+
+impl Compiled {
+    /// Revise constraint, narrowing `domains`
+    ///
+    /// Returns `false` if constraint cannot be satisfied.
+    fn revise(&self, domains: &mut [Interval]) -> bool {
+        let mut values: Vec<Interval> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let value = match *node {
+                Node::Var(i) => domains[i],
+                Node::Const(k) => Interval::singleton(k),
+                Node::Add(a, b) => values[a] + values[b],
+                Node::Sub(a, b) => values[a] - values[b],
+                Node::Mul(a, b) => values[a] * values[b],
+                Node::Div(a, b) => values[a].quotient(&values[b]),
+                Node::Neg(a) => -values[a],
+                Node::Sqr(a) => values[a].sqr(),
+                Node::Pow(a, n) => values[a].powi(n),
+                Node::Call(func, a) => func.apply(values[a]),
+            };
+            values.push(value);
+        }
+
+        let root = self.nodes.len() - 1;
+        values[root] = values[root].intersection(self.range);
+        for (j, node) in self.nodes.iter().enumerate().rev() {
+            let value = values[j];
+            if value.is_empty() {
+                return false;
+            }
+            match *node {
+                Node::Var(i) => domains[i] = domains[i].intersection(value),
+                Node::Const(_) => (),
+                Node::Add(a, b) => {
+                    values[a] = values[a].intersection(value - values[b]);
+                    values[b] = values[b].intersection(value - values[a]);
+                }
+                Node::Sub(a, b) => {
+                    values[a] = values[a].intersection(value + values[b]);
+                    values[b] = values[b].intersection(values[a] - value);
+                }
+                Node::Mul(a, b) => {
+                    values[a] = values[a].intersection(value.quotient(&values[b]));
+                    values[b] = values[b].intersection(value.quotient(&values[a]));
+                }
+                Node::Div(a, b) => {
+                    values[a] = values[a].intersection(value * values[b]);
+                    values[b] = values[b].intersection(values[a].quotient(&value));
+                }
+                Node::Neg(a) => values[a] = values[a].intersection(-value),
+                Node::Sqr(a) | Node::Pow(a, 2) => values[a] = sqrt_within(value, values[a]),
+                // other powers are not inverted: operand is left as is, which is sound
+                Node::Pow(..) => (),
+                Node::Call(func, a) => values[a] = func.inverse_within(value, values[a]),
+            }
+        }
+        self.vars.iter().all(|&i| !domains[i].is_empty())
+    }
+}
+
+/// Network of variables with interval domains, linked by constraints
+///
+#[derive(Debug, Clone, Default)]
+pub struct Network {
+    names: Vec<String>,
+    domains: Vec<Interval>,
+    constraints: Vec<Compiled>,
+}
+
+impl Network {
+    pub fn new() -> Self {
+        Network::default()
+    }
+
+    /// Declare variable `name` with given domain, or replace the domain of an existing variable
+    ///
+    pub fn add_variable(&mut self, name: &str, domain: Interval) {
+        let i = self.index(name);
+        self.domains[i] = domain;
+    }
+
+    /// Register constraint
+    ///
+    /// Variables of the constraint which have not been declared get the domain `INFINITY`.
+    ///
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        let mut nodes = Vec::new();
+        self.compile(&constraint.term.0, &mut nodes);
+        let mut vars: Vec<usize> = nodes
+            .iter()
+            .filter_map(|node| match node {
+                Node::Var(i) => Some(*i),
+                _ => None,
+            })
+            .collect();
+        vars.sort_unstable();
+        vars.dedup();
+        self.constraints.push(Compiled {
+            nodes,
+            range: constraint.range,
+            vars,
+        });
+    }
+
+    /// Current domain of variable `name`, `None` if there is no such variable
+    ///
+    pub fn domain(&self, name: &str) -> Option<Interval> {
+        let i = self.names.iter().position(|known| known == name)?;
+        Some(self.domains[i])
+    }
+
+    /// Iterate over variables and their current domains, in declaration order
+    ///
+    pub fn domains(&self) -> impl Iterator<Item = (&str, Interval)> {
+        self.names
+            .iter()
+            .map(String::as_str)
+            .zip(self.domains.iter().copied())
+    }
+
+    /// Narrow domains by propagating constraints until a fixpoint is reached
+    ///
+    /// Revisions which narrow a domain by a negligible fraction of its width, or which leave it
+    /// unbounded, are not propagated any further, so that slowly converging networks stop in
+    /// reasonable time. Propagation also stops after a fixed number of revisions.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the constraints are proved to have no solution, domains being then left
+    /// partially narrowed, `true` otherwise.
+    ///
+    pub fn contract(&mut self) -> bool {
+        if self.domains.iter().any(|domain| domain.is_empty()) {
+            return false;
+        }
+        let mut queue: VecDeque<usize> = (0..self.constraints.len()).collect();
+        let mut queued = vec![true; self.constraints.len()];
+        let mut revisions = 0;
+        while let Some(c) = queue.pop_front() {
+            revisions += 1;
+            if revisions > MAX_REVISIONS {
+                break;
+            }
+            queued[c] = false;
+            let before = self.domains.clone();
+            if !self.constraints[c].revise(&mut self.domains) {
+                return false;
+            }
+            for &i in &self.constraints[c].vars {
+                if !progressed(before[i], self.domains[i]) {
+                    continue;
+                }
+                for (other, constraint) in self.constraints.iter().enumerate() {
+                    if other != c && !queued[other] && constraint.vars.contains(&i) {
+                        queued[other] = true;
+                        queue.push_back(other);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    fn index(&mut self, name: &str) -> usize {
+        match self.names.iter().position(|known| known == name) {
+            Some(i) => i,
+            None => {
+                self.names.push(name.to_string());
+                self.domains.push(INFINITY);
+                self.names.len() - 1
+            }
+        }
+    }
+
+    /// Append nodes of `tree` in post-order, returning the index of its root
+    fn compile(&mut self, tree: &Tree, nodes: &mut Vec<Node>) -> usize {
+        let node = match tree {
+            Tree::Var(name) => Node::Var(self.index(name)),
+            Tree::Const(k) => Node::Const(*k),
+            Tree::Add(a, b) => Node::Add(self.compile(a, nodes), self.compile(b, nodes)),
+            Tree::Sub(a, b) => Node::Sub(self.compile(a, nodes), self.compile(b, nodes)),
+            Tree::Mul(a, b) => Node::Mul(self.compile(a, nodes), self.compile(b, nodes)),
+            Tree::Div(a, b) => Node::Div(self.compile(a, nodes), self.compile(b, nodes)),
+            Tree::Neg(a) => Node::Neg(self.compile(a, nodes)),
+            Tree::Sqr(a) => Node::Sqr(self.compile(a, nodes)),
+            Tree::Pow(a, n) => Node::Pow(self.compile(a, nodes), *n),
+            Tree::Call(func, a) => Node::Call(*func, self.compile(a, nodes)),
+        };
+        nodes.push(node);
+        nodes.len() - 1
+    }
+}
+
+/// Check if narrowing `before` to `after` is worth propagating
+fn progressed(before: Interval, after: Interval) -> bool {
+    if before == after {
+        return false;
+    }
+    let (old, new) = (before.width(), after.width());
+    if !old.is_finite() {
+        // unbounded domains may shrink forever: only getting bounded counts
+        return new.is_finite();
+    }
+    old - new > MIN_PROGRESS * old
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound};
+
+    fn closed(a: f64, b: f64) -> Interval {
+        Interval::new(Closed(a), Closed(b))
+    }
+
+    #[test]
+    fn test_contract_1() {
+        let (x, y) = (Term::var("x"), Term::var("y"));
+        let mut network = Network::new();
+        network.add_variable("x", closed(0., 8.));
+        network.add_variable("y", closed(0., 5.));
+        network.add_constraint((x + y).within(Interval::singleton(10.)));
+        assert!(network.contract());
+        assert_eq!(network.domain("x"), Some(closed(5., 8.)));
+        assert_eq!(network.domain("y"), Some(closed(2., 5.)));
+        assert_eq!(network.domain("z"), None);
+    }
+
+    #[test]
+    fn test_contract_2() {
+        let x = Term::var("x");
+        let mut network = Network::new();
+        network.add_variable("x", Interval::new(Open(0.), Unbound));
+        network.add_constraint(x.sqr().within(Interval::singleton(4.)));
+        assert!(network.contract());
+        let x = network.domain("x").unwrap();
+        assert!(x.contains(2.) && x.width() < 1e-12);
+    }
+
+    #[test]
+    fn test_contract_3() {
+        let (x, y) = (Term::var("x"), Term::var("y"));
+        let mut network = Network::new();
+        network.add_variable("x", closed(2., 4.));
+        network.add_variable("y", closed(0., 10.));
+        network.add_constraint((&x * &y).within(Interval::singleton(1.)));
+        assert!(network.contract());
+        let y = network.domain("y").unwrap();
+        assert!(y.contains(0.25) && y.contains(0.5));
+        assert!(y.inf().unwrap() > 0.2499 && y.sup().unwrap() < 0.5001);
+    }
+
+    #[test]
+    fn test_contract_4() {
+        // Undeclared variable y gets narrowed from INFINITY
+        let (x, y) = (Term::var("x"), Term::var("y"));
+        let mut network = Network::new();
+        network.add_variable("x", closed(0., 1.));
+        network.add_constraint((&x - 2. * &y).within(Interval::singleton(0.)));
+        network.add_constraint((&x + &y).within(closed(5., 6.)));
+        assert!(!network.contract());
+        assert_eq!(network.domains().count(), 2);
+    }
+
+    #[test]
+    fn test_contract_5() {
+        // Bounds of x and y chase each other down forever: propagation must stop
+        let (x, y) = (Term::var("x"), Term::var("y"));
+        let mut network = Network::new();
+        network.add_variable("x", Interval::new(Unbound, Closed(0.)));
+        network.add_constraint((&x - &y).within(Interval::singleton(1.)));
+        network.add_constraint((&y - &x).within(Interval::singleton(1.)));
+        assert!(network.contract());
+        assert_eq!(network.domain("x").unwrap().left(), Unbound);
+    }
+
+    #[test]
+    fn test_display_1() {
+        let x = Term::var("x");
+        assert_eq!((-(&x * 2.) + x.sqr()).to_string(), "((-(x * 2)) + x^2)");
+        assert_eq!((x.sqr().sqr() / -1.).to_string(), "((x^2)^2 / (-1))");
+    }
+}
@@ -0,0 +1,300 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use super::{Bound, Closed, Interval, IntervalSet, Open, Unbound};
+
+/// Number of intervals covering each point of the real line
+///
+/// Finite endpoints of the intervals split the real line into elementary pieces: single endpoint
+/// values and the open gaps between them. Coverage is constant on each piece, so that it is
+/// computed once for all pieces by a sweep in `O(n log n)`, then looked up in `O(log n)`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Coverage, Interval, IntervalSet, Closed, Open};
+///
+/// let coverage = Coverage::new([
+///     Interval::new(Closed(0.), Closed(4.)),
+///     Interval::new(Closed(2.), Open(6.)),
+///     Interval::new(Open(3.), Closed(8.)),
+/// ]);
+///
+/// assert_eq!(coverage.coverage_at(1.), 1);
+/// assert_eq!(coverage.coverage_at(3.), 2);
+/// assert_eq!(coverage.coverage_at(3.5), 3);
+/// assert_eq!(coverage.coverage_at(9.), 0);
+/// assert_eq!(coverage.max_coverage(), 3);
+/// assert_eq!(
+///     coverage.max_coverage_regions(),
+///     IntervalSet::from(&[Interval::new(Open(3.), Closed(4.))])
+/// );
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Coverage {
+    /// Sorted distinct finite endpoints
+    values: Vec<f64>,
+    /// Coverage of each piece: gap before `values[i]` at `2i`, `values[i]` itself at `2i + 1`
+    counts: Vec<usize>,
+}
+
+impl Coverage {
+    /// Compute coverage of given intervals
+    ///
+    /// Empty intervals cover nothing.
+    ///
+    pub fn new(intervals: impl IntoIterator<Item = Interval>) -> Self {
+        let intervals: Vec<Interval> = intervals
+            .into_iter()
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let mut values: Vec<f64> = intervals
+            .iter()
+            .flat_map(|segment| [segment.left(), segment.right()])
+            .filter_map(Bound::value)
+            .collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values.dedup();
+
+        let index = |k: f64| values.partition_point(|v| *v < k);
+        let mut deltas = vec![0isize; 2 * values.len() + 2];
+        for segment in &intervals {
+            let first = match segment.left() {
+                Closed(k) => 2 * index(k) + 1,
+                Open(k) => 2 * index(k) + 2,
+                Unbound => 0,
+            };
+            let last = match segment.right() {
+                Closed(k) => 2 * index(k) + 1,
+                Open(k) => 2 * index(k),
+                Unbound => 2 * values.len(),
+            };
+            deltas[first] += 1;
+            deltas[last + 1] -= 1;
+        }
+
+        let mut count = 0;
+        let counts = deltas[..deltas.len() - 1]
+            .iter()
+            .map(|delta| {
+                count += delta;
+                count as usize
+            })
+            .collect();
+        Coverage { values, counts }
+    }
+
+    /// Number of intervals containing `x`
+    ///
+    pub fn coverage_at(&self, x: f64) -> usize {
+        if x.is_nan() {
+            return 0;
+        }
+        let i = self.values.partition_point(|v| *v < x);
+        match self.values.get(i) {
+            Some(v) if *v == x => self.counts[2 * i + 1],
+            _ => self.counts[2 * i],
+        }
+    }
+
+    /// Highest coverage reached on the real line
+    ///
+    pub fn max_coverage(&self) -> usize {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Points where coverage is maximal
+    ///
+    /// # Returns
+    ///
+    /// Empty set if coverage is null everywhere.
+    ///
+    pub fn max_coverage_regions(&self) -> IntervalSet {
+        let max = self.max_coverage();
+        if max == 0 {
+            return IntervalSet::new();
+        }
+        self.regions(|count| count == max)
+    }
+
+    /// Coverage as a step function
+    ///
+    /// # Returns
+    ///
+    /// Sorted pieces partitioning the real line, with the number of intervals covering each of
+    /// them. Consecutive pieces have distinct counts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Coverage, Interval, Closed, Open, Unbound};
+    ///
+    /// let coverage = Coverage::new([
+    ///     Interval::new(Closed(0.), Closed(2.)),
+    ///     Interval::new(Open(1.), Closed(3.)),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     coverage.steps(),
+    ///     vec![
+    ///         (Interval::new(Unbound, Open(0.)), 0),
+    ///         (Interval::new(Closed(0.), Closed(1.)), 1),
+    ///         (Interval::new(Open(1.), Closed(2.)), 2),
+    ///         (Interval::new(Open(2.), Closed(3.)), 1),
+    ///         (Interval::new(Open(3.), Unbound), 0),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    pub fn steps(&self) -> Vec<(Interval, usize)> {
+        let mut steps: Vec<(Interval, usize)> = Vec::new();
+        for (i, count) in self.counts.iter().enumerate() {
+            let piece = self.piece(i);
+            match steps.last_mut() {
+                Some((last, last_count)) if *last_count == *count => {
+                    *last = last.union(piece).0;
+                }
+                _ => steps.push((piece, *count)),
+            }
+        }
+        steps
+    }
+
+    /// Points covered by at least `k` intervals
+    ///
+    /// `at_least(1)` is the union of intervals, `at_least(2)` is the union of their pairwise
+    /// intersections.
+    ///
+    pub fn at_least(&self, k: usize) -> IntervalSet {
+        self.regions(|count| count >= k)
+    }
+
+    /// Elementary piece of index `i`
+    fn piece(&self, i: usize) -> Interval {
+        let k = i / 2;
+        if i % 2 == 1 {
+            return Interval::singleton(self.values[k]);
+        }
+        let left = match k {
+            0 => Unbound,
+            _ => Open(self.values[k - 1]),
+        };
+        let right = match self.values.get(k) {
+            Some(v) => Open(*v),
+            None => Unbound,
+        };
+        Interval::new(left, right)
+    }
+
+    /// Points whose coverage satisfies `predicate`
+    fn regions(&self, predicate: impl Fn(usize) -> bool) -> IntervalSet {
+        let pieces = self
+            .counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| predicate(**count))
+            .map(|(i, _)| self.piece(i));
+        IntervalSet::union_all(pieces)
+    }
+}
+
+impl FromIterator<Interval> for Coverage {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        Coverage::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{EMPTY, INFINITY};
+
+    #[test]
+    fn test_coverage_at_1() {
+        let coverage = Coverage::new([
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Open(2.), Unbound),
+            Interval::singleton(1.),
+            EMPTY,
+        ]);
+        assert_eq!(coverage.coverage_at(-1.), 0);
+        assert_eq!(coverage.coverage_at(0.), 1);
+        assert_eq!(coverage.coverage_at(1.), 2);
+        assert_eq!(coverage.coverage_at(2.), 1);
+        assert_eq!(coverage.coverage_at(1e300), 1);
+        assert_eq!(coverage.coverage_at(f64::NAN), 0);
+    }
+
+    #[test]
+    fn test_coverage_at_2() {
+        let coverage: Coverage = [INFINITY, INFINITY].into_iter().collect();
+        assert_eq!(coverage.coverage_at(0.), 2);
+        assert_eq!(
+            coverage.max_coverage_regions(),
+            IntervalSet::from(&[INFINITY])
+        );
+    }
+
+    #[test]
+    fn test_steps_1() {
+        assert_eq!(Coverage::new(Vec::new()).steps(), vec![(INFINITY, 0)]);
+        let coverage = Coverage::new([
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(1.), Closed(2.)),
+        ]);
+        assert_eq!(
+            coverage.steps(),
+            vec![
+                (Interval::new(Unbound, Open(0.)), 0),
+                (Interval::new(Closed(0.), Closed(2.)), 1),
+                (Interval::new(Open(2.), Unbound), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_at_least_1() {
+        let a = Interval::new(Closed(0.), Closed(4.));
+        let b = Interval::new(Open(2.), Closed(6.));
+        let c = Interval::new(Closed(4.), Open(8.));
+        let coverage = Coverage::new([a, b, c]);
+        assert_eq!(coverage.at_least(0), IntervalSet::from(&[INFINITY]));
+        assert_eq!(coverage.at_least(1), IntervalSet::from(&[a, b, c]));
+        assert_eq!(
+            coverage.at_least(2),
+            IntervalSet::from(&[Interval::new(Open(2.), Closed(6.))])
+        );
+        assert_eq!(
+            coverage.at_least(3),
+            IntervalSet::from(&[Interval::singleton(4.)])
+        );
+        assert!(coverage.at_least(4).is_empty());
+    }
+
+    #[test]
+    fn test_max_coverage_1() {
+        let coverage = Coverage::new(Vec::new());
+        assert_eq!(coverage.coverage_at(0.), 0);
+        assert_eq!(coverage.max_coverage(), 0);
+        assert!(coverage.max_coverage_regions().is_empty());
+    }
+
+    #[test]
+    fn test_max_coverage_2() {
+        let coverage = Coverage::new([
+            Interval::new(Closed(0.), Closed(2.)),
+            Interval::new(Closed(1.), Closed(5.)),
+            Interval::new(Closed(2.), Open(3.)),
+            Interval::new(Open(4.), Closed(6.)),
+        ]);
+        assert_eq!(coverage.max_coverage(), 3);
+        assert_eq!(
+            coverage.max_coverage_regions(),
+            IntervalSet::from(&[Interval::singleton(2.)])
+        );
+    }
+}
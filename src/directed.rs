@@ -0,0 +1,257 @@
+use core::fmt::Display;
+
+use auto_ops::impl_op_ex;
+
+use super::{Closed, Interval, Unbound};
+
+/// Directed (modal) interval `[lo, hi]` of Kaucher arithmetic, where `lo > hi` is allowed
+///
+/// A directed interval is proper if `lo <= hi` (it then stands for the classical closed
+/// interval), improper otherwise. Kaucher arithmetic extends interval arithmetic to improper
+/// intervals so that addition and multiplication become invertible: `x - x.dual()` is exactly
+/// `[0, 0]`, which makes inner estimations of solution sets computable.
+///
+/// Endpoints may be infinite. An undefined `∞ - ∞` endpoint is taken outward (`-∞` for `lo`,
+/// `+∞` for `hi`), and `0 × ∞` is zero, so that results never hold `NaN`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{DirectedInterval, Interval, Closed};
+///
+/// let x = DirectedInterval::new(1., 3.);
+/// let y = DirectedInterval::new(4., 2.);
+///
+/// assert!(y.is_improper());
+/// assert_eq!(x + y, DirectedInterval::new(5., 5.));
+/// assert_eq!(x - x.dual(), DirectedInterval::new(0., 0.));
+/// assert_eq!(y.proper().to_interval(), Interval::new(Closed(2.), Closed(4.)));
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectedInterval {
+    lo: f64,
+    hi: f64,
+}
+
+/// Sum of endpoints, `∞ - ∞` giving `outer`, the infinity on the outer side of the endpoint
+fn add(a: f64, b: f64, outer: f64) -> f64 {
+    let sum = a + b;
+    if sum.is_nan() {
+        outer
+    } else {
+        sum
+    }
+}
+
+/// Product of endpoints, `0 × ∞` giving zero
+fn mul(a: f64, b: f64) -> f64 {
+    if a == 0. || b == 0. {
+        0.
+    } else {
+        a * b
+    }
+}
+
+/// Sign class of a directed interval, selecting the product formula
+#[derive(Clone, Copy)]
+enum Class {
+    /// Both endpoints non-negative
+    Positive,
+    /// Both endpoints non-positive
+    Negative,
+    /// Proper interval containing zero in its interior
+    Zero,
+    /// Improper interval containing zero in the interior of its proper projection
+    DualZero,
+}
+
+impl DirectedInterval {
+    /// Build directed interval from its endpoints, in any order
+    ///
+    /// # Panics
+    ///
+    /// Panics if an endpoint is `NaN`.
+    ///
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(!lo.is_nan() && !hi.is_nan(), "endpoints must not be NaN");
+        DirectedInterval { lo, hi }
+    }
+
+    /// Proper directed interval with the same points as `interval`
+    ///
+    /// Open endpoints are closed, unbound ones become infinite.
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty.
+    ///
+    pub fn from_interval(interval: Interval) -> Option<Self> {
+        Some(DirectedInterval::new(interval.inf()?, interval.sup()?))
+    }
+
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    pub fn is_proper(&self) -> bool {
+        self.lo <= self.hi
+    }
+
+    pub fn is_improper(&self) -> bool {
+        self.lo > self.hi
+    }
+
+    /// Dual interval `[hi, lo]`, switching proper and improper intervals
+    ///
+    pub fn dual(&self) -> Self {
+        DirectedInterval {
+            lo: self.hi,
+            hi: self.lo,
+        }
+    }
+
+    /// Proper projection `[min(lo, hi), max(lo, hi)]`
+    ///
+    pub fn proper(&self) -> Self {
+        DirectedInterval {
+            lo: self.lo.min(self.hi),
+            hi: self.lo.max(self.hi),
+        }
+    }
+
+    /// Points of proper projection, as a closed interval
+    ///
+    pub fn to_interval(&self) -> Interval {
+        let bound = |k: f64| if k.is_infinite() { Unbound } else { Closed(k) };
+        let DirectedInterval { lo, hi } = self.proper();
+        Interval::new(bound(lo), bound(hi))
+    }
+
+    fn class(&self) -> Class {
+        if self.lo >= 0. && self.hi >= 0. {
+            Class::Positive
+        } else if self.lo <= 0. && self.hi <= 0. {
+            Class::Negative
+        } else if self.lo < 0. {
+            Class::Zero
+        } else {
+            Class::DualZero
+        }
+    }
+
+    /// Kaucher product, following the table of the sign classes of both operands
+    ///
+    /// As in classical interval arithmetic, `0 × ∞` is zero.
+    fn product(&self, other: &DirectedInterval) -> DirectedInterval {
+        use Class::*;
+        let (x1, x2, y1, y2) = (self.lo, self.hi, other.lo, other.hi);
+        let (lo, hi) = match (self.class(), other.class()) {
+            (Positive, Positive) => (mul(x1, y1), mul(x2, y2)),
+            (Positive, Zero) => (mul(x2, y1), mul(x2, y2)),
+            (Positive, Negative) => (mul(x2, y1), mul(x1, y2)),
+            (Positive, DualZero) => (mul(x1, y1), mul(x1, y2)),
+            (Zero, Positive) => (mul(x1, y2), mul(x2, y2)),
+            (Zero, Zero) => (mul(x1, y2).min(mul(x2, y1)), mul(x1, y1).max(mul(x2, y2))),
+            (Zero, Negative) => (mul(x2, y1), mul(x1, y1)),
+            (Zero, DualZero) | (DualZero, Zero) => (0., 0.),
+            (Negative, Positive) => (mul(x1, y2), mul(x2, y1)),
+            (Negative, Zero) => (mul(x1, y2), mul(x1, y1)),
+            (Negative, Negative) => (mul(x2, y2), mul(x1, y1)),
+            (Negative, DualZero) => (mul(x2, y2), mul(x2, y1)),
+            (DualZero, Positive) => (mul(x1, y1), mul(x2, y1)),
+            (DualZero, Negative) => (mul(x2, y2), mul(x1, y2)),
+            (DualZero, DualZero) => (mul(x1, y1).max(mul(x2, y2)), mul(x1, y2).min(mul(x2, y1))),
+        };
+        DirectedInterval { lo, hi }
+    }
+}
+
+impl Display for DirectedInterval {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{:5.2},{:5.2}]", self.lo, self.hi)
+    }
+}
+
+impl_op_ex!(+|a: &DirectedInterval, b: &DirectedInterval| -> DirectedInterval {
+    DirectedInterval {
+        lo: add(a.lo, b.lo, f64::NEG_INFINITY),
+        hi: add(a.hi, b.hi, f64::INFINITY),
+    }
+});
+impl_op_ex!(
+    -|a: &DirectedInterval, b: &DirectedInterval| -> DirectedInterval {
+        DirectedInterval {
+            lo: add(a.lo, -b.hi, f64::NEG_INFINITY),
+            hi: add(a.hi, -b.lo, f64::INFINITY),
+        }
+    }
+);
+impl_op_ex!(-|a: &DirectedInterval| -> DirectedInterval { DirectedInterval::new(-a.hi, -a.lo) });
+impl_op_ex!(*|a: &DirectedInterval, b: &DirectedInterval| -> DirectedInterval { a.product(b) });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Open, EMPTY, INFINITY};
+
+    #[test]
+    fn test_from_interval_1() {
+        assert_eq!(DirectedInterval::from_interval(EMPTY), None);
+        assert_eq!(
+            DirectedInterval::from_interval(Interval::new(Open(1.), Closed(2.))),
+            Some(DirectedInterval::new(1., 2.))
+        );
+        let inf = DirectedInterval::from_interval(INFINITY).unwrap();
+        assert_eq!(inf.to_interval(), INFINITY);
+        assert!(inf.dual().is_improper());
+    }
+
+    #[test]
+    fn test_add_1() {
+        let x = DirectedInterval::new(-1., 2.);
+        assert_eq!(x - x, DirectedInterval::new(-3., 3.));
+        assert_eq!(x - x.dual(), DirectedInterval::new(0., 0.));
+        assert_eq!(x + (-x).dual(), DirectedInterval::new(0., 0.));
+    }
+
+    #[test]
+    fn test_mul_1() {
+        let p = DirectedInterval::new(1., 2.);
+        let z = DirectedInterval::new(-1., 3.);
+        assert_eq!(p * z, DirectedInterval::new(-2., 6.));
+        assert_eq!(z * z, DirectedInterval::new(-3., 9.));
+        assert_eq!(z * z.dual(), DirectedInterval::new(0., 0.));
+        assert_eq!(
+            -p * DirectedInterval::new(-3., -1.),
+            DirectedInterval::new(1., 6.)
+        );
+        // Multiplicative inverse of a positive interval
+        let inverse = DirectedInterval::new(1. / p.lo(), 1. / p.hi());
+        assert_eq!(p * inverse, DirectedInterval::new(1., 1.));
+    }
+
+    #[test]
+    fn test_infinite_1() {
+        // ∞ - ∞ widens outward, 0 × ∞ is zero
+        let inf = DirectedInterval::from_interval(INFINITY).unwrap();
+        let whole = DirectedInterval::new(f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!(inf + inf.dual(), whole);
+        assert_eq!(inf - inf, whole);
+        assert_eq!(inf.dual() - inf.dual(), inf.dual());
+        let half = DirectedInterval::new(0., f64::INFINITY);
+        let z = DirectedInterval::new(-1., 3.);
+        assert_eq!(half * z, whole);
+        assert_eq!(half * half, half);
+        assert_eq!(
+            DirectedInterval::new(0., 0.) * inf,
+            DirectedInterval::new(0., 0.)
+        );
+        assert_eq!(inf * inf, whole);
+        assert_eq!(inf.dual() * inf.dual(), inf.dual());
+    }
+}
@@ -0,0 +1,208 @@
+use core::fmt::Display;
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::Interval;
+
+/// Pair of enclosures of a function and of its derivative, for forward-mode differentiation
+///
+/// Evaluating a function on `DualInterval::variable(x)` instead of `x` gives, in a single pass,
+/// an enclosure of the function over `x` and an enclosure of its derivative over `x`. A
+/// derivative of constant sign proves the function monotonic over `x`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{DualInterval, Interval, Closed};
+///
+/// // f(x) = x² + sin(x), f'(x) = 2x + cos(x)
+/// let x = DualInterval::variable(Interval::new(Closed(1.), Closed(2.)));
+/// let f = x.sqr() + x.sin();
+///
+/// assert!(f.value().contains(1. + 1f64.sin()) && f.value().contains(4. + 2f64.sin()));
+/// assert!(f.derivative().contains(2. + 1f64.cos()));
+/// assert!(f.is_nondecreasing());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DualInterval {
+    value: Interval,
+    derivative: Interval,
+}
+
+impl DualInterval {
+    /// Pair from enclosures of a value and of its derivative
+    ///
+    pub fn new(value: Interval, derivative: Interval) -> Self {
+        DualInterval { value, derivative }
+    }
+
+    /// Quantity not depending on the variable: derivative is zero
+    ///
+    pub fn constant(value: Interval) -> Self {
+        DualInterval::new(value, Interval::singleton(0.))
+    }
+
+    /// Variable of differentiation ranging over `value`: derivative is one
+    ///
+    pub fn variable(value: Interval) -> Self {
+        DualInterval::new(value, Interval::singleton(1.))
+    }
+
+    pub fn value(&self) -> Interval {
+        self.value
+    }
+
+    pub fn derivative(&self) -> Interval {
+        self.derivative
+    }
+
+    /// Check if derivative is proved non-negative, ie. function is non-decreasing
+    ///
+    pub fn is_nondecreasing(&self) -> bool {
+        self.derivative.inf().is_some_and(|inf| inf >= 0.)
+    }
+
+    /// Check if derivative is proved non-positive, ie. function is non-increasing
+    ///
+    pub fn is_nonincreasing(&self) -> bool {
+        self.derivative.sup().is_some_and(|sup| sup <= 0.)
+    }
+
+    /// Chain rule: `f(self)` given enclosures `f(value)` and `f'(value)`
+    fn chain(&self, value: Interval, slope: Interval) -> Self {
+        DualInterval::new(value, slope * self.derivative)
+    }
+
+    pub fn sqr(&self) -> Self {
+        self.chain(self.value.sqr(), 2. * self.value)
+    }
+
+    pub fn powi(&self, n: i32) -> Self {
+        let slope = match n {
+            0 => Interval::singleton(0.),
+            n => f64::from(n) * self.value.powi(n - 1),
+        };
+        self.chain(self.value.powi(n), slope)
+    }
+
+    pub fn sqrt(&self) -> Self {
+        let root = self.value.sqrt();
+        self.chain(root, Interval::singleton(0.5).quotient(&root))
+    }
+
+    pub fn exp(&self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    pub fn ln(&self) -> Self {
+        self.chain(
+            self.value.ln(),
+            Interval::singleton(1.).quotient(&self.value),
+        )
+    }
+
+    pub fn sin(&self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    pub fn cos(&self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+}
+
+impl From<Interval> for DualInterval {
+    fn from(value: Interval) -> Self {
+        DualInterval::constant(value)
+    }
+}
+
+impl Display for DualInterval {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({}, {})", self.value, self.derivative)
+    }
+}
+
+impl_op_ex!(+|a: &DualInterval, b: &DualInterval| -> DualInterval {
+    DualInterval::new(a.value + b.value, a.derivative + b.derivative)
+});
+impl_op_ex!(-|a: &DualInterval, b: &DualInterval| -> DualInterval {
+    DualInterval::new(a.value - b.value, a.derivative - b.derivative)
+});
+impl_op_ex!(*|a: &DualInterval, b: &DualInterval| -> DualInterval {
+    DualInterval::new(
+        a.value * b.value,
+        a.derivative * b.value + a.value * b.derivative,
+    )
+});
+impl_op_ex!(/|a: &DualInterval, b: &DualInterval| -> DualInterval {
+    let numerator = a.derivative * b.value - a.value * b.derivative;
+    DualInterval::new(
+        a.value.quotient(&b.value),
+        numerator.quotient(&b.value.sqr()),
+    )
+});
+impl_op_ex!(-|a: &DualInterval| -> DualInterval { DualInterval::new(-a.value, -a.derivative) });
+
+impl_op_ex_commutative!(+|a: &DualInterval, k: f64| -> DualInterval {
+    DualInterval::new(a.value + k, a.derivative)
+});
+impl_op_ex!(-|a: &DualInterval, k: f64| -> DualInterval {
+    DualInterval::new(a.value - k, a.derivative)
+});
+impl_op_ex!(-|k: f64, a: &DualInterval| -> DualInterval {
+    DualInterval::new(k - a.value, -a.derivative)
+});
+impl_op_ex_commutative!(*|a: &DualInterval, k: f64| -> DualInterval {
+    DualInterval::new(a.value * k, a.derivative * k)
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, INFINITY};
+
+    fn closed(a: f64, b: f64) -> Interval {
+        Interval::new(Closed(a), Closed(b))
+    }
+
+    #[test]
+    fn test_arith_1() {
+        let x = DualInterval::variable(closed(0., 3.));
+        let f = x.sqr() - 2. * x;
+        assert_eq!(f.value(), closed(-6., 9.));
+        assert_eq!(f.derivative(), closed(-2., 4.));
+        assert!(!f.is_nondecreasing() && !f.is_nonincreasing());
+        let g = x * x + 1.;
+        assert_eq!(g.derivative(), closed(0., 6.));
+        assert!(g.is_nondecreasing());
+    }
+
+    #[test]
+    fn test_div_1() {
+        // 1 / x over [1, 2]: derivative -1/x² ∈ [-1, -0.25]
+        let x = DualInterval::variable(closed(1., 2.));
+        let f = DualInterval::constant(Interval::singleton(1.)) / x;
+        assert_eq!(f.value(), closed(0.5, 1.));
+        assert_eq!(f.derivative(), closed(-1., -0.25));
+        assert!(f.is_nonincreasing());
+        let y = DualInterval::variable(closed(-1., 1.));
+        assert_eq!((1. - y / y).derivative(), INFINITY);
+    }
+
+    #[test]
+    fn test_elementary_1() {
+        let x = DualInterval::variable(closed(1., 4.));
+        let root = x.sqrt();
+        assert!(root.value().contains(1.) && root.value().contains(2.));
+        assert!(root.derivative().contains(0.25) && root.derivative().contains(0.5));
+        assert!(root.derivative().width() < 0.26);
+        let l = x.ln();
+        assert!(l.derivative().contains(1.) && l.derivative().contains(0.25));
+        assert_eq!(x.powi(3).derivative(), closed(3., 48.));
+        assert_eq!(x.powi(0).derivative(), Interval::singleton(0.));
+        let c = (x * 0.).cos();
+        assert_eq!(c.derivative(), Interval::singleton(0.));
+    }
+}
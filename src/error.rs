@@ -0,0 +1,69 @@
+use core::fmt::Display;
+
+use super::ParseIntervalError;
+
+/// Error returned by the fallible (`try_` or `checked_`) variants of operations
+///
+/// Infallible operations saturate instead: they return `EMPTY` or `INFINITY`, or panic.
+/// Fallible variants report why instead.
+///
+/// # Example
+///
+/// ```
+/// use core::error::Error;
+/// use interval::{Interval, IntervalError, Closed};
+///
+/// assert_eq!(
+///     Interval::try_new(Closed(f64::NAN), Closed(1.)),
+///     Err(IntervalError::NanEndpoint)
+/// );
+/// let err: IntervalError = "[1,".parse::<Interval>().unwrap_err().into();
+/// assert_eq!(err.to_string(), "invalid interval: missing closing bracket");
+/// assert!(err.source().is_none());
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntervalError {
+    /// An endpoint is `NaN`
+    NanEndpoint,
+    /// Text is not a valid interval
+    ///
+    /// This variant is transparent: it displays as the parse error itself, and has the same
+    /// source.
+    Parse(ParseIntervalError),
+    /// Operands can not be combined (ie. division by an interval containing zero)
+    IncompatibleOperands(&'static str),
+    /// An unbounded interval was given where a bounded one is required
+    Unbounded,
+    /// Result of an operation on finite values overflows `f64`
+    Overflow,
+}
+
+impl Display for IntervalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IntervalError::NanEndpoint => write!(f, "NaN endpoint"),
+            IntervalError::Parse(err) => write!(f, "{err}"),
+            IntervalError::IncompatibleOperands(reason) => {
+                write!(f, "incompatible operands: {reason}")
+            }
+            IntervalError::Unbounded => write!(f, "unbounded interval where bounded is required"),
+            IntervalError::Overflow => write!(f, "result overflows f64"),
+        }
+    }
+}
+
+impl core::error::Error for IntervalError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            IntervalError::Parse(err) => err.source(),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseIntervalError> for IntervalError {
+    fn from(err: ParseIntervalError) -> Self {
+        IntervalError::Parse(err)
+    }
+}
@@ -0,0 +1,556 @@
+//! Arithmetic expressions evaluated in interval arithmetic
+//!
+//! An `Expr` is parsed from text, or built from `Expr::var`, `Expr::constant`, operators and
+//! elementary functions. Evaluating it over intervals gives an enclosure of its range: every
+//! value taken by the expression when its variables range over their intervals.
+//!
+//! The enclosure is usually wider than the exact range, as each occurrence of a variable is
+//! evaluated independently: `x - x` over `[0, 1]` gives `[-1, 1]`.
+//!
+//! # Syntax
+//!
+//! - numbers (`3`, `0.5`, `1e-3`), taken as their nearest `f64`, which must be finite
+//! - variables: identifiers such as `x` or `speed_2`
+//! - `+`, `-`, `*`, `/`, unary `-`, and powers with an integer exponent (`x^2`, `x^-1`)
+//! - functions `sqrt`, `exp`, `ln`, `sin`, `cos` and `abs`
+//! - parentheses
+//!
+//! Expression trees are at most 256 levels deep: each parenthesis, unary minus and binary
+//! operator of a chain (`x + x + x` is two levels) counts as a level.
+//!
+//! # Example
+//!
+//! ```
+//! use interval::{Interval, Closed};
+//! use interval::expr::Expr;
+//!
+//! let f: Expr = "x^2 - sin(x) + 3".parse().unwrap();
+//! let x = Interval::new(Closed(0.), Closed(1.));
+//! let range = f.eval(&[("x", x)]).unwrap();
+//!
+//! // x² ∈ [0, 1], sin(x) ∈ [0, 0.842]
+//! assert!(range.contains(2.16) && range.contains(4.));
+//! assert!(range.inf().unwrap() > 2.15 && range.sup().unwrap() < 4.0001);
+//! assert_eq!(f.variables(), vec!["x"]);
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::contract::{Func, Term, Tree};
+use super::Interval;
+
+/// Nesting depth of parentheses, unary minus and binary operators beyond which parsing fails,
+/// keeping recursion (in parsing, evaluation and drop of the tree) away from stack overflows
+const MAX_DEPTH: usize = 256;
+
+/// Arithmetic expression over named variables
+///
+/// Expressions share their tree with `contract::Term`, and convert into terms to be used in
+/// constraints.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr(Term);
+
+impl Expr {
+    pub fn var(name: &str) -> Self {
+        Expr(Term::var(name))
+    }
+
+    pub fn constant(k: f64) -> Self {
+        Expr(Term::constant(k))
+    }
+
+    /// Integer power of expression
+    ///
+    pub fn powi(&self, n: i32) -> Self {
+        Expr(Term(Tree::Pow(Box::new(self.tree().clone()), n)))
+    }
+
+    pub fn sqrt(&self) -> Self {
+        self.call(Func::Sqrt)
+    }
+
+    pub fn exp(&self) -> Self {
+        self.call(Func::Exp)
+    }
+
+    pub fn ln(&self) -> Self {
+        self.call(Func::Ln)
+    }
+
+    pub fn sin(&self) -> Self {
+        self.call(Func::Sin)
+    }
+
+    pub fn cos(&self) -> Self {
+        self.call(Func::Cos)
+    }
+
+    pub fn abs(&self) -> Self {
+        self.call(Func::Abs)
+    }
+
+    fn tree(&self) -> &Tree {
+        &self.0 .0
+    }
+
+    fn call(&self, func: Func) -> Self {
+        Expr(Term(Tree::Call(func, Box::new(self.tree().clone()))))
+    }
+
+    /// Enclosure of the range of expression, when its variables range over given intervals
+    ///
+    /// Division by an interval containing zero gives `INFINITY`, and functions only keep the
+    /// part of their argument inside their domain (`sqrt` of `[-1, 4]` is `[0, 2]`).
+    ///
+    /// # Returns
+    ///
+    /// `None` if a variable of expression is missing from `vars`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    /// use interval::expr::Expr;
+    ///
+    /// let (x, y) = (Expr::var("x"), Expr::var("y"));
+    /// let f = &x * &y + 1.;
+    ///
+    /// let range = f.eval(&[
+    ///     ("x", Interval::new(Closed(-1.), Closed(2.))),
+    ///     ("y", Interval::new(Closed(3.), Closed(4.))),
+    /// ]);
+    /// assert_eq!(range, Some(Interval::new(Closed(-3.), Closed(9.))));
+    /// assert_eq!(f.eval(&[("x", Interval::singleton(0.))]), None);
+    /// ```
+    ///
+    pub fn eval(&self, vars: &[(&str, Interval)]) -> Option<Interval> {
+        fn eval(tree: &Tree, vars: &[(&str, Interval)]) -> Option<Interval> {
+            Some(match tree {
+                Tree::Var(name) => vars.iter().find(|(var, _)| var == name)?.1,
+                Tree::Const(k) => Interval::singleton(*k),
+                Tree::Neg(a) => -eval(a, vars)?,
+                Tree::Add(a, b) => eval(a, vars)? + eval(b, vars)?,
+                Tree::Sub(a, b) => eval(a, vars)? - eval(b, vars)?,
+                Tree::Mul(a, b) => eval(a, vars)? * eval(b, vars)?,
+                Tree::Div(a, b) => eval(a, vars)?.quotient(&eval(b, vars)?),
+                Tree::Sqr(a) => eval(a, vars)?.sqr(),
+                Tree::Pow(a, n) => eval(a, vars)?.powi(*n),
+                Tree::Call(func, a) => func.apply(eval(a, vars)?),
+            })
+        }
+        eval(self.tree(), vars)
+    }
+
+    /// Names of the variables of expression, sorted and without duplicates
+    ///
+    pub fn variables(&self) -> Vec<&str> {
+        fn collect<'a>(tree: &'a Tree, names: &mut Vec<&'a str>) {
+            match tree {
+                Tree::Var(name) => names.push(name),
+                Tree::Const(_) => (),
+                Tree::Neg(a) | Tree::Sqr(a) | Tree::Pow(a, _) | Tree::Call(_, a) => {
+                    collect(a, names)
+                }
+                Tree::Add(a, b) | Tree::Sub(a, b) | Tree::Mul(a, b) | Tree::Div(a, b) => {
+                    collect(a, names);
+                    collect(b, names);
+                }
+            }
+        }
+        let mut names = Vec::new();
+        collect(self.tree(), &mut names);
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+}
+
+impl From<f64> for Expr {
+    fn from(k: f64) -> Self {
+        Expr::constant(k)
+    }
+}
+
+impl From<Expr> for Term {
+    fn from(expr: Expr) -> Self {
+        expr.0
+    }
+}
+
+/// Fully parenthesized notation (see `contract::Term`), which parses back to an equivalent
+/// expression as long as constants are finite
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl_op_ex!(+|a: &Expr, b: &Expr| -> Expr { Expr(&a.0 + &b.0) });
+impl_op_ex!(-|a: &Expr, b: &Expr| -> Expr { Expr(&a.0 - &b.0) });
+impl_op_ex!(*|a: &Expr, b: &Expr| -> Expr { Expr(&a.0 * &b.0) });
+impl_op_ex!(/|a: &Expr, b: &Expr| -> Expr { Expr(&a.0 / &b.0) });
+impl_op_ex!(-|a: &Expr| -> Expr { Expr(-&a.0) });
+
+impl_op_ex_commutative!(+|a: &Expr, k: f64| -> Expr { a + Expr::constant(k) });
+impl_op_ex!(-|a: &Expr, k: f64| -> Expr { a - Expr::constant(k) });
+impl_op_ex!(-|k: f64, a: &Expr| -> Expr { Expr::constant(k) - a });
+impl_op_ex_commutative!(*|a: &Expr, k: f64| -> Expr { a * Expr::constant(k) });
+impl_op_ex!(/|a: &Expr, k: f64| -> Expr { a / Expr::constant(k) });
+impl_op_ex!(/|k: f64, a: &Expr| -> Expr { Expr::constant(k) / a });
+
+/// Error returned when parsing an expression from text fails
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseExprError {
+    reason: &'static str,
+}
+
+impl ParseExprError {
+    fn new(reason: &'static str) -> Self {
+        ParseExprError { reason }
+    }
+}
+
+impl Display for ParseExprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid expression: {}", self.reason)
+    }
+}
+
+impl std::error::Error for ParseExprError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Symbol(char),
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ParseExprError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = start;
+            let mut previous = ' ';
+            while let Some(&(i, c)) = chars.peek() {
+                let exponent_sign = (c == '+' || c == '-') && matches!(previous, 'e' | 'E');
+                if !(c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E') || exponent_sign) {
+                    break;
+                }
+                previous = c;
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            let number: f64 = s[start..end]
+                .parse()
+                .map_err(|_| ParseExprError::new("invalid number"))?;
+            if !number.is_finite() {
+                return Err(ParseExprError::new("number out of range"));
+            }
+            tokens.push(Token::Number(number));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = start;
+            while let Some(&(i, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token::Ident(s[start..end].to_string()));
+        } else if "+-*/^()".contains(c) {
+            tokens.push(Token::Symbol(c));
+            chars.next();
+        } else {
+            return Err(ParseExprError::new("unexpected character"));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser, one method per precedence level
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// Current nesting of parentheses, unary minus and binary operators
+    depth: usize,
+}
+
+impl Parser {
+    fn nest(&mut self) -> Result<(), ParseExprError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(ParseExprError::new("expression nested too deeply"));
+        }
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// sum := product (('+' | '-') product)*
+    fn sum(&mut self) -> Result<Tree, ParseExprError> {
+        let depth = self.depth;
+        let mut tree = self.product()?;
+        loop {
+            if self.eat('+') {
+                self.nest()?;
+                tree = Tree::Add(Box::new(tree), Box::new(self.product()?));
+            } else if self.eat('-') {
+                self.nest()?;
+                tree = Tree::Sub(Box::new(tree), Box::new(self.product()?));
+            } else {
+                self.depth = depth;
+                return Ok(tree);
+            }
+        }
+    }
+
+    /// product := unary (('*' | '/') unary)*
+    fn product(&mut self) -> Result<Tree, ParseExprError> {
+        let depth = self.depth;
+        let mut tree = self.unary()?;
+        loop {
+            if self.eat('*') {
+                self.nest()?;
+                tree = Tree::Mul(Box::new(tree), Box::new(self.unary()?));
+            } else if self.eat('/') {
+                self.nest()?;
+                tree = Tree::Div(Box::new(tree), Box::new(self.unary()?));
+            } else {
+                self.depth = depth;
+                return Ok(tree);
+            }
+        }
+    }
+
+    /// unary := '-' unary | power
+    ///
+    /// Negated numbers are folded into negative constants.
+    fn unary(&mut self) -> Result<Tree, ParseExprError> {
+        if self.eat('-') {
+            self.nest()?;
+            let tree = match self.unary()? {
+                Tree::Const(k) => Tree::Const(-k),
+                tree => Tree::Neg(Box::new(tree)),
+            };
+            self.depth -= 1;
+            Ok(tree)
+        } else {
+            self.power()
+        }
+    }
+
+    /// power := atom ('^' '-'? integer)?
+    fn power(&mut self) -> Result<Tree, ParseExprError> {
+        let tree = self.atom()?;
+        if !self.eat('^') {
+            return Ok(tree);
+        }
+        let negative = self.eat('-');
+        let invalid = || ParseExprError::new("exponent must be an integer");
+        let n = match self.tokens.get(self.pos) {
+            Some(Token::Number(k)) if k.fract() == 0. && k.abs() <= i32::MAX as f64 => *k as i32,
+            _ => return Err(invalid()),
+        };
+        self.pos += 1;
+        Ok(Tree::Pow(Box::new(tree), if negative { -n } else { n }))
+    }
+
+    /// atom := number | variable | function '(' sum ')' | '(' sum ')'
+    fn atom(&mut self) -> Result<Tree, ParseExprError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| ParseExprError::new("unexpected end of expression"))?;
+        self.pos += 1;
+        match token {
+            Token::Number(k) => Ok(Tree::Const(k)),
+            Token::Ident(name) if self.peek() == Some(&Token::Symbol('(')) => {
+                let func = Func::ALL
+                    .into_iter()
+                    .find(|func| func.name() == name)
+                    .ok_or_else(|| ParseExprError::new("unknown function"))?;
+                Ok(Tree::Call(func, Box::new(self.group()?)))
+            }
+            Token::Ident(name) => Ok(Tree::Var(name)),
+            Token::Symbol('(') => {
+                self.pos -= 1;
+                self.group()
+            }
+            Token::Symbol(_) => Err(ParseExprError::new("unexpected operator")),
+        }
+    }
+
+    /// group := '(' sum ')'
+    fn group(&mut self) -> Result<Tree, ParseExprError> {
+        if !self.eat('(') {
+            return Err(ParseExprError::new("expected '('"));
+        }
+        self.nest()?;
+        let tree = self.sum()?;
+        if !self.eat(')') {
+            return Err(ParseExprError::new("expected ')'"));
+        }
+        self.depth -= 1;
+        Ok(tree)
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseExprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            pos: 0,
+            depth: 0,
+        };
+        let tree = parser.sum()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(ParseExprError::new("unexpected trailing input"));
+        }
+        Ok(Expr(Term(tree)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Unbound, INFINITY};
+
+    fn closed(a: f64, b: f64) -> Interval {
+        Interval::new(Closed(a), Closed(b))
+    }
+
+    #[test]
+    fn test_parse_1() {
+        let x = Expr::var("x");
+        assert_eq!("x^2 - x*3".parse(), Ok(x.powi(2) - 3. * &x));
+        assert_eq!("(x^2)^3".parse(), Ok(x.powi(2).powi(3)));
+        assert_eq!("-x^2".parse(), Ok(-x.powi(2)));
+        assert_eq!("2 - x - 1".parse(), Ok(2. - &x - 1.));
+        assert_eq!("x / 2 / y".parse(), Ok(&x / 2. / Expr::var("y")));
+        assert_eq!("x^-1".parse(), Ok(x.powi(-1)));
+        assert_eq!(
+            "exp(1.5e-3 * x)".parse(),
+            Ok((Expr::constant(1.5e-3) * &x).exp())
+        );
+    }
+
+    #[test]
+    fn test_parse_2() {
+        assert!("".parse::<Expr>().is_err());
+        assert!("x +".parse::<Expr>().is_err());
+        assert!("(x".parse::<Expr>().is_err());
+        assert!("x y".parse::<Expr>().is_err());
+        assert!("tan(x)".parse::<Expr>().is_err());
+        assert!("x^0.5".parse::<Expr>().is_err());
+        assert!("x $ 2".parse::<Expr>().is_err());
+        assert!("1.2.3".parse::<Expr>().is_err());
+        assert_eq!(
+            "1e400 * x".parse::<Expr>(),
+            Err(ParseExprError::new("number out of range"))
+        );
+    }
+
+    #[test]
+    fn test_parse_3() {
+        let nested = |n| format!("{}x{}", "(".repeat(n), ")".repeat(n));
+        assert!(nested(MAX_DEPTH).parse::<Expr>().is_ok());
+        let too_deep = Err(ParseExprError::new("expression nested too deeply"));
+        assert_eq!(nested(100_000).parse::<Expr>(), too_deep);
+        assert_eq!(
+            format!("{}x", "-".repeat(100_000)).parse::<Expr>(),
+            too_deep
+        );
+    }
+
+    #[test]
+    fn test_parse_4() {
+        // Long flat chains used to parse, then overflow the stack in eval
+        let chain = |n, op| vec!["x"; n].join(op);
+        let too_deep = Err(ParseExprError::new("expression nested too deeply"));
+        for op in ["+", "-", "*", "/"] {
+            assert_eq!(chain(10_000, op).parse::<Expr>(), too_deep);
+        }
+        let f: Expr = chain(MAX_DEPTH + 1, "+").parse().unwrap();
+        assert_eq!(
+            f.eval(&[("x", closed(0., 1.))]),
+            Some(closed(0., (MAX_DEPTH + 1) as f64))
+        );
+        // Products nest within sums
+        let product = chain(MAX_DEPTH, "*");
+        assert!(format!("x+{product}").parse::<Expr>().is_ok());
+        assert_eq!(format!("x+x+{product}").parse::<Expr>(), too_deep);
+    }
+
+    #[test]
+    fn test_display_1() {
+        let f: Expr = "-(x - -2)^2 / sqrt(y) * -1".parse().unwrap();
+        assert_eq!(f.to_string(), "(((-(x - (-2))^2) / sqrt(y)) * (-1))");
+        assert_eq!(f.to_string().parse(), Ok(f));
+        let g = Expr::var("x").powi(2).powi(-1);
+        assert_eq!(g.to_string(), "(x^2)^-1");
+        assert_eq!(g.to_string().parse(), Ok(g));
+    }
+
+    #[test]
+    fn test_term_1() {
+        // Parsed expressions are constraint terms
+        use crate::contract::Network;
+        let f: Expr = "exp(x) / y".parse().unwrap();
+        let mut network = Network::new();
+        network.add_variable("x", closed(0., 1.));
+        network.add_variable("y", closed(1., 10.));
+        network.add_constraint(Term::from(f).within(closed(2., 3.)));
+        assert!(network.contract());
+        assert_eq!(
+            network.domain("y").map(|y| y.sup().unwrap() < 3.),
+            Some(true)
+        );
+        assert!(network.domain("x").unwrap().inf().unwrap() > 0.69);
+    }
+
+    #[test]
+    fn test_eval_1() {
+        let f: Expr = "x^2 - 2*x".parse().unwrap();
+        let x = closed(0., 2.);
+        assert_eq!(f.eval(&[("x", x)]), Some(closed(-4., 4.)));
+        let g: Expr = "1 / x".parse().unwrap();
+        assert_eq!(g.eval(&[("x", x)]), Some(INFINITY));
+        assert_eq!(g.eval(&[("x", closed(2., 4.))]), Some(closed(0.25, 0.5)));
+        let h: Expr = "sqrt(x - 1) + ln(x)".parse().unwrap();
+        assert_eq!(h.eval(&[("x", closed(-1., 0.5))]), Some(crate::EMPTY));
+        let cosine: Expr = "cos(t)".parse().unwrap();
+        assert_eq!(
+            cosine.eval(&[("t", Interval::new(Closed(0.), Unbound))]),
+            Some(closed(-1., 1.))
+        );
+    }
+
+    #[test]
+    fn test_variables_1() {
+        let f: Expr = "y * x + abs(x) - 3".parse().unwrap();
+        assert_eq!(f.variables(), vec!["x", "y"]);
+        assert!(Expr::constant(1.).variables().is_empty());
+    }
+}
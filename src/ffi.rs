@@ -0,0 +1,348 @@
+//! C interface to intervals
+//!
+//! Types are `#[repr(C)]` and functions are `extern "C"` with unmangled names, so that the crate
+//! can be linked from C or C++. Build it as a static or dynamic library with
+//! `cargo rustc --release --features ffi --crate-type staticlib` (or `cdylib`). A matching C
+//! declaration of the interface is:
+//!
+//! ```c
+//! #include <stdbool.h>
+//! #include <stddef.h>
+//! #include <stdint.h>
+//!
+//! typedef uint32_t interval_bound_kind;
+//! enum { INTERVAL_OPEN = 0, INTERVAL_CLOSED = 1, INTERVAL_UNBOUND = 2 };
+//! enum { INTERVAL_OK = 0, INTERVAL_INVALID_KIND = -1, INTERVAL_NULL_POINTER = -2 };
+//!
+//! typedef struct {
+//!     interval_bound_kind left_kind;
+//!     double left;
+//!     interval_bound_kind right_kind;
+//!     double right;
+//! } interval_t;
+//!
+//! typedef struct {
+//!     size_t count;
+//!     interval_t intervals[2];
+//! } interval_union_t;
+//!
+//! int interval_new(interval_bound_kind, double, interval_bound_kind, double, interval_t *);
+//! int interval_union(interval_t, interval_t, interval_union_t *);
+//! int interval_intersection(interval_t, interval_t, interval_t *);
+//! int interval_contains(interval_t, double, bool *);
+//! int interval_is_empty(interval_t, bool *);
+//! ```
+//!
+//! Every function returns a status, `INTERVAL_OK` on success, and writes its result through its
+//! last pointer. Bound kinds come from C as plain integers, and are checked: functions return
+//! `INTERVAL_INVALID_KIND` for anything but the three kinds above, and leave their output
+//! untouched. Values of unbound endpoints are ignored on input and set to `±INFINITY` on
+//! output.
+
+use core::ffi::c_int;
+
+use super::{Bound, Closed, Interval, Open, Unbound, EMPTY};
+
+/// Kind of an open endpoint
+pub const INTERVAL_OPEN: u32 = 0;
+/// Kind of a closed endpoint
+pub const INTERVAL_CLOSED: u32 = 1;
+/// Kind of a missing endpoint
+pub const INTERVAL_UNBOUND: u32 = 2;
+
+/// Status of a successful call
+pub const INTERVAL_OK: c_int = 0;
+/// Status of a call given an endpoint kind which is none of the three above
+pub const INTERVAL_INVALID_KIND: c_int = -1;
+/// Status of a call given a null output pointer
+pub const INTERVAL_NULL_POINTER: c_int = -2;
+
+/// Interval as seen from C
+///
+/// Intervals returned by the functions of this module are normalized: every empty interval is
+/// `(0, 0)` with open endpoints.
+///
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CInterval {
+    pub left_kind: u32,
+    pub left: f64,
+    pub right_kind: u32,
+    pub right: f64,
+}
+
+/// Union of two intervals: one interval, or two disjoint ones in increasing order
+///
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CIntervalUnion {
+    /// Number of meaningful entries in `intervals`, 1 or 2
+    pub count: usize,
+    pub intervals: [CInterval; 2],
+}
+
+fn to_bound(kind: u32, value: f64) -> Option<Bound> {
+    match kind {
+        INTERVAL_OPEN => Some(Open(value)),
+        INTERVAL_CLOSED => Some(Closed(value)),
+        INTERVAL_UNBOUND => Some(Unbound),
+        _ => None,
+    }
+}
+
+fn from_bound(bound: Bound, infinity: f64) -> (u32, f64) {
+    match bound {
+        Open(k) => (INTERVAL_OPEN, k),
+        Closed(k) => (INTERVAL_CLOSED, k),
+        Unbound => (INTERVAL_UNBOUND, infinity),
+    }
+}
+
+impl From<Interval> for CInterval {
+    fn from(interval: Interval) -> Self {
+        let (left_kind, left) = from_bound(interval.left(), f64::NEG_INFINITY);
+        let (right_kind, right) = from_bound(interval.right(), f64::INFINITY);
+        CInterval {
+            left_kind,
+            left,
+            right_kind,
+            right,
+        }
+    }
+}
+
+impl CInterval {
+    /// Interval described by endpoints, `None` if an endpoint kind is invalid
+    ///
+    pub fn to_interval(&self) -> Option<Interval> {
+        Some(Interval::new(
+            to_bound(self.left_kind, self.left)?,
+            to_bound(self.right_kind, self.right)?,
+        ))
+    }
+}
+
+/// Write `value` through `out`, reporting null pointers
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+///
+unsafe fn write_out<T>(out: *mut T, value: T) -> c_int {
+    if out.is_null() {
+        return INTERVAL_NULL_POINTER;
+    }
+    out.write(value);
+    INTERVAL_OK
+}
+
+/// Build interval from given endpoints (see `Interval::new`) into `out`
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn interval_new(
+    left_kind: u32,
+    left: f64,
+    right_kind: u32,
+    right: f64,
+    out: *mut CInterval,
+) -> c_int {
+    match (to_bound(left_kind, left), to_bound(right_kind, right)) {
+        (Some(left), Some(right)) => write_out(out, Interval::new(left, right).into()),
+        _ => INTERVAL_INVALID_KIND,
+    }
+}
+
+/// Union of two intervals (see `Interval::union`) into `out`
+///
+/// Union has `count == 2` if intervals are disjoint, `count == 1` otherwise. Unused entry is
+/// the empty interval.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn interval_union(
+    a: CInterval,
+    b: CInterval,
+    out: *mut CIntervalUnion,
+) -> c_int {
+    let (Some(a), Some(b)) = (a.to_interval(), b.to_interval()) else {
+        return INTERVAL_INVALID_KIND;
+    };
+    let union = match a.union(b) {
+        (joint, None) => CIntervalUnion {
+            count: 1,
+            intervals: [joint.into(), EMPTY.into()],
+        },
+        (lower, Some(upper)) => CIntervalUnion {
+            count: 2,
+            intervals: [lower.into(), upper.into()],
+        },
+    };
+    write_out(out, union)
+}
+
+/// Intersection of two intervals (see `Interval::intersection`) into `out`
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn interval_intersection(
+    a: CInterval,
+    b: CInterval,
+    out: *mut CInterval,
+) -> c_int {
+    match (a.to_interval(), b.to_interval()) {
+        (Some(a), Some(b)) => write_out(out, a.intersection(b).into()),
+        _ => INTERVAL_INVALID_KIND,
+    }
+}
+
+/// Check if interval contains `x`, into `out`
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn interval_contains(a: CInterval, x: f64, out: *mut bool) -> c_int {
+    match a.to_interval() {
+        Some(a) => write_out(out, a.contains(x)),
+        None => INTERVAL_INVALID_KIND,
+    }
+}
+
+/// Check if interval is empty, into `out`
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn interval_is_empty(a: CInterval, out: *mut bool) -> c_int {
+    match a.to_interval() {
+        Some(a) => write_out(out, a.is_empty()),
+        None => INTERVAL_INVALID_KIND,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::INFINITY;
+
+    fn new(left_kind: u32, left: f64, right_kind: u32, right: f64) -> CInterval {
+        let mut out = CInterval::from(INFINITY);
+        let status = unsafe { interval_new(left_kind, left, right_kind, right, &mut out) };
+        assert_eq!(status, INTERVAL_OK);
+        out
+    }
+
+    fn contains(a: CInterval, x: f64) -> bool {
+        let mut out = false;
+        assert_eq!(unsafe { interval_contains(a, x, &mut out) }, INTERVAL_OK);
+        out
+    }
+
+    fn is_empty(a: CInterval) -> bool {
+        let mut out = false;
+        assert_eq!(unsafe { interval_is_empty(a, &mut out) }, INTERVAL_OK);
+        out
+    }
+
+    #[test]
+    fn test_round_trip_1() {
+        let intervals = [
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Unbound, Closed(42.)),
+            Interval::singleton(42.),
+            EMPTY,
+            INFINITY,
+        ];
+        for a in intervals {
+            assert_eq!(CInterval::from(a).to_interval(), Some(a));
+        }
+        let c = CInterval::from(INFINITY);
+        assert_eq!((c.left, c.right), (f64::NEG_INFINITY, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_interval_new_1() {
+        let a = new(INTERVAL_CLOSED, 2., INTERVAL_OPEN, 1.);
+        assert_eq!(a, CInterval::from(EMPTY));
+        assert!(is_empty(a));
+        let b = new(INTERVAL_UNBOUND, 7., INTERVAL_CLOSED, 1.);
+        assert_eq!(b.to_interval(), Some(Interval::new(Unbound, Closed(1.))));
+        assert!(contains(b, -1e300));
+        assert!(!contains(b, 2.));
+        assert!(!is_empty(b));
+    }
+
+    #[test]
+    fn test_interval_new_2() {
+        // Invalid kinds and null pointers are reported
+        let mut out = CInterval::from(EMPTY);
+        let status = unsafe { interval_new(3, 0., INTERVAL_CLOSED, 1., &mut out) };
+        assert_eq!(status, INTERVAL_INVALID_KIND);
+        assert_eq!(out, CInterval::from(EMPTY));
+        let status = unsafe { interval_new(0, 0., 1, 1., core::ptr::null_mut()) };
+        assert_eq!(status, INTERVAL_NULL_POINTER);
+
+        let invalid = CInterval {
+            right_kind: u32::MAX,
+            ..CInterval::from(INFINITY)
+        };
+        assert_eq!(invalid.to_interval(), None);
+        // Predicates report bad input through their status only, leaving output untouched
+        let mut flag = true;
+        let status = unsafe { interval_contains(invalid, 0., &mut flag) };
+        assert_eq!((status, flag), (INTERVAL_INVALID_KIND, true));
+        let status = unsafe { interval_is_empty(invalid, &mut flag) };
+        assert_eq!((status, flag), (INTERVAL_INVALID_KIND, true));
+        let status =
+            unsafe { interval_contains(CInterval::from(INFINITY), 0., core::ptr::null_mut()) };
+        assert_eq!(status, INTERVAL_NULL_POINTER);
+        let status = unsafe { interval_intersection(invalid, invalid, &mut out) };
+        assert_eq!(status, INTERVAL_INVALID_KIND);
+    }
+
+    #[test]
+    fn test_interval_union_1() {
+        let a = new(INTERVAL_CLOSED, 0., INTERVAL_OPEN, 1.);
+        let b = new(INTERVAL_CLOSED, 1., INTERVAL_CLOSED, 2.);
+        let c = new(INTERVAL_OPEN, 2., INTERVAL_CLOSED, 3.);
+        let mut joint = CIntervalUnion {
+            count: 0,
+            intervals: [a; 2],
+        };
+        assert_eq!(unsafe { interval_union(a, b, &mut joint) }, INTERVAL_OK);
+        assert_eq!(joint.count, 1);
+        assert_eq!(
+            joint.intervals[0],
+            new(INTERVAL_CLOSED, 0., INTERVAL_CLOSED, 2.)
+        );
+        let mut split = joint;
+        assert_eq!(unsafe { interval_union(c, a, &mut split) }, INTERVAL_OK);
+        assert_eq!(split.count, 2);
+        assert_eq!(split.intervals, [a, c]);
+    }
+
+    #[test]
+    fn test_interval_intersection_1() {
+        let a = new(INTERVAL_CLOSED, 0., INTERVAL_CLOSED, 2.);
+        let b = new(INTERVAL_OPEN, 1., INTERVAL_UNBOUND, 0.);
+        let mut out = a;
+        assert_eq!(
+            unsafe { interval_intersection(a, b, &mut out) },
+            INTERVAL_OK
+        );
+        assert_eq!(out, new(INTERVAL_OPEN, 1., INTERVAL_CLOSED, 2.));
+    }
+}
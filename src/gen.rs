@@ -0,0 +1,206 @@
+//! Seeded generators of intervals and interval sets, for property tests
+//!
+//! Draws are biased toward the inputs that break interval code: endpoints shared between
+//! intervals, singletons, empty intervals, mixed open and closed bounds, and unbounded sides.
+//! Results only depend on the state of the random number generator: a failing case is replayed
+//! by seeding it again.
+//!
+//! ```
+//! use interval::gen::random_interval;
+//! use interval::{Interval, Closed, Unbound};
+//! use rand::SeedableRng;
+//!
+//! let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+//! let range = Interval::new(Closed(0.), Unbound);
+//!
+//! for _ in 0..100 {
+//!     let a = random_interval(&mut rng, range);
+//!     assert_eq!(a.intersection(range), a);
+//! }
+//! ```
+
+use rand::Rng;
+
+#[cfg(feature = "alloc")]
+use super::IntervalSet;
+use super::{Bound, Closed, Interval, Open, Unbound, EMPTY};
+
+/// Number of steps of the grid most endpoints are snapped to, so that they often coincide
+const GRID: u32 = 8;
+/// Width of the span drawn from on an unbounded side
+const SPAN: f64 = 16.;
+
+/// Finite span over which endpoints are drawn, `None` if `range` is empty
+fn finite_span(range: &Interval) -> Option<(f64, f64)> {
+    let (inf, sup) = (range.inf()?, range.sup()?);
+    Some(match (inf.is_finite(), sup.is_finite()) {
+        (true, true) => (inf, sup),
+        (true, false) => (inf, inf + SPAN),
+        (false, true) => (sup - SPAN, sup),
+        (false, false) => (-SPAN / 2., SPAN / 2.),
+    })
+}
+
+/// Point of `[a, b]`, on the grid three times out of four
+fn point<R: Rng + ?Sized>(rng: &mut R, a: f64, b: f64) -> f64 {
+    let t = if rng.gen_ratio(3, 4) {
+        f64::from(rng.gen_range(0..=GRID)) / f64::from(GRID)
+    } else {
+        rng.gen()
+    };
+    (a * (1. - t) + b * t).clamp(a, b)
+}
+
+/// Open or closed bound at `k`, `Unbound` one time out of four if `side` is unbounded
+fn bound<R: Rng + ?Sized>(rng: &mut R, k: f64, side: Bound) -> Bound {
+    if side == Unbound && rng.gen_ratio(1, 4) {
+        Unbound
+    } else if rng.gen() {
+        Closed(k)
+    } else {
+        Open(k)
+    }
+}
+
+/// Draw an interval included in `range`
+///
+/// Endpoints are mostly snapped to a grid of `range` (or of a finite span of it, on unbounded
+/// sides), and are open or closed with equal odds. About one draw in sixteen is empty, one in
+/// six is a singleton, and sides of `range` that are unbounded stay unbounded one time out of
+/// four.
+///
+/// # Returns
+///
+/// `EMPTY` if `range` is empty.
+///
+/// # Example
+///
+/// ```
+/// use interval::gen::random_interval;
+/// use interval::{Interval, Closed, Open};
+/// use rand::SeedableRng;
+///
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+/// let range = Interval::new(Closed(-1.), Open(1.));
+///
+/// let a = random_interval(&mut rng, range);
+/// assert_eq!(a.intersection(range), a);
+/// ```
+///
+pub fn random_interval<R: Rng + ?Sized>(rng: &mut R, range: Interval) -> Interval {
+    let Some((a, b)) = finite_span(&range) else {
+        return EMPTY;
+    };
+    let x = point(rng, a, b);
+    let candidate = match rng.gen_range(0..16) {
+        0 => EMPTY,
+        1..=3 => Interval::singleton(x),
+        _ => {
+            let y = point(rng, a, b);
+            let left = bound(rng, x.min(y), range.left());
+            let right = bound(rng, x.max(y), range.right());
+            Interval::new(left, right)
+        }
+    };
+    candidate.intersection(range)
+}
+
+/// Draw a set of at most `max_components` components, included in `range`
+///
+/// `range` is cut into a random number of slots sharing their endpoints, and an interval is
+/// drawn in each slot with `random_interval`: neighbouring components often touch, and merge or
+/// not depending on their bounds.
+///
+/// # Returns
+///
+/// An empty set if `range` is empty or `max_components` is zero.
+///
+/// # Example
+///
+/// ```
+/// use interval::gen::random_interval_set;
+/// use interval::INFINITY;
+/// use rand::SeedableRng;
+///
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+///
+/// let s = random_interval_set(&mut rng, 8, INFINITY);
+/// assert!(s.iter().count() <= 8);
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn random_interval_set<R: Rng + ?Sized>(
+    rng: &mut R,
+    max_components: usize,
+    range: Interval,
+) -> IntervalSet {
+    let Some((a, b)) = finite_span(&range) else {
+        return IntervalSet::new();
+    };
+    let n = rng.gen_range(0..=max_components);
+    let cut = |i: usize| {
+        let t = i as f64 / n as f64;
+        Closed((a * (1. - t) + b * t).clamp(a, b))
+    };
+    IntervalSet::union_all((0..n).map(|i| {
+        let left = if i == 0 { range.left() } else { cut(i) };
+        let right = if i + 1 == n {
+            range.right()
+        } else {
+            cut(i + 1)
+        };
+        random_interval(rng, Interval::new(left, right))
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::INFINITY;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_random_interval_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let range = Interval::new(Open(0.), Unbound);
+        let draws: Vec<Interval> = (0..1000)
+            .map(|_| random_interval(&mut rng, range))
+            .collect();
+        assert!(draws.iter().all(|a| a.intersection(range) == *a));
+        assert!(draws.iter().any(|a| a.is_empty()));
+        assert!(draws.iter().any(|a| a.is_singleton()));
+        assert!(draws.iter().any(|a| a.right() == Unbound));
+        assert!(draws.iter().all(|a| a.left() != Unbound));
+        assert_eq!(random_interval(&mut rng, EMPTY), EMPTY);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_random_interval_set_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let range = Interval::new(Closed(-1.), Closed(1.));
+        let mut touching = 0;
+        for _ in 0..1000 {
+            let s = random_interval_set(&mut rng, 5, range);
+            let components: Vec<Interval> = s.iter().collect();
+            assert!(components.len() <= 5);
+            assert!(components.iter().all(|a| a.intersection(range) == *a));
+            touching += components
+                .windows(2)
+                .filter(|pair| pair[0].sup() == pair[1].inf())
+                .count();
+        }
+        assert!(touching > 0);
+        assert!(random_interval_set(&mut rng, 0, INFINITY).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_random_interval_set_2() {
+        let draw = |seed| random_interval_set(&mut StdRng::seed_from_u64(seed), 8, INFINITY);
+        assert_eq!(draw(7), draw(7));
+        assert!((0..100).any(|seed| draw(seed).iter().any(|a| !a.is_bounded())));
+    }
+}
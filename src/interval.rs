@@ -1,15 +1,41 @@
+mod allen;
+#[cfg(feature = "std")]
+mod arith;
+mod batch;
 mod bound;
+mod compare;
+mod construct;
+mod format;
+mod integer;
 mod left;
+mod metric;
+mod packed;
+#[cfg(feature = "rand")]
+mod random;
 mod right;
-
-use bound::Bound;
-use left::Left;
-use right::Right;
+mod sampling;
+mod transform;
+
+pub use allen::AllenRelation;
+#[cfg(feature = "std")]
+pub(crate) use arith::{down, two_sum, up};
+pub use bound::Bound;
+pub use format::IntervalFormat;
+pub use integer::IntegerPolicy;
+pub use left::Left;
+pub use packed::PackedInterval;
+pub use right::Right;
+pub use transform::RoundingMode;
 
 pub use Bound::{Closed, Open, Unbound};
 
-use std::cmp::PartialEq;
-use std::fmt::Display;
+use crate::IntervalError;
+#[cfg(feature = "alloc")]
+use crate::IntervalSet;
+
+use core::cmp::PartialEq;
+use core::fmt::Display;
+use core::hash::{Hash, Hasher};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Interval(Left, Right);
@@ -17,13 +43,26 @@ pub struct Interval(Left, Right);
 pub const EMPTY: Interval = Interval(Left(Open(0.)), Right(Open(0.)));
 pub const INFINITY: Interval = Interval(Left(Unbound), Right(Unbound));
 
+/// Notation of `IntervalFormat::new()`
+///
 impl Display for Interval {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        IntervalFormat::new().write_interval(f, self)
+    }
+}
+
+/// Same notation as `Display`, endpoints being sent as raw `f64` and formatted by the host
+///
+#[cfg(feature = "defmt")]
+impl defmt::Format for Interval {
+    fn format(&self, f: defmt::Formatter<'_>) {
         match self {
-            Interval(Left(Open(k1)), Right(Open(k2))) if k1 == k2 => write!(f, "∅"),
-            Interval(Left(Unbound), Right(Unbound)) => write!(f, "(-∞,+∞)"),
-            Interval(Left(Closed(a)), Right(Closed(b))) if a == b => write!(f, "{{{a:5.2}}}"),
-            Interval(a, b) => write!(f, "{a},{b}"),
+            Interval(Left(Open(k1)), Right(Open(k2))) if k1 == k2 => defmt::write!(f, "∅"),
+            Interval(Left(Unbound), Right(Unbound)) => defmt::write!(f, "(-∞,+∞)"),
+            Interval(Left(Closed(a)), Right(Closed(b))) if a == b => {
+                defmt::write!(f, "{{{=f64}}}", a)
+            }
+            Interval(a, b) => defmt::write!(f, "{},{}", a, b),
         }
     }
 }
@@ -35,11 +74,80 @@ impl PartialEq for Interval {
     }
 }
 
+/// An interval equals a scalar when it is the singleton of that scalar
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed};
+///
+/// assert!(Interval::singleton(42.) == 42.);
+/// assert!(42. == Interval::from(42.));
+/// assert!(Interval::new(Closed(42.), Closed(43.)) != 42.);
+/// ```
+///
+impl PartialEq<f64> for Interval {
+    fn eq(&self, other: &f64) -> bool {
+        self.is_singleton() && self.inf() == Some(*other)
+    }
+}
+
+impl PartialEq<Interval> for f64 {
+    fn eq(&self, other: &Interval) -> bool {
+        other == self
+    }
+}
+
+/// Singleton of a scalar, `EMPTY` for `NaN` (see `Interval::singleton`)
+///
+impl From<f64> for Interval {
+    fn from(k: f64) -> Self {
+        Interval::singleton(k)
+    }
+}
+
+/// Endpoints are never `NaN` and `-0.` is stored as `0.`, so that equality is an equivalence
+/// relation, consistent with hashing
+///
+impl Eq for Interval {}
+
+impl Hash for Interval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for bound in [self.left(), self.right()] {
+            match bound {
+                Open(k) => (0u8, k.to_bits()).hash(state),
+                Closed(k) => (1u8, k.to_bits()).hash(state),
+                Unbound => 2u8.hash(state),
+            }
+        }
+    }
+}
+
+impl From<Interval> for (Bound, Bound) {
+    fn from(interval: Interval) -> Self {
+        interval.into_bounds()
+    }
+}
+
+/// Check if bounds that are not crossed still delimit no point: a `NaN` endpoint, or an open
+/// infinite endpoint on the unbounded side (ie. `(+∞,+∞)`)
+const fn holds_nothing(b1: Left, b2: Right) -> bool {
+    match (b1, b2) {
+        (Left(Closed(k) | Open(k)), _) | (_, Right(Closed(k) | Open(k))) if k.is_nan() => true,
+        (Left(Open(k)), _) => k == f64::INFINITY,
+        (_, Right(Open(k))) => k == f64::NEG_INFINITY,
+        _ => false,
+    }
+}
+
 impl Interval {
     /// Build interval from given bounds
     ///
     /// # Returns
     ///
+    /// `EMPTY` whenever bounds delimit no point (crossed bounds, `(5,5)`, a `NaN` endpoint,
+    /// `(+∞,+∞)`), so that all empty intervals share a single representation. Likewise, `-0.`
+    /// endpoints are stored as `0.`.
     ///
     /// # Example
     ///
@@ -53,23 +161,53 @@ impl Interval {
     /// assert_eq!(format!("{a}"), "(42.00,43.00]");
     /// assert_eq!(format!("{b}"), "(-∞,+∞)");
     /// assert_eq!(format!("{c}"), "{42.00}");
+    ///
+    /// const VALID: Interval = Interval::new(Closed(0.), Open(1.));
+    /// assert!(VALID.contains(0.5));
     /// ```
     ///
-    pub fn new(b1: Bound, b2: Bound) -> Self {
-        let b1 = Left(b1);
-        let b2 = Right(b2);
+    pub const fn new(b1: Bound, b2: Bound) -> Self {
+        let b1 = Left(b1.without_signed_zero());
+        let b2 = Right(b2.without_signed_zero());
 
-        if b2 < b1 {
+        if b2.is_before(b1) || holds_nothing(b1, b2) {
             EMPTY
-        } else if (b1, b2) == (Left(Unbound), Right(Unbound)) {
+        } else if matches!((b1, b2), (Left(Unbound), Right(Unbound))) {
             INFINITY
         } else {
             Interval(b1, b2)
         }
     }
 
-    pub fn singleton(k: f64) -> Self {
-        Interval(Left(Closed(k)), Right(Closed(k)))
+    /// Build interval from given bounds, rejecting `NaN` endpoints
+    ///
+    /// `Interval::new` silently turns an interval with a `NaN` endpoint into `EMPTY`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalError, Closed, Unbound};
+    ///
+    /// assert_eq!(Interval::try_new(Closed(1.), Unbound), Ok(Interval::new(Closed(1.), Unbound)));
+    /// assert_eq!(Interval::try_new(Unbound, Closed(f64::NAN)), Err(IntervalError::NanEndpoint));
+    /// ```
+    ///
+    pub fn try_new(b1: Bound, b2: Bound) -> Result<Self, IntervalError> {
+        match (b1, b2) {
+            (Closed(k) | Open(k), _) | (_, Closed(k) | Open(k)) if k.is_nan() => {
+                Err(IntervalError::NanEndpoint)
+            }
+            _ => Ok(Interval::new(b1, b2)),
+        }
+    }
+
+    /// Interval `[k,k]` (`[0,0]` for `-0.`), or `EMPTY` if `k` is `NaN`
+    ///
+    pub const fn singleton(k: f64) -> Self {
+        if k.is_nan() {
+            return EMPTY;
+        }
+        Interval(Left(Closed(k + 0.)), Right(Closed(k + 0.)))
     }
 
     pub fn is_singleton(&self) -> bool {
@@ -83,6 +221,446 @@ impl Interval {
         self == EMPTY
     }
 
+    /// Check if interval is (topologically) open: no endpoint is closed
+    ///
+    /// `EMPTY` and `INFINITY` are both open and closed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// assert!(Interval::new(Open(42.), Unbound).is_open());
+    /// assert!(!Interval::new(Open(42.), Closed(43.)).is_open());
+    /// ```
+    ///
+    pub fn is_open(&self) -> bool {
+        self.is_empty() || !matches!((self.left(), self.right()), (Closed(_), _) | (_, Closed(_)))
+    }
+
+    /// Check if interval is (topologically) closed: no endpoint is open
+    ///
+    /// `EMPTY` and `INFINITY` are both open and closed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// assert!(Interval::new(Unbound, Closed(42.)).is_closed());
+    /// assert!(!Interval::new(Open(42.), Closed(43.)).is_closed());
+    /// ```
+    ///
+    pub fn is_closed(&self) -> bool {
+        self.is_empty() || !matches!((self.left(), self.right()), (Open(_), _) | (_, Open(_)))
+    }
+
+    /// Check if interval has one open and one closed finite endpoint (ie. `[a,b)` or `(a,b]`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// assert!(Interval::new(Closed(42.), Open(43.)).is_half_open());
+    /// assert!(!Interval::new(Closed(42.), Unbound).is_half_open());
+    /// ```
+    ///
+    pub fn is_half_open(&self) -> bool {
+        !self.is_empty()
+            && matches!(
+                (self.left(), self.right()),
+                (Open(_), Closed(_)) | (Closed(_), Open(_))
+            )
+    }
+
+    /// Check if interval is bounded: no endpoint is `Unbound`
+    ///
+    /// `EMPTY` is bounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// assert!(Interval::new(Open(42.), Closed(43.)).is_bounded());
+    /// assert!(!Interval::new(Closed(42.), Unbound).is_bounded());
+    /// ```
+    ///
+    pub fn is_bounded(&self) -> bool {
+        self.is_left_bounded() && self.is_right_bounded()
+    }
+
+    /// Check if left endpoint is not `Unbound` (`EMPTY` is bounded)
+    ///
+    pub fn is_left_bounded(&self) -> bool {
+        !matches!(self.left(), Unbound)
+    }
+
+    /// Check if right endpoint is not `Unbound` (`EMPTY` is bounded)
+    ///
+    pub fn is_right_bounded(&self) -> bool {
+        !matches!(self.right(), Unbound)
+    }
+
+    /// Check if left endpoint is `Open` (always false for `EMPTY`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Open(42.), Closed(43.));
+    ///
+    /// assert!(a.is_left_open());
+    /// assert!(a.is_right_closed());
+    /// assert!(!Interval::new(Unbound, Closed(43.)).is_left_open());
+    /// ```
+    ///
+    pub fn is_left_open(&self) -> bool {
+        !self.is_empty() && matches!(self.left(), Open(_))
+    }
+
+    /// Check if left endpoint is `Closed` (always false for `EMPTY`)
+    ///
+    pub fn is_left_closed(&self) -> bool {
+        !self.is_empty() && matches!(self.left(), Closed(_))
+    }
+
+    /// Check if right endpoint is `Open` (always false for `EMPTY`)
+    ///
+    pub fn is_right_open(&self) -> bool {
+        !self.is_empty() && matches!(self.right(), Open(_))
+    }
+
+    /// Check if right endpoint is `Closed` (always false for `EMPTY`)
+    ///
+    pub fn is_right_closed(&self) -> bool {
+        !self.is_empty() && matches!(self.right(), Closed(_))
+    }
+
+    /// Check if interval is degenerate: it contains at most one point (ie. empty or singleton)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// assert!(Interval::singleton(42.).is_degenerate());
+    /// assert!(EMPTY.is_degenerate());
+    /// assert!(!Interval::new(Closed(42.), Open(43.)).is_degenerate());
+    /// ```
+    ///
+    pub fn is_degenerate(&self) -> bool {
+        self.is_empty() || self.is_singleton()
+    }
+
+    /// Check if `x` belongs to interval
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(42.), Open(43.));
+    ///
+    /// assert!(a.contains(42.));
+    /// assert!(!a.contains(43.));
+    /// ```
+    ///
+    pub fn contains(&self, x: f64) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        let above_left = match self.left() {
+            Closed(k) => x >= k,
+            Open(k) => x > k,
+            Unbound => !x.is_nan(),
+        };
+        let below_right = match self.right() {
+            Closed(k) => x <= k,
+            Open(k) => x < k,
+            Unbound => !x.is_nan(),
+        };
+        above_left && below_right
+    }
+
+    /// Nearest point of interval to `x`
+    ///
+    /// When `x` lies beyond an open endpoint, the endpoint is nudged inward by one ULP (ie. the
+    /// closest representable `f64` inside the interval).
+    ///
+    /// # Returns
+    ///
+    /// `None` for `EMPTY`, for a `NaN` input, or if interval holds no representable `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert_eq!(a.clamp(0.5), Some(0.5));
+    /// assert_eq!(a.clamp(-3.), Some(0.));
+    /// assert_eq!(a.clamp(3.), Some(1f64.next_down()));
+    /// assert_eq!(EMPTY.clamp(3.), None);
+    /// ```
+    ///
+    pub fn clamp(&self, x: f64) -> Option<f64> {
+        if self.is_empty() || x.is_nan() {
+            return None;
+        }
+        let nearest = match (self.left(), self.right()) {
+            (Closed(k), _) if x < k => k,
+            (Open(k), _) if x <= k => k.next_up(),
+            (_, Closed(k)) if x > k => k,
+            (_, Open(k)) if x >= k => k.next_down(),
+            _ => x,
+        };
+        if self.contains(nearest) {
+            Some(nearest)
+        } else {
+            None
+        }
+    }
+
+    /// Left endpoint of interval
+    ///
+    /// Note that `EMPTY` is stored as `(0,0)`, hence its left endpoint is `Open(0.)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Open(42.), Closed(43.));
+    /// let b = Interval::new(Unbound, Closed(43.));
+    ///
+    /// assert_eq!(a.left(), Open(42.));
+    /// assert_eq!(b.left(), Unbound);
+    /// ```
+    ///
+    pub fn left(&self) -> Bound {
+        let Interval(Left(bound), _) = self;
+        *bound
+    }
+
+    /// Right endpoint of interval
+    ///
+    /// Note that `EMPTY` is stored as `(0,0)`, hence its right endpoint is `Open(0.)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Open(42.), Closed(43.));
+    /// let b = Interval::new(Closed(42.), Unbound);
+    ///
+    /// assert_eq!(a.right(), Closed(43.));
+    /// assert_eq!(b.right(), Unbound);
+    /// ```
+    ///
+    pub fn right(&self) -> Bound {
+        let Interval(_, Right(bound)) = self;
+        *bound
+    }
+
+    /// Destructure interval into its left and right bounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Open(42.), Unbound);
+    ///
+    /// match a.into_bounds() {
+    ///     (Open(k), Unbound) => assert_eq!(k, 42.),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ///
+    pub fn into_bounds(self) -> (Bound, Bound) {
+        let Interval(Left(left), Right(right)) = self;
+        (left, right)
+    }
+
+    /// Build a new interval replacing left endpoint
+    ///
+    /// The result is normalized as in `Interval::new` (ie. it may become `EMPTY`). Since `EMPTY`
+    /// is stored as `(0,0)`, replacing an endpoint of `EMPTY` keeps `Open(0.)` as other endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    ///
+    /// assert_eq!(a.with_left(Open(5.)), Interval::new(Open(5.), Closed(10.)));
+    /// assert_eq!(a.with_left(Open(10.)), EMPTY);
+    /// assert_eq!(EMPTY.with_left(Closed(-1.)), Interval::new(Closed(-1.), Open(0.)));
+    /// ```
+    ///
+    pub fn with_left(self, left: Bound) -> Self {
+        Interval::new(left, self.right())
+    }
+
+    /// Build a new interval replacing right endpoint
+    ///
+    /// The result is normalized as in `Interval::new` (ie. it may become `EMPTY`). Since `EMPTY`
+    /// is stored as `(0,0)`, replacing an endpoint of `EMPTY` keeps `Open(0.)` as other endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    ///
+    /// assert_eq!(a.with_right(Open(5.)), Interval::new(Closed(0.), Open(5.)));
+    /// assert_eq!(a.with_right(Closed(-1.)), EMPTY);
+    /// ```
+    ///
+    pub fn with_right(self, right: Bound) -> Self {
+        Interval::new(self.left(), right)
+    }
+
+    /// Infimum (greatest lower bound) of interval
+    ///
+    /// # Returns
+    ///
+    /// `None` for the empty set, `Some(f64::NEG_INFINITY)` if interval is unbounded on the left,
+    /// the value of the left endpoint otherwise (whether open or closed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound, EMPTY};
+    ///
+    /// assert_eq!(Interval::new(Open(42.), Closed(43.)).inf(), Some(42.));
+    /// assert_eq!(Interval::new(Unbound, Closed(43.)).inf(), Some(f64::NEG_INFINITY));
+    /// assert_eq!(EMPTY.inf(), None);
+    /// ```
+    ///
+    pub fn inf(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        match self.left() {
+            Closed(k) | Open(k) => Some(k),
+            Unbound => Some(f64::NEG_INFINITY),
+        }
+    }
+
+    /// Supremum (least upper bound) of interval
+    ///
+    /// # Returns
+    ///
+    /// `None` for the empty set, `Some(f64::INFINITY)` if interval is unbounded on the right,
+    /// the value of the right endpoint otherwise (whether open or closed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound, EMPTY};
+    ///
+    /// assert_eq!(Interval::new(Open(42.), Closed(43.)).sup(), Some(43.));
+    /// assert_eq!(Interval::new(Closed(42.), Unbound).sup(), Some(f64::INFINITY));
+    /// assert_eq!(EMPTY.sup(), None);
+    /// ```
+    ///
+    pub fn sup(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        match self.right() {
+            Closed(k) | Open(k) => Some(k),
+            Unbound => Some(f64::INFINITY),
+        }
+    }
+
+    /// Topological closure of interval: finite open endpoints become closed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Open(42.), Unbound);
+    ///
+    /// assert_eq!(a.closure(), Interval::new(Closed(42.), Unbound));
+    /// ```
+    ///
+    pub fn closure(self) -> Self {
+        if self.is_empty() {
+            return EMPTY;
+        }
+        let close = |bound| match bound {
+            Open(k) => Closed(k),
+            bound => bound,
+        };
+        Interval::new(close(self.left()), close(self.right()))
+    }
+
+    /// Topological interior of interval: closed endpoints become open
+    ///
+    /// Interior of a singleton is `EMPTY`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(42.), Closed(43.));
+    ///
+    /// assert_eq!(a.interior(), Interval::new(Open(42.), Open(43.)));
+    /// assert_eq!(Interval::singleton(42.).interior(), EMPTY);
+    /// ```
+    ///
+    pub fn interior(self) -> Self {
+        if self.is_empty() {
+            return EMPTY;
+        }
+        let open = |bound| match bound {
+            Closed(k) => Open(k),
+            bound => bound,
+        };
+        Interval::new(open(self.left()), open(self.right()))
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Topological boundary of interval: the set of its finite endpoints
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Open(42.), Closed(43.));
+    /// let b = Interval::new(Unbound, Closed(43.));
+    ///
+    /// assert_eq!(
+    ///     a.boundary(),
+    ///     IntervalSet::from(&[Interval::singleton(42.), Interval::singleton(43.)])
+    /// );
+    /// assert_eq!(b.boundary(), IntervalSet::from(&[Interval::singleton(43.)]));
+    /// ```
+    ///
+    pub fn boundary(self) -> IntervalSet {
+        if self.is_empty() {
+            return IntervalSet::new();
+        }
+        IntervalSet::union_all([self.left(), self.right()].into_iter().filter_map(|bound| {
+            match bound {
+                Open(k) | Closed(k) => Some(Interval::singleton(k)),
+                Unbound => None,
+            }
+        }))
+    }
+
     pub fn union(self, other: Interval) -> (Interval, Option<Interval>) {
         match (self, other) {
             (a, Interval(Left(Open(k1)), Right(Open(k2))))
@@ -97,7 +675,7 @@ impl Interval {
             }
 
             (Interval(a1, a2), Interval(b1, b2)) => {
-                if self.overlap(other) || self.adhere_to(other) {
+                if self.overlap(other) || self.is_adjacent(&other) {
                     (Interval(a1.min(b1), a2.max(b2)), None)
                 } else if b1 > a2 {
                     (self, Some(other))
@@ -108,6 +686,84 @@ impl Interval {
         }
     }
 
+    /// Smallest interval containing both intervals
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    /// let b = Interval::new(Open(2.), Closed(3.));
+    ///
+    /// assert_eq!(a.hull(b), Interval::new(Closed(0.), Closed(3.)));
+    /// assert_eq!(a.hull(EMPTY), a);
+    /// ```
+    ///
+    pub fn hull(self, other: Interval) -> Interval {
+        match self.union(other) {
+            (lower, Some(upper)) => Interval::new(lower.left(), upper.right()),
+            (joint, None) => joint,
+        }
+    }
+
+    /// Intersection of two intervals
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(2.));
+    /// let b = Interval::new(Open(1.), Unbound);
+    ///
+    /// assert_eq!(a.intersection(b), Interval::new(Open(1.), Open(2.)));
+    /// assert_eq!(a.intersection(Interval::new(Closed(2.), Unbound)), EMPTY);
+    /// ```
+    ///
+    pub fn intersection(self, other: Interval) -> Interval {
+        if !self.overlap(other) {
+            return EMPTY;
+        }
+        let (Interval(a1, a2), Interval(b1, b2)) = (self, other);
+        let (Left(left), Right(right)) = (a1.max(b1), a2.min(b2));
+        Interval::new(left, right)
+    }
+
+    /// Intersection of any number of intervals
+    ///
+    /// Stops consuming `intervals` as soon as the running intersection becomes empty.
+    ///
+    /// # Returns
+    ///
+    /// `INFINITY` if there is no interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound, EMPTY, INFINITY};
+    ///
+    /// let constraints = [
+    ///     Interval::new(Closed(0.), Unbound),
+    ///     Interval::new(Unbound, Open(10.)),
+    ///     Interval::new(Closed(5.), Closed(20.)),
+    /// ];
+    ///
+    /// assert_eq!(Interval::intersection_all(constraints), Interval::new(Closed(5.), Open(10.)));
+    /// assert_eq!(Interval::intersection_all([]), INFINITY);
+    /// ```
+    ///
+    pub fn intersection_all(intervals: impl IntoIterator<Item = Interval>) -> Interval {
+        let mut res = INFINITY;
+        for segment in intervals {
+            res = res.intersection(segment);
+            if res.is_empty() {
+                return EMPTY;
+            }
+        }
+        res
+    }
+
     /// Check if intervals overlap
     ///
     /// Note that `Interval(Left(Open(0.)),Right(Open(0.)))` overlap nothing.
@@ -126,16 +782,35 @@ impl Interval {
         }
     }
 
-    /// Check if interval endpoints could rejoin (ie ]2 and (2, (2 and 2] ...)
+    /// Check if intervals are adjacent: they touch without overlapping
+    ///
+    /// Two intervals are adjacent when they share an endpoint value, closed on one side and open
+    /// on the other (ie `2]` and `(2`, `2)` and `[2`), so that their union is a single interval
+    /// while their intersection is empty. `[1,2]` and `[2,3]` overlap on `{2}` hence are not
+    /// adjacent, and `[1,2)` and `(2,3]` leave a hole at `2`. Empty set is adjacent to nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(1.), Open(2.));
+    /// let b = Interval::new(Closed(2.), Closed(3.));
+    /// let c = Interval::new(Open(2.), Closed(3.));
+    ///
+    /// assert!(a.is_adjacent(&b));
+    /// assert!(b.is_adjacent(&a));
+    /// assert!(!a.is_adjacent(&c));
+    /// ```
     ///
-    fn adhere_to(self, other: Interval) -> bool {
-        if self.is_empty() || other.is_empty() {
+    pub fn is_adjacent(&self, other: &Interval) -> bool {
+        if self.is_empty() || other.is_empty() || self.overlap(*other) {
             return false;
         }
         match (self, other) {
             (Interval(Left(Unbound), Right(Unbound)), _)
             | (_, Interval(Left(Unbound), Right(Unbound))) => false,
-            (Interval(a1, a2), Interval(b1, b2)) => a1.closure(b2) || a2.closure(b1),
+            (Interval(a1, a2), Interval(b1, b2)) => a1.closure(*b2) || a2.closure(*b1),
         }
     }
 }
@@ -167,6 +842,71 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_new_empty_1() {
+        let empties = [
+            Interval::new(Open(5.), Open(5.)),
+            Interval::new(Closed(5.), Open(5.)),
+            Interval::new(Closed(6.), Closed(5.)),
+            Interval::new(Closed(f64::NAN), Closed(5.)),
+            Interval::new(Unbound, Open(f64::NAN)),
+            Interval::new(Open(f64::INFINITY), Unbound),
+            Interval::new(Unbound, Open(f64::NEG_INFINITY)),
+            Interval::singleton(f64::NAN),
+        ];
+        for a in empties {
+            assert_eq!(a, EMPTY);
+            assert!(a.is_empty());
+            assert_eq!(format!("{a}"), "∅");
+        }
+        assert!(Interval::new(Closed(f64::INFINITY), Unbound).contains(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_signed_zero_1() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |a: Interval| {
+            let mut hasher = DefaultHasher::new();
+            a.hash(&mut hasher);
+            hasher.finish()
+        };
+        let a = Interval::new(Closed(-0.), Closed(0.));
+        let b = Interval::singleton(-0.);
+        assert!(a.is_singleton() && b.is_singleton());
+        assert_eq!(a, Interval::singleton(0.));
+        assert_eq!(hash(a), hash(Interval::singleton(0.)));
+        assert_eq!(hash(b), hash(a));
+        assert_eq!(format!("{a}"), format!("{}", Interval::singleton(0.)));
+        let c = Interval::new(Open(-1.), Open(-0.));
+        assert_eq!(c.right(), Open(0.));
+        assert!(c.sup().unwrap().is_sign_positive());
+        assert_ne!(hash(c), hash(Interval::new(Open(-1.), Closed(0.))));
+    }
+
+    #[test]
+    fn test_eq_f64_1() {
+        assert_eq!(Interval::from(-0.), 0.);
+        assert_eq!(Interval::from(f64::NAN), EMPTY);
+        assert_ne!(EMPTY, f64::NAN);
+        assert_ne!(Interval::new(Closed(1.), Open(2.)), 1.);
+        assert_ne!(Interval::new(Closed(1.), Unbound), f64::INFINITY);
+        assert_eq!(Interval::singleton(f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_try_new_1() {
+        assert_eq!(Interval::try_new(Open(2.), Open(1.)), Ok(EMPTY));
+        assert_eq!(
+            Interval::try_new(Closed(f64::NAN), Unbound),
+            Err(IntervalError::NanEndpoint)
+        );
+        assert_eq!(
+            Interval::try_new(Open(0.), Open(f64::NAN)),
+            Err(IntervalError::NanEndpoint)
+        );
+    }
+
     #[test]
     fn test_singleton_1() {
         let a = Interval::new(Closed(42.), Open(43.));
@@ -185,6 +925,18 @@ mod test {
         assert!(a.is_singleton());
     }
 
+    #[test]
+    fn test_const_1() {
+        const A: Interval = Interval::new(Closed(0.), Open(1.));
+        const B: Interval = Interval::new(Closed(1.), Open(0.));
+        const C: Interval = Interval::new(Unbound, Unbound);
+        static D: Interval = Interval::singleton(42.);
+        assert_eq!(A, Interval::new(Closed(0.), Open(1.)));
+        assert_eq!(B, EMPTY);
+        assert_eq!(C, INFINITY);
+        assert!(D.is_singleton());
+    }
+
     #[test]
     fn test_overlap_1() {
         let a = Interval::new(Unbound, Unbound);
@@ -372,51 +1124,68 @@ mod test {
     }
 
     #[test]
-    fn test_adhere_1() {
+    fn test_adjacent_1() {
         let a = Interval::new(Open(42.), Unbound);
         let b = Interval::new(Unbound, Closed(42.));
 
-        assert!(a.adhere_to(b));
+        assert!(a.is_adjacent(&b));
     }
 
     #[test]
-    fn test_adhere_2() {
+    fn test_adjacent_2() {
         let a = Interval::new(Open(42.), Unbound);
         let b = Interval::new(Unbound, Open(42.));
 
-        assert!(!a.adhere_to(b));
+        assert!(!a.is_adjacent(&b));
     }
 
     #[test]
-    fn test_adhere_3() {
+    fn test_adjacent_3() {
         let a = Interval::new(Unbound, Open(42.));
         let b = Interval::new(Closed(42.), Unbound);
 
-        assert!(a.adhere_to(b));
+        assert!(a.is_adjacent(&b));
     }
 
     #[test]
-    fn test_adhere_4() {
+    fn test_adjacent_4() {
         let a = Interval::new(Unbound, Open(42.));
         let b = Interval::new(Open(42.), Unbound);
 
-        assert!(!a.adhere_to(b));
+        assert!(!a.is_adjacent(&b));
     }
 
     #[test]
-    fn test_adhere_5() {
+    fn test_adjacent_5() {
         let a = INFINITY;
         let b = Interval::new(Open(42.), Unbound);
 
-        assert!(!a.adhere_to(b));
+        assert!(!a.is_adjacent(&b));
     }
 
     #[test]
-    fn test_adhere_6() {
+    fn test_adjacent_6() {
         let a = EMPTY;
         let b = Interval::new(Open(42.), Unbound);
 
-        assert!(!a.adhere_to(b));
+        assert!(!a.is_adjacent(&b));
+    }
+
+    #[test]
+    fn test_adjacent_7() {
+        let a = Interval::new(Closed(41.), Closed(42.));
+        let b = Interval::new(Closed(42.), Closed(43.));
+
+        assert!(!a.is_adjacent(&b));
+    }
+
+    #[test]
+    fn test_adjacent_8() {
+        let a = Interval::new(Closed(41.), Open(42.));
+        let b = Interval::new(Closed(42.), Closed(43.));
+
+        assert!(a.is_adjacent(&b));
+        assert!(b.is_adjacent(&a));
     }
 
     #[test]
@@ -512,6 +1281,53 @@ mod test {
         let b = Interval::new(Closed(43.), Unbound);
         assert_eq!(b.union(a), (Interval::new(Open(42.), Unbound), None));
     }
+    #[test]
+    fn test_intersection_1() {
+        let a = Interval::new(Closed(42.), Closed(52.));
+        let b = Interval::new(Open(42.), Open(52.));
+        assert_eq!(a.intersection(b), b);
+        assert_eq!(b.intersection(a), b);
+    }
+
+    #[test]
+    fn test_intersection_2() {
+        let a = Interval::new(Closed(42.), Closed(52.));
+        let b = Interval::new(Closed(52.), Unbound);
+        assert_eq!(a.intersection(b), Interval::singleton(52.));
+        assert_eq!(a.intersection(INFINITY), a);
+        assert_eq!(INFINITY.intersection(INFINITY), INFINITY);
+        assert_eq!(a.intersection(EMPTY), EMPTY);
+    }
+
+    #[test]
+    fn test_intersection_3() {
+        let a = Interval::new(Closed(42.), Open(52.));
+        let b = Interval::new(Closed(52.), Unbound);
+        assert_eq!(a.intersection(b), EMPTY);
+    }
+
+    #[test]
+    fn test_intersection_all_1() {
+        let mut consumed = 0;
+        let segments = [
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(2.), Closed(3.)),
+            Interval::new(Closed(0.), Closed(3.)),
+        ]
+        .into_iter()
+        .inspect(|_| consumed += 1);
+        assert_eq!(Interval::intersection_all(segments), EMPTY);
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn test_intersection_all_2() {
+        let a = Interval::new(Open(0.), Closed(2.));
+        let b = Interval::new(Closed(2.), Unbound);
+        assert_eq!(Interval::intersection_all([a, b]), Interval::singleton(2.));
+        assert_eq!(Interval::intersection_all([a]), a);
+    }
+
     #[test]
     fn test_build_1() {
         assert!(matches!(
@@ -629,6 +1445,255 @@ mod test {
         assert!(EMPTY.is_empty());
     }
 
+    #[test]
+    fn test_shape_1() {
+        let a = Interval::new(Open(42.), Open(43.));
+        assert!(a.is_open());
+        assert!(!a.is_closed());
+        assert!(!a.is_half_open());
+        assert!(a.is_bounded());
+        assert!(!a.is_degenerate());
+    }
+
+    #[test]
+    fn test_shape_2() {
+        let a = Interval::new(Closed(42.), Closed(43.));
+        assert!(!a.is_open());
+        assert!(a.is_closed());
+        assert!(!a.is_half_open());
+        assert!(a.is_bounded());
+        assert!(!a.is_degenerate());
+    }
+
+    #[test]
+    fn test_shape_3() {
+        let a = Interval::new(Open(42.), Closed(43.));
+        assert!(!a.is_open());
+        assert!(!a.is_closed());
+        assert!(a.is_half_open());
+        assert!(a.is_bounded());
+    }
+
+    #[test]
+    fn test_shape_4() {
+        for a in [EMPTY, INFINITY] {
+            assert!(a.is_open());
+            assert!(a.is_closed());
+            assert!(!a.is_half_open());
+        }
+        assert!(EMPTY.is_bounded());
+        assert!(!INFINITY.is_bounded());
+    }
+
+    #[test]
+    fn test_shape_5() {
+        let a = Interval::new(Unbound, Closed(43.));
+        assert!(!a.is_open());
+        assert!(a.is_closed());
+        assert!(!a.is_half_open());
+        assert!(!a.is_bounded());
+    }
+
+    #[test]
+    fn test_shape_6() {
+        let a = Interval::singleton(42.);
+        assert!(a.is_closed());
+        assert!(a.is_bounded());
+        assert!(a.is_degenerate());
+    }
+
+    #[test]
+    fn test_sides_1() {
+        let a = Interval::new(Unbound, Open(43.));
+        assert!(!a.is_left_bounded());
+        assert!(a.is_right_bounded());
+        assert!(!a.is_left_open());
+        assert!(!a.is_left_closed());
+        assert!(a.is_right_open());
+        assert!(!a.is_right_closed());
+    }
+
+    #[test]
+    fn test_sides_2() {
+        let a = Interval::new(Closed(42.), Unbound);
+        assert!(a.is_left_bounded());
+        assert!(!a.is_right_bounded());
+        assert!(a.is_left_closed());
+        assert!(!a.is_right_open());
+        assert!(!a.is_right_closed());
+    }
+
+    #[test]
+    fn test_sides_3() {
+        assert!(EMPTY.is_left_bounded());
+        assert!(EMPTY.is_right_bounded());
+        assert!(!EMPTY.is_left_open());
+        assert!(!EMPTY.is_right_open());
+    }
+
+    #[test]
+    fn test_contains_1() {
+        let a = Interval::new(Open(42.), Closed(43.));
+        assert!(!a.contains(42.));
+        assert!(a.contains(42.5));
+        assert!(a.contains(43.));
+        assert!(!a.contains(f64::NAN));
+    }
+
+    #[test]
+    fn test_contains_2() {
+        assert!(INFINITY.contains(42.));
+        assert!(!INFINITY.contains(f64::NAN));
+        assert!(!EMPTY.contains(0.));
+    }
+
+    #[test]
+    fn test_clamp_1() {
+        let a = Interval::new(Closed(42.), Closed(43.));
+        assert_eq!(a.clamp(0.), Some(42.));
+        assert_eq!(a.clamp(42.5), Some(42.5));
+        assert_eq!(a.clamp(100.), Some(43.));
+    }
+
+    #[test]
+    fn test_clamp_2() {
+        let a = Interval::new(Open(42.), Unbound);
+        assert_eq!(a.clamp(42.), Some(42f64.next_up()));
+        assert_eq!(a.clamp(1e300), Some(1e300));
+    }
+
+    #[test]
+    fn test_clamp_3() {
+        let a = Interval::new(Open(42.), Open(42f64.next_up()));
+        assert_eq!(a.clamp(42.), None);
+        assert_eq!(INFINITY.clamp(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_endpoints_1() {
+        let a = Interval::new(Closed(42.), Open(43.));
+        assert_eq!(a.left(), Closed(42.));
+        assert_eq!(a.right(), Open(43.));
+    }
+
+    #[test]
+    fn test_endpoints_2() {
+        assert_eq!(INFINITY.left(), Unbound);
+        assert_eq!(INFINITY.right(), Unbound);
+    }
+
+    #[test]
+    fn test_into_bounds_1() {
+        let a = Interval::new(Closed(42.), Open(43.));
+        assert_eq!(a.into_bounds(), (Closed(42.), Open(43.)));
+    }
+
+    #[test]
+    fn test_into_bounds_2() {
+        let (left, right): (Bound, Bound) = Interval::new(Unbound, Closed(43.)).into();
+        assert_eq!((left, right), (Unbound, Closed(43.)));
+    }
+
+    #[test]
+    fn test_into_bounds_3() {
+        let (left, right) = Interval::new(Open(42.), Open(43.)).into_bounds();
+        assert_eq!(
+            Interval::new(left, right),
+            Interval::new(Open(42.), Open(43.))
+        );
+    }
+
+    #[test]
+    fn test_with_1() {
+        let a = Interval::new(Closed(42.), Open(43.));
+        assert_eq!(a.with_left(Unbound), Interval::new(Unbound, Open(43.)));
+        assert_eq!(a.with_right(Unbound), Interval::new(Closed(42.), Unbound));
+    }
+
+    #[test]
+    fn test_with_2() {
+        let a = Interval::new(Closed(42.), Unbound);
+        assert_eq!(a.with_left(Unbound), INFINITY);
+    }
+
+    #[test]
+    fn test_with_3() {
+        let a = Interval::new(Closed(42.), Open(43.));
+        assert_eq!(a.with_left(Closed(43.)), EMPTY);
+        assert_eq!(a.with_right(Open(42.)), EMPTY);
+        assert_eq!(a.with_right(Closed(42.)), Interval::singleton(42.));
+    }
+
+    #[test]
+    fn test_with_4() {
+        // EMPTY is (0,0): replacing one endpoint may give a non-empty interval
+        assert_eq!(EMPTY.with_left(Unbound), Interval::new(Unbound, Open(0.)));
+        assert_eq!(
+            EMPTY.with_right(Closed(1.)),
+            Interval::new(Open(0.), Closed(1.))
+        );
+        assert_eq!(EMPTY.with_left(Closed(0.)), EMPTY);
+        assert_eq!(EMPTY.with_right(Open(-1.)), EMPTY);
+    }
+
+    #[test]
+    fn test_closure_1() {
+        let a = Interval::new(Open(42.), Open(43.));
+        assert_eq!(a.closure(), Interval::new(Closed(42.), Closed(43.)));
+    }
+
+    #[test]
+    fn test_closure_2() {
+        assert_eq!(EMPTY.closure(), EMPTY);
+        assert_eq!(INFINITY.closure(), INFINITY);
+    }
+
+    #[test]
+    fn test_interior_1() {
+        let a = Interval::new(Closed(42.), Unbound);
+        assert_eq!(a.interior(), Interval::new(Open(42.), Unbound));
+    }
+
+    #[test]
+    fn test_interior_2() {
+        assert_eq!(EMPTY.interior(), EMPTY);
+        assert_eq!(INFINITY.interior(), INFINITY);
+        assert_eq!(Interval::singleton(42.).interior(), EMPTY);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_boundary_1() {
+        assert!(EMPTY.boundary().is_empty());
+        assert!(INFINITY.boundary().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_boundary_2() {
+        let a = Interval::singleton(42.);
+        assert_eq!(a.boundary(), IntervalSet::from(&[a]));
+    }
+
+    #[test]
+    fn test_inf_sup_1() {
+        let a = Interval::new(Closed(42.), Open(43.));
+        assert_eq!(a.inf(), Some(42.));
+        assert_eq!(a.sup(), Some(43.));
+    }
+
+    #[test]
+    fn test_inf_sup_2() {
+        assert_eq!(INFINITY.inf(), Some(f64::NEG_INFINITY));
+        assert_eq!(INFINITY.sup(), Some(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inf_sup_3() {
+        assert_eq!(EMPTY.inf(), None);
+        assert_eq!(EMPTY.sup(), None);
+    }
+
     #[test]
     fn test_display_1() {
         assert_eq!(format!("{}", EMPTY), "∅");
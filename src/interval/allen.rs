@@ -0,0 +1,225 @@
+use core::cmp::Ordering;
+
+use super::Interval;
+
+/// The thirteen relations of Allen's interval algebra
+///
+/// Relations are computed on endpoints ordering, taking open and closed bounds into account:
+/// `[0,1)` starts `[0,1]`, and `[0,1]` meets `[1,2]` as well as `[1,2)`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AllenRelation {
+    /// `self` ends before `other` starts, with a gap between them
+    Precedes,
+    /// `self` ends where `other` starts
+    Meets,
+    /// `self` starts first, and ends inside `other`
+    Overlaps,
+    /// `self` and `other` start together, `self` ends first
+    Starts,
+    /// `self` is strictly inside `other`
+    During,
+    /// `self` and `other` end together, `self` starts last
+    Finishes,
+    /// `self` and `other` are equal
+    Equals,
+    /// Inverse of `Precedes`
+    PrecededBy,
+    /// Inverse of `Meets`
+    MetBy,
+    /// Inverse of `Overlaps`
+    OverlappedBy,
+    /// Inverse of `Starts`
+    StartedBy,
+    /// Inverse of `During`
+    Contains,
+    /// Inverse of `Finishes`
+    FinishedBy,
+}
+
+impl AllenRelation {
+    /// Relation obtained by swapping operands
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::AllenRelation;
+    ///
+    /// assert_eq!(AllenRelation::Starts.inverse(), AllenRelation::StartedBy);
+    /// assert_eq!(AllenRelation::Equals.inverse(), AllenRelation::Equals);
+    /// ```
+    ///
+    pub fn inverse(self) -> Self {
+        use AllenRelation::*;
+
+        match self {
+            Precedes => PrecededBy,
+            Meets => MetBy,
+            Overlaps => OverlappedBy,
+            Starts => StartedBy,
+            During => Contains,
+            Finishes => FinishedBy,
+            Equals => Equals,
+            PrecededBy => Precedes,
+            MetBy => Meets,
+            OverlappedBy => Overlaps,
+            StartedBy => Starts,
+            Contains => During,
+            FinishedBy => Finishes,
+        }
+    }
+}
+
+impl Interval {
+    /// Classify the relation of `self` to `other` in Allen's interval algebra
+    ///
+    /// # Returns
+    ///
+    /// `None` if one of the intervals is empty, the relation otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{AllenRelation, Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    /// let b = Interval::new(Closed(1.), Closed(2.));
+    /// let c = Interval::new(Closed(0.), Closed(2.));
+    ///
+    /// assert_eq!(a.relation_to(&b), Some(AllenRelation::Meets));
+    /// assert_eq!(a.relation_to(&c), Some(AllenRelation::Starts));
+    /// assert_eq!(c.relation_to(&b), Some(AllenRelation::FinishedBy));
+    /// ```
+    ///
+    pub fn relation_to(&self, other: &Interval) -> Option<AllenRelation> {
+        use AllenRelation::*;
+
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+
+        let (Interval(a1, a2), Interval(b1, b2)) = (self, other);
+
+        if a2 < b1 {
+            return Some(if a2.closure(*b1) { Meets } else { Precedes });
+        }
+        if b2 < a1 {
+            return Some(if b2.closure(*a1) { MetBy } else { PrecededBy });
+        }
+
+        let relation = match (a1.partial_cmp(b1), a2.partial_cmp(b2)) {
+            (Some(Ordering::Equal), Some(Ordering::Equal)) => Equals,
+            (Some(Ordering::Equal), Some(Ordering::Less)) => Starts,
+            (Some(Ordering::Equal), _) => StartedBy,
+            (Some(Ordering::Greater), Some(Ordering::Equal)) => Finishes,
+            (_, Some(Ordering::Equal)) => FinishedBy,
+            (Some(Ordering::Greater), Some(Ordering::Less)) => During,
+            (Some(Ordering::Less), Some(Ordering::Greater)) => Contains,
+            (Some(Ordering::Less), _) if a2 == b1 => Meets,
+            (Some(Ordering::Less), _) => Overlaps,
+            _ if b2 == a1 => MetBy,
+            _ => OverlappedBy,
+        };
+        Some(relation)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AllenRelation::*;
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_relation_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let b = Interval::new(Closed(2.), Closed(3.));
+        assert_eq!(a.relation_to(&b), Some(Precedes));
+        assert_eq!(b.relation_to(&a), Some(PrecededBy));
+    }
+
+    #[test]
+    fn test_relation_2() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Open(1.), Closed(3.));
+        assert_eq!(a.relation_to(&b), Some(Precedes));
+        assert_eq!(b.relation_to(&a), Some(PrecededBy));
+    }
+
+    #[test]
+    fn test_relation_3() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        for b in [
+            Interval::new(Closed(1.), Closed(3.)),
+            Interval::new(Open(1.), Closed(3.)),
+        ] {
+            assert_eq!(a.relation_to(&b), Some(Meets));
+            assert_eq!(b.relation_to(&a), Some(MetBy));
+        }
+    }
+
+    #[test]
+    fn test_relation_4() {
+        let a = Interval::new(Closed(0.), Closed(2.));
+        let b = Interval::new(Closed(1.), Closed(3.));
+        assert_eq!(a.relation_to(&b), Some(Overlaps));
+        assert_eq!(b.relation_to(&a), Some(OverlappedBy));
+    }
+
+    #[test]
+    fn test_relation_5() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.relation_to(&b), Some(Starts));
+        assert_eq!(b.relation_to(&a), Some(StartedBy));
+    }
+
+    #[test]
+    fn test_relation_6() {
+        let a = Interval::new(Closed(1.), Closed(2.));
+        let b = Interval::new(Open(0.), Unbound);
+        assert_eq!(a.relation_to(&b), Some(During));
+        assert_eq!(b.relation_to(&a), Some(Contains));
+    }
+
+    #[test]
+    fn test_relation_7() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        let b = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.relation_to(&b), Some(Finishes));
+        assert_eq!(b.relation_to(&a), Some(FinishedBy));
+    }
+
+    #[test]
+    fn test_relation_8() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert_eq!(a.relation_to(&a), Some(Equals));
+        assert_eq!(INFINITY.relation_to(&INFINITY), Some(Equals));
+    }
+
+    #[test]
+    fn test_relation_9() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert_eq!(a.relation_to(&EMPTY), None);
+        assert_eq!(EMPTY.relation_to(&a), None);
+    }
+
+    #[test]
+    fn test_relation_10() {
+        let intervals = [
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Open(0.), Open(1.)),
+            Interval::new(Closed(1.), Closed(3.)),
+            Interval::new(Unbound, Open(2.)),
+            Interval::new(Closed(0.5), Unbound),
+            Interval::singleton(1.),
+            INFINITY,
+        ];
+        for a in intervals.iter() {
+            for b in intervals.iter() {
+                let r = a.relation_to(b).unwrap();
+                assert_eq!(b.relation_to(a), Some(r.inverse()));
+            }
+        }
+    }
+}
@@ -0,0 +1,713 @@
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::bound::Bound::{self, Closed, Open, Unbound};
+use core::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use super::{Interval, EMPTY, INFINITY};
+use crate::{IntervalError, IntervalSet};
+
+/// Error-free sum: `a + b == s + e` exactly, for finite `s`
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Error-free product: `a * b == p + e` exactly, for finite `p`, with `0 * ∞ = 0`
+///
+/// When `p` underflows, `e` may be rounded too, but keeps the sign of the exact error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    if a == 0. || b == 0. {
+        return (0., 0.);
+    }
+    let p = a * b;
+    let e = a.mul_add(b, -p);
+    if p.abs() < f64::MIN_POSITIVE && e == 0. {
+        // error rounded to a signed zero, `+0.` standing for a null or tiny positive error
+        return (p, f64::from_bits(1).copysign(e));
+    }
+    (p, e)
+}
+
+/// Check if `a * b` falls below the normal range, where `two_product` is not error-free
+fn underflows(a: f64, b: f64) -> bool {
+    a != 0. && b != 0. && (a * b).abs() < f64::MIN_POSITIVE
+}
+
+/// Fused `a * b + c` with its error: `a * b + c == s + e` up to the rounding of `e`, which
+/// keeps its sign, for finite `a * b`
+fn two_mul_add(a: f64, b: f64, c: f64) -> (f64, f64) {
+    let (p, pe) = two_product(a, b);
+    let (s, e) = two_sum(p, c);
+    let (t, f) = two_sum(e, pe);
+    let (r, g) = two_sum(s, t);
+    (r, g + f)
+}
+
+/// Error-free reciprocal, up to the magnitude of the error: `1 / a == r + e`
+fn two_recip(a: f64) -> (f64, f64) {
+    let r = 1. / a;
+    (r, -a.mul_add(r, -1.) / a)
+}
+
+/// Error-free square root, up to the magnitude of the error: `√a == r + e`
+fn two_sqrt(a: f64) -> (f64, f64) {
+    let r = a.sqrt();
+    (r, -r.mul_add(r, -a))
+}
+
+/// `a^n` for `a >= 0`, by square-and-multiply rounded by `round` at each step
+fn pow_abs(a: f64, n: u32, round: fn((f64, f64)) -> f64) -> f64 {
+    let (mut base, mut n, mut res) = (a, n, 1.);
+    while n > 0 {
+        if n & 1 == 1 {
+            res = round(two_product(res, base));
+        }
+        base = round(two_product(base, base));
+        n >>= 1;
+    }
+    res
+}
+
+/// Range of a `2π`-periodic function `f` over `[lo, hi]`, `f` reaching `1` at `peak` and `-1`
+/// at `peak + π`
+fn trig_range(lo: f64, hi: f64, f: fn(f64) -> f64, peak: f64) -> Interval {
+    // Critical points closer than SLACK to the interval are taken as inside: this only costs
+    // about SLACK² on the result, and absorbs the rounding errors of the reduction
+    const SLACK: f64 = 1e-6;
+    if !(lo.abs() < 1e9 && hi.abs() < 1e9) || hi - lo >= TAU {
+        return Interval::new(Closed(-1.), Closed(1.));
+    }
+    let reaches = |c: f64| {
+        let k = ((lo - c) / TAU).ceil();
+        c + k * TAU <= hi + SLACK || c + (k - 1.) * TAU >= lo - SLACK
+    };
+    let (a, b) = (f(lo), f(hi));
+    let max = if reaches(peak) {
+        1.
+    } else {
+        a.max(b).next_up().min(1.)
+    };
+    let min = if reaches(peak + PI) {
+        -1.
+    } else {
+        a.min(b).next_down().max(-1.)
+    };
+    Interval::new(Closed(min), Closed(max))
+}
+
+/// Largest float below exact value `s + e`
+pub(crate) fn down((s, e): (f64, f64)) -> f64 {
+    if s == f64::INFINITY {
+        f64::MAX
+    } else if e < 0. {
+        s.next_down()
+    } else {
+        s
+    }
+}
+
+/// Smallest float above exact value `s + e`
+pub(crate) fn up((s, e): (f64, f64)) -> f64 {
+    if s == f64::NEG_INFINITY {
+        f64::MIN
+    } else if e > 0. {
+        s.next_up()
+    } else {
+        s
+    }
+}
+
+/// Closed bound at `k`, or no bound if `k` is infinite
+fn closed(k: f64) -> Bound {
+    if k.is_infinite() {
+        Unbound
+    } else {
+        Closed(k)
+    }
+}
+
+/// Sum of two lower bounds, or of two upper bounds, rounded by `round`
+fn add_bounds(a: Bound, b: Bound, round: fn((f64, f64)) -> f64) -> Bound {
+    match (a, b) {
+        (Unbound, _) | (_, Unbound) => Unbound,
+        (Closed(x), Closed(y)) => closed(round(two_sum(x, y))),
+        (Closed(x) | Open(x), Closed(y) | Open(y)) => match closed(round(two_sum(x, y))) {
+            Closed(k) => Open(k),
+            bound => bound,
+        },
+    }
+}
+
+/// Bound of `1 / k` on the other side, for a bound `k` of an interval not containing zero,
+/// rounded by `round`
+fn recip_bound(bound: Bound, round: fn((f64, f64)) -> f64) -> Bound {
+    match bound {
+        Unbound => Open(0.),
+        Open(0.) => Unbound,
+        Closed(k) => closed(round(two_recip(k))),
+        Open(k) => match closed(round(two_recip(k))) {
+            Closed(r) => Open(r),
+            bound => bound,
+        },
+    }
+}
+
+fn neg_bound(bound: Bound) -> Bound {
+    match bound {
+        Closed(k) => Closed(-k),
+        Open(k) => Open(-k),
+        Unbound => Unbound,
+    }
+}
+
+impl Interval {
+    fn sum(&self, other: &Interval) -> Interval {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        Interval::new(
+            add_bounds(self.left(), other.left(), down),
+            add_bounds(self.right(), other.right(), up),
+        )
+    }
+
+    fn product(&self, other: &Interval) -> Interval {
+        let (Some(a1), Some(a2), Some(b1), Some(b2)) =
+            (self.inf(), self.sup(), other.inf(), other.sup())
+        else {
+            return EMPTY;
+        };
+        let corners = [(a1, b1), (a1, b2), (a2, b1), (a2, b2)];
+        let lo = corners
+            .iter()
+            .map(|&(x, y)| down(two_product(x, y)))
+            .fold(f64::INFINITY, f64::min);
+        let hi = corners
+            .iter()
+            .map(|&(x, y)| up(two_product(x, y)))
+            .fold(f64::NEG_INFINITY, f64::max);
+        Interval::new(closed(lo), closed(hi))
+    }
+
+    /// Enclosure of `{ x * y + z | x ∈ self, y ∈ b, z ∈ c }`
+    ///
+    /// Each endpoint is rounded outward once, from its exact value, where `self * b + c`
+    /// rounds the product then the sum: the result is never wider, and often one ulp tighter.
+    /// Open endpoints of `c` are kept open, as in sums.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let third = Interval::singleton(1. / 3.);
+    /// let (three, one) = (Interval::singleton(3.), Interval::singleton(1.));
+    ///
+    /// // 1/3 is rounded down, so that 3 * (1/3) - 1 is exactly -2⁻⁵⁴
+    /// assert_eq!(third.mul_add(&three, &-one), Interval::singleton(-(2f64.powi(-54))));
+    /// assert_eq!(
+    ///     third * three - one,
+    ///     Interval::new(Closed(-(2f64.powi(-53))), Closed(0.))
+    /// );
+    /// ```
+    ///
+    pub fn mul_add(&self, b: &Interval, c: &Interval) -> Interval {
+        let (Some(a1), Some(a2), Some(b1), Some(b2), Some(c1), Some(c2)) =
+            (self.inf(), self.sup(), b.inf(), b.sup(), c.inf(), c.sup())
+        else {
+            return EMPTY;
+        };
+        let corners = [(a1, b1), (a1, b2), (a2, b1), (a2, b2)];
+        let exact = [c1, c2].iter().all(|k| k.is_finite())
+            && corners
+                .iter()
+                .all(|&(x, y)| (x * y).is_finite() && !underflows(x, y));
+        if !exact {
+            return self.product(b).sum(c);
+        }
+        let lo = corners
+            .iter()
+            .map(|&(x, y)| down(two_mul_add(x, y, c1)))
+            .fold(f64::INFINITY, f64::min);
+        let hi = corners
+            .iter()
+            .map(|&(x, y)| up(two_mul_add(x, y, c2)))
+            .fold(f64::NEG_INFINITY, f64::max);
+        let bound = |kind: Bound, k: f64| match (kind, closed(k)) {
+            (Open(_), Closed(k)) => Open(k),
+            (_, bound) => bound,
+        };
+        Interval::new(bound(c.left(), lo), bound(c.right(), hi))
+    }
+
+    /// Enclosure of `{ x² | x ∈ self }`
+    ///
+    /// Tighter than `self * self`, which does not know that both operands are the same value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let a = Interval::new(Closed(-1.), Closed(2.));
+    ///
+    /// assert_eq!(a.sqr(), Interval::new(Closed(0.), Closed(4.)));
+    /// assert_eq!(a * a, Interval::new(Closed(-2.), Closed(4.)));
+    /// ```
+    ///
+    pub fn sqr(&self) -> Interval {
+        match (self.inf(), self.sup()) {
+            (Some(lo), Some(hi)) if lo < 0. && hi > 0. => {
+                let hi = up(two_product(lo, lo)).max(up(two_product(hi, hi)));
+                Interval::new(Closed(0.), closed(hi))
+            }
+            _ => self.product(self),
+        }
+    }
+
+    /// Enclosure of `{ x / y | x ∈ self, y ∈ other }`, or `INFINITY` if `other` contains zero
+    pub(crate) fn quotient(&self, other: &Interval) -> Interval {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        match (other.inf(), other.sup()) {
+            (Some(lo), Some(hi)) if lo > 0. || hi < 0. => {
+                let recip = Interval::new(closed(down(two_recip(hi))), closed(up(two_recip(lo))));
+                self.product(&recip)
+            }
+            _ => INFINITY,
+        }
+    }
+
+    /// Enclosure of `{ x / y | x ∈ self, y ∈ other }`
+    ///
+    /// # Returns
+    ///
+    /// `IntervalError::IncompatibleOperands` if `other` contains zero or is open at zero, where
+    /// the quotient is not a bounded interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalError, Closed};
+    ///
+    /// let a = Interval::new(Closed(1.), Closed(2.));
+    /// let b = Interval::new(Closed(-1.), Closed(4.));
+    ///
+    /// assert_eq!(b.checked_div(&a), Ok(Interval::new(Closed(-1.), Closed(4.))));
+    /// assert!(matches!(a.checked_div(&b), Err(IntervalError::IncompatibleOperands(_))));
+    /// ```
+    ///
+    pub fn checked_div(&self, other: &Interval) -> Result<Interval, IntervalError> {
+        let positive = other.inf().is_some_and(|lo| lo > 0.);
+        let negative = other.sup().is_some_and(|hi| hi < 0.);
+        if !other.is_empty() && !positive && !negative {
+            return Err(IntervalError::IncompatibleOperands("divisor reaches zero"));
+        }
+        Ok(self.quotient(other))
+    }
+
+    /// Enclosure of `{ 1 / x | x ∈ self, x ≠ 0 }`
+    ///
+    /// An interval straddling zero has a reciprocal in two pieces. A zero endpoint, open or
+    /// closed, gives an unbounded side, and an unbounded side gives an open zero endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open, Unbound};
+    ///
+    /// let a = Interval::new(Closed(-2.), Open(4.));
+    ///
+    /// assert_eq!(
+    ///     a.recip(),
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Unbound, Closed(-0.5)),
+    ///         Interval::new(Open(0.25), Unbound),
+    ///     ])
+    /// );
+    /// assert_eq!(
+    ///     Interval::new(Open(0.), Closed(2.)).recip(),
+    ///     IntervalSet::from(&[Interval::new(Closed(0.5), Unbound)])
+    /// );
+    /// ```
+    ///
+    pub fn recip(&self) -> IntervalSet {
+        let negative = self.intersection(Interval::new(Unbound, Open(0.)));
+        let positive = self.intersection(Interval::new(Open(0.), Unbound));
+        let pieces = [negative, positive]
+            .into_iter()
+            .filter(|piece| !piece.is_empty())
+            .map(|piece| {
+                Interval::new(
+                    recip_bound(piece.right(), down),
+                    recip_bound(piece.left(), up),
+                )
+            });
+        IntervalSet::union_all(pieces)
+    }
+
+    /// Enclosure of `{ xⁿ | x ∈ self }`
+    ///
+    /// Negative powers of an interval containing zero are `INFINITY`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Unbound, INFINITY};
+    ///
+    /// let a = Interval::new(Closed(-1.), Closed(2.));
+    ///
+    /// assert_eq!(a.powi(3), Interval::new(Closed(-1.), Closed(8.)));
+    /// assert_eq!(a.powi(4), Interval::new(Closed(0.), Closed(16.)));
+    /// assert_eq!(a.powi(-1), INFINITY);
+    /// assert_eq!(Interval::new(Closed(2.), Unbound).powi(-2), Interval::new(Closed(0.), Closed(0.25)));
+    /// ```
+    ///
+    pub fn powi(&self, n: i32) -> Interval {
+        if n < 0 {
+            let power = self.powi_abs(n.unsigned_abs());
+            return Interval::singleton(1.).quotient(&power);
+        }
+        self.powi_abs(n as u32)
+    }
+
+    fn powi_abs(&self, n: u32) -> Interval {
+        let (Some(lo), Some(hi)) = (self.inf(), self.sup()) else {
+            return EMPTY;
+        };
+        if n == 0 {
+            return Interval::singleton(1.);
+        }
+        let (lo, hi) = if n % 2 == 1 {
+            let signed = |a: f64, round, opposite| {
+                if a < 0. {
+                    -pow_abs(-a, n, opposite)
+                } else {
+                    pow_abs(a, n, round)
+                }
+            };
+            (signed(lo, down, up), signed(hi, up, down))
+        } else if lo >= 0. {
+            (pow_abs(lo, n, down), pow_abs(hi, n, up))
+        } else if hi <= 0. {
+            (pow_abs(-hi, n, down), pow_abs(-lo, n, up))
+        } else {
+            (0., pow_abs(hi.max(-lo), n, up))
+        };
+        Interval::new(closed(lo), closed(hi))
+    }
+
+    /// Enclosure of `{ √x | x ∈ self, x >= 0 }`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, EMPTY};
+    ///
+    /// assert_eq!(Interval::new(Open(-1.), Closed(4.)).sqrt(), Interval::new(Closed(0.), Closed(2.)));
+    /// assert_eq!(Interval::new(Open(-1.), Open(0.)).sqrt(), EMPTY);
+    /// ```
+    ///
+    pub fn sqrt(&self) -> Interval {
+        let domain = self.intersection(Interval::new(Closed(0.), Unbound));
+        let (Some(lo), Some(hi)) = (domain.inf(), domain.sup()) else {
+            return EMPTY;
+        };
+        Interval::new(Closed(down(two_sqrt(lo)).max(0.)), closed(up(two_sqrt(hi))))
+    }
+
+    /// Enclosure of `{ eˣ | x ∈ self }`
+    ///
+    /// Endpoints computed by the platform math library are widened by one ulp, to cover its
+    /// rounding error.
+    ///
+    pub fn exp(&self) -> Interval {
+        let (Some(lo), Some(hi)) = (self.inf(), self.sup()) else {
+            return EMPTY;
+        };
+        Interval::new(
+            Closed(lo.exp().next_down().max(0.)),
+            closed(hi.exp().next_up()),
+        )
+    }
+
+    /// Enclosure of `{ ln(x) | x ∈ self, x > 0 }`
+    ///
+    /// Endpoints computed by the platform math library are widened by one ulp, to cover its
+    /// rounding error.
+    ///
+    pub fn ln(&self) -> Interval {
+        let domain = self.intersection(Interval::new(Open(0.), Unbound));
+        let (Some(lo), Some(hi)) = (domain.inf(), domain.sup()) else {
+            return EMPTY;
+        };
+        Interval::new(closed(lo.ln().next_down()), closed(hi.ln().next_up()))
+    }
+
+    /// Enclosure of `{ sin(x) | x ∈ self }`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    /// use std::f64::consts::PI;
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(PI));
+    ///
+    /// assert_eq!(a.sin().sup(), Some(1.));
+    /// assert!(a.sin().contains(0.) && a.sin().inf().unwrap() > -1e-15);
+    /// assert_eq!(a.cos(), Interval::new(Closed(-1.), Closed(1.)));
+    /// ```
+    ///
+    pub fn sin(&self) -> Interval {
+        match (self.inf(), self.sup()) {
+            (Some(lo), Some(hi)) => trig_range(lo, hi, f64::sin, FRAC_PI_2),
+            _ => EMPTY,
+        }
+    }
+
+    /// Enclosure of `{ cos(x) | x ∈ self }`
+    ///
+    pub fn cos(&self) -> Interval {
+        match (self.inf(), self.sup()) {
+            (Some(lo), Some(hi)) => trig_range(lo, hi, f64::cos, 0.),
+            _ => EMPTY,
+        }
+    }
+
+    /// `{ |x| | x ∈ self }`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// assert_eq!(Interval::new(Open(-3.), Closed(2.)).abs(), Interval::new(Closed(0.), Open(3.)));
+    /// ```
+    ///
+    pub fn abs(&self) -> Interval {
+        match (self.inf(), self.sup()) {
+            (Some(lo), _) if lo >= 0. => *self,
+            (_, Some(hi)) if hi <= 0. => -self,
+            (Some(_), Some(_)) => {
+                let (left, right) = (neg_bound(self.left()), self.right());
+                let right = match (left, right) {
+                    (_, Unbound) | (Unbound, _) => Unbound,
+                    (Closed(a) | Open(a), Closed(b) | Open(b)) if a > b => left,
+                    (Closed(a), Open(b)) if a == b => left,
+                    _ => right,
+                };
+                Interval::new(Closed(0.), right)
+            }
+            _ => EMPTY,
+        }
+    }
+}
+
+// Sums keep open endpoints open, products are computed on the closure of their operands
+impl_op_ex!(-|a: &Interval| -> Interval {
+    if a.is_empty() {
+        return EMPTY;
+    }
+    Interval::new(neg_bound(a.right()), neg_bound(a.left()))
+});
+impl_op_ex!(+|a: &Interval, b: &Interval| -> Interval { a.sum(b) });
+impl_op_ex!(-|a: &Interval, b: &Interval| -> Interval { a.sum(&-b) });
+impl_op_ex!(*|a: &Interval, b: &Interval| -> Interval { a.product(b) });
+
+impl_op_ex_commutative!(+|a: &Interval, k: f64| -> Interval { a.sum(&Interval::singleton(k)) });
+impl_op_ex!(-|a: &Interval, k: f64| -> Interval { a.sum(&Interval::singleton(-k)) });
+impl_op_ex!(-|k: f64, a: &Interval| -> Interval { Interval::singleton(k).sum(&-a) });
+impl_op_ex_commutative!(*|a: &Interval, k: f64| -> Interval { a.product(&Interval::singleton(k)) });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::INFINITY;
+
+    #[test]
+    fn test_add_1() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert_eq!(a + a, Interval::new(Open(0.), Closed(2.)));
+        assert_eq!(a + EMPTY, EMPTY);
+        assert_eq!(a + INFINITY, INFINITY);
+        assert_eq!(a - 1., Interval::new(Open(-1.), Closed(0.)));
+        assert_eq!(1. - a, Interval::new(Closed(0.), Open(1.)));
+        let b = Interval::singleton(f64::MAX) + f64::MAX;
+        assert_eq!(b, Interval::new(Closed(f64::MAX), Unbound));
+    }
+
+    #[test]
+    fn test_mul_add_1() {
+        let a = Interval::new(Closed(-1.), Closed(2.));
+        let b = Interval::new(Closed(3.), Closed(4.));
+        let c = Interval::new(Open(0.5), Closed(1.));
+        assert_eq!(a.mul_add(&b, &c), a * b + c);
+        assert_eq!(a.mul_add(&b, &c), Interval::new(Open(-3.5), Closed(9.)));
+        assert_eq!(a.mul_add(&EMPTY, &c), EMPTY);
+        assert_eq!(a.mul_add(&b, &INFINITY), INFINITY);
+        let big = Interval::singleton(f64::MAX);
+        let c = Interval::singleton(-f64::MAX);
+        assert_eq!(big.mul_add(&big, &c), big * big + c);
+    }
+
+    #[test]
+    fn test_mul_add_2() {
+        for (x, y, z) in [(0.1, 0.2, 0.3), (1e10 / 3., 3., -1e10), (-0.7, 1.3, 0.91)] {
+            let (a, b, c) = (Interval::from(x), Interval::from(y), Interval::from(z));
+            let (fused, chained) = (a.mul_add(&b, &c), a * b + c);
+            assert!(fused.contains(x.mul_add(y, z)));
+            assert!(chained.intersection(fused) == fused);
+            assert!(fused.width() <= 2. * f64::EPSILON * z.abs().max(1.));
+        }
+    }
+
+    #[test]
+    fn test_recip_1() {
+        let pieces = |a: Interval| a.recip().iter().collect::<Vec<_>>();
+        assert_eq!(
+            pieces(Interval::new(Closed(2.), Closed(4.))),
+            vec![Interval::new(Closed(0.25), Closed(0.5))]
+        );
+        assert_eq!(
+            pieces(Interval::new(Unbound, Closed(-1.))),
+            vec![Interval::new(Closed(-1.), Open(0.))]
+        );
+        assert_eq!(
+            pieces(Interval::new(Closed(-1.), Closed(0.))),
+            vec![Interval::new(Unbound, Closed(-1.))]
+        );
+        assert_eq!(
+            pieces(INFINITY),
+            vec![
+                Interval::new(Unbound, Open(0.)),
+                Interval::new(Open(0.), Unbound)
+            ]
+        );
+        assert!(Interval::singleton(0.).recip().is_empty());
+        assert!(EMPTY.recip().is_empty());
+    }
+
+    #[test]
+    fn test_recip_2() {
+        let a = Interval::new(Closed(3.), Open(7.)).recip();
+        let third = a.iter().next().unwrap();
+        assert!(third.contains(1. / 3.) && !third.contains(1. / 7.));
+        assert!(third.width() < 1e-15 + 1. / 3. - 1. / 7.);
+        assert_eq!(third.right(), Closed((1f64 / 3.).next_up()));
+    }
+
+    #[test]
+    fn test_add_2() {
+        let a = Interval::singleton(1.) + 1e-20;
+        assert_eq!(a, Interval::new(Closed(1.), Closed(1f64.next_up())));
+        let b = Interval::singleton(0.1) - 0.1;
+        assert_eq!(b, Interval::singleton(0.));
+    }
+
+    #[test]
+    fn test_mul_1() {
+        let a = Interval::new(Closed(-2.), Open(3.));
+        let b = Interval::new(Closed(1.), Unbound);
+        assert_eq!(a * b, INFINITY);
+        assert_eq!(a * Interval::singleton(0.), Interval::singleton(0.));
+        assert_eq!(a * -1., Interval::new(Closed(-3.), Closed(2.)));
+        assert_eq!(b * b, b);
+        assert_eq!(EMPTY * 0., EMPTY);
+        let c = Interval::singleton(0.1) * 3.;
+        assert!(c.contains(0.1 * 3.) && c.width() > 0.);
+    }
+
+    #[test]
+    fn test_mul_2() {
+        // Products underflowing to zero or to subnormals
+        let tiny = f64::from_bits(1);
+        let a = Interval::singleton(1e-200);
+        assert_eq!(a * a, Interval::new(Closed(0.), Closed(tiny)));
+        assert_eq!(a * -a, Interval::new(Closed(-tiny), Closed(0.)));
+        let b = Interval::singleton(3. * f64::MIN_POSITIVE) * 0.1;
+        assert!(b.inf().unwrap() < b.sup().unwrap());
+        let c = a.mul_add(&a, &Interval::singleton(0.));
+        assert!(c.sup().unwrap() > 0.);
+    }
+
+    #[test]
+    fn test_quotient_1() {
+        let a = Interval::new(Closed(1.), Closed(2.));
+        assert_eq!(
+            a.quotient(&Interval::singleton(4.)),
+            Interval::new(Closed(0.25), Closed(0.5))
+        );
+        assert_eq!(a.quotient(&Interval::new(Open(0.), Closed(1.))), INFINITY);
+        let third = Interval::singleton(1.).quotient(&Interval::singleton(3.));
+        assert!(third.contains(1. / 3.) && third.width() > 0.);
+    }
+
+    #[test]
+    fn test_checked_div_1() {
+        let a = Interval::new(Closed(1.), Closed(2.));
+        let err = Err(IntervalError::IncompatibleOperands("divisor reaches zero"));
+        assert_eq!(a.checked_div(&Interval::new(Open(0.), Closed(1.))), err);
+        assert_eq!(a.checked_div(&Interval::new(Unbound, Closed(0.))), err);
+        assert_eq!(
+            a.checked_div(&Interval::new(Unbound, Closed(-1.))),
+            Ok(Interval::new(Closed(-2.), Closed(0.)))
+        );
+        assert_eq!(a.checked_div(&EMPTY), Ok(EMPTY));
+    }
+
+    #[test]
+    fn test_powi_1() {
+        let a = Interval::new(Closed(-3.), Closed(-2.));
+        assert_eq!(a.powi(2), Interval::new(Closed(4.), Closed(9.)));
+        assert_eq!(a.powi(3), Interval::new(Closed(-27.), Closed(-8.)));
+        assert_eq!(a.powi(0), Interval::singleton(1.));
+        let recip = a.powi(-1);
+        assert!(recip.contains(-0.5) && recip.contains(-1. / 3.) && recip.width() < 0.17);
+        let b = Interval::singleton(1.1).powi(10);
+        assert!(b.contains(1.1f64.powi(10)) && b.width() < 1e-14);
+        assert_eq!(EMPTY.powi(0), EMPTY);
+    }
+
+    #[test]
+    fn test_elementary_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let e = a.exp();
+        assert!(e.contains(1.) && e.contains(std::f64::consts::E) && e.width() < 1.72);
+        let l = Interval::new(Closed(0.), Closed(1.)).ln();
+        assert_eq!(l.inf(), Some(f64::NEG_INFINITY));
+        assert!(l.contains(0.) && l.sup().unwrap() < 1e-15);
+        assert_eq!(Interval::new(Unbound, Closed(-1.)).ln(), EMPTY);
+        assert_eq!(Interval::singleton(4.).sqrt(), Interval::singleton(2.));
+        let root = Interval::singleton(2.).sqrt();
+        assert!(root.contains(2f64.sqrt()) && root.width() > 0. && root.width() < 1e-15);
+    }
+
+    #[test]
+    fn test_trig_1() {
+        let a = Interval::new(Closed(1.), Closed(2.));
+        assert_eq!(a.sin().sup(), Some(1.));
+        assert!(
+            a.sin().contains(1f64.sin()) && a.sin().inf().unwrap() < 2f64.sin().min(1f64.sin())
+        );
+        let c = a.cos();
+        assert!(c.contains(1f64.cos()) && c.contains(2f64.cos()) && c.width() < 1.);
+        assert_eq!(INFINITY.sin(), Interval::new(Closed(-1.), Closed(1.)));
+        let b = Interval::new(Closed(3.), Closed(10.));
+        assert_eq!(b.cos(), Interval::new(Closed(-1.), Closed(1.)));
+    }
+
+    #[test]
+    fn test_sqr_1() {
+        let a = Interval::new(Closed(-3.), Closed(2.));
+        assert_eq!(a.sqr(), Interval::new(Closed(0.), Closed(9.)));
+        assert_eq!((-a).sqr(), a.sqr());
+        assert_eq!(
+            Interval::new(Unbound, Closed(-2.)).sqr(),
+            Interval::new(Closed(4.), Unbound)
+        );
+        assert_eq!(EMPTY.sqr(), EMPTY);
+    }
+}
@@ -0,0 +1,333 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::bound::Bound::{self, Closed, Open, Unbound};
+#[cfg(feature = "alloc")]
+use super::transform::subdivision_cut;
+use super::{Interval, Left, Right, EMPTY};
+
+impl Interval {
+    /// Smallest and largest `f64` belonging to interval, as `(lo, hi)`
+    ///
+    /// `x` belongs to interval iff `lo <= x && x <= hi`, which turns containment into two
+    /// branch-free comparisons. Empty intervals give `(+∞, -∞)`, which nothing satisfies.
+    ///
+    pub(crate) fn thresholds(&self) -> (f64, f64) {
+        if self.is_empty() {
+            return (f64::INFINITY, f64::NEG_INFINITY);
+        }
+        let lo = match self.left() {
+            Closed(k) => k,
+            Open(k) => k.next_up(),
+            Unbound => f64::NEG_INFINITY,
+        };
+        let hi = match self.right() {
+            Closed(k) => k,
+            Open(k) => k.next_down(),
+            Unbound => f64::INFINITY,
+        };
+        (lo, hi)
+    }
+
+    /// Check membership of many points at once (see `Interval::contains`)
+    ///
+    /// Endpoints are turned into two thresholds beforehand, so that the loop over points is
+    /// made of branch-free comparisons the compiler can vectorize.
+    ///
+    /// # Panics
+    ///
+    /// If `xs` and `out` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    /// let xs = [-1., 0., 0.5, 1., f64::NAN];
+    /// let mut out = [false; 5];
+    /// a.contains_batch(&xs, &mut out);
+    ///
+    /// assert_eq!(out, [false, true, true, false, false]);
+    /// ```
+    ///
+    pub fn contains_batch(&self, xs: &[f64], out: &mut [bool]) {
+        assert_eq!(xs.len(), out.len(), "xs and out must have the same length");
+        let (lo, hi) = self.thresholds();
+        for (res, x) in out.iter_mut().zip(xs) {
+            *res = (lo <= *x) & (*x <= hi);
+        }
+    }
+
+    /// Split points into those inside interval and those outside, keeping their order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let valid = Interval::new(Closed(0.), Open(100.));
+    /// let (inside, outside) = valid.partition_points(&[12., -3., 99., 250., f64::NAN]);
+    ///
+    /// assert_eq!(inside, vec![12., 99.]);
+    /// assert_eq!(outside[..2], [-3., 250.]);
+    /// assert!(outside[2].is_nan());
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn partition_points(&self, xs: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let (lo, hi) = self.thresholds();
+        xs.iter().partition(|x| lo <= **x && **x <= hi)
+    }
+
+    /// Reorder points so that those inside interval come first, without allocating
+    ///
+    /// The relative order of points is not preserved.
+    ///
+    /// # Returns
+    ///
+    /// The number of points inside interval, ie. the index of the first point outside.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let mut xs = [5., -1., 2., 7.];
+    /// let n = Interval::new(Closed(0.), Closed(5.)).partition_points_in_place(&mut xs);
+    ///
+    /// assert_eq!(n, 2);
+    /// assert!(xs[..n].contains(&5.) && xs[..n].contains(&2.));
+    /// ```
+    ///
+    pub fn partition_points_in_place(&self, xs: &mut [f64]) -> usize {
+        let (lo, hi) = self.thresholds();
+        let mut n = 0;
+        for i in 0..xs.len() {
+            if lo <= xs[i] && xs[i] <= hi {
+                xs.swap(n, i);
+                n += 1;
+            }
+        }
+        n
+    }
+
+    /// Histogram of samples over `n_bins` equal bins of interval
+    ///
+    /// Bins are those of `Interval::subdivide`: half-open, except for the last one which keeps
+    /// the right bound of interval. Samples lying outside of interval (or `NaN`) are not counted.
+    ///
+    /// # Returns
+    ///
+    /// One count per bin, or no count at all if interval is empty or unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(3.));
+    /// let samples = [0., 0.5, 1., 2.9, 3., 3.1, -1.];
+    ///
+    /// assert_eq!(a.bin_counts(&samples, 3), vec![2, 1, 2]);
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn bin_counts(&self, samples: &[f64], n_bins: usize) -> Vec<u64> {
+        let (Some(a), Some(b)) = (self.inf(), self.sup()) else {
+            return Vec::new();
+        };
+        if !self.is_bounded() || n_bins == 0 {
+            return Vec::new();
+        }
+        let mut counts = alloc::vec![0; n_bins];
+        if a == b {
+            // singleton: every bin but the last is empty
+            counts[n_bins - 1] = samples.iter().filter(|&&x| x == a).count() as u64;
+            return counts;
+        }
+        // bin `i` starts at cut `i`, cuts being those of `Interval::subdivide`
+        let cuts: Vec<f64> = (1..n_bins)
+            .map(|i| subdivision_cut(a, b, n_bins, i))
+            .collect();
+        for &x in samples {
+            if self.contains(x) {
+                counts[cuts.partition_point(|&cut| cut <= x)] += 1;
+            }
+        }
+        counts
+    }
+
+    /// Intersect interval with a packed slice of intervals, given as separate arrays of bounds
+    ///
+    /// Interval `i` of the slice is `(lefts[i], rights[i])`, and its intersection with `self` is
+    /// written to `(out_lefts[i], out_rights[i])`. Empty intersections are written as the bounds
+    /// of `EMPTY`.
+    ///
+    /// # Returns
+    ///
+    /// Number of non-empty intersections.
+    ///
+    /// # Panics
+    ///
+    /// If the four slices do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound, EMPTY};
+    ///
+    /// let lefts = [Closed(0.), Open(5.), Unbound];
+    /// let rights = [Closed(2.), Closed(9.), Open(1.)];
+    /// let (mut out_lefts, mut out_rights) = ([Unbound; 3], [Unbound; 3]);
+    ///
+    /// let query = Interval::new(Closed(1.), Closed(6.));
+    /// let count = query.intersect_packed(&lefts, &rights, &mut out_lefts, &mut out_rights);
+    ///
+    /// assert_eq!(count, 2);
+    /// assert_eq!(out_lefts, [Closed(1.), Open(5.), EMPTY.left()]);
+    /// assert_eq!(out_rights, [Closed(2.), Closed(6.), EMPTY.right()]);
+    /// ```
+    ///
+    pub fn intersect_packed(
+        &self,
+        lefts: &[Bound],
+        rights: &[Bound],
+        out_lefts: &mut [Bound],
+        out_rights: &mut [Bound],
+    ) -> usize {
+        let n = lefts.len();
+        assert!(
+            rights.len() == n && out_lefts.len() == n && out_rights.len() == n,
+            "packed slices must have the same length"
+        );
+        let Interval(left, right) = *self;
+        let mut count = 0;
+        for i in 0..n {
+            let res = if self.is_empty() {
+                EMPTY
+            } else {
+                Interval::new(Left(lefts[i]).max(left).0, Right(rights[i]).min(right).0)
+            };
+            let Interval(Left(l), Right(r)) = res;
+            out_lefts[i] = l;
+            out_rights[i] = r;
+            count += usize::from(!res.is_empty());
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{EMPTY, INFINITY};
+
+    #[test]
+    fn test_contains_batch_1() {
+        let xs = [
+            f64::NEG_INFINITY,
+            -1.,
+            0.,
+            1f64.next_down(),
+            1.,
+            2.,
+            f64::INFINITY,
+            f64::NAN,
+        ];
+        let intervals = [
+            EMPTY,
+            INFINITY,
+            Interval::singleton(1.),
+            Interval::new(Open(0.), Open(1.)),
+            Interval::new(Unbound, Closed(0.)),
+            Interval::new(Open(1.), Unbound),
+        ];
+        for a in intervals {
+            let mut out = [true; 8];
+            a.contains_batch(&xs, &mut out);
+            let expected: Vec<bool> = xs.iter().map(|x| a.contains(*x)).collect();
+            assert_eq!(out.to_vec(), expected, "{a}");
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_contains_batch_2() {
+        INFINITY.contains_batch(&[0.], &mut []);
+    }
+
+    #[test]
+    fn test_intersect_packed_1() {
+        let segments = [
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Open(1.), Closed(2.)),
+            EMPTY,
+            INFINITY,
+            Interval::new(Closed(3.), Unbound),
+        ];
+        let lefts: Vec<Bound> = segments.iter().map(|s| s.left()).collect();
+        let rights: Vec<Bound> = segments.iter().map(|s| s.right()).collect();
+        for query in [EMPTY, INFINITY, Interval::new(Open(0.5), Closed(3.))] {
+            let (mut out_lefts, mut out_rights) = (vec![Unbound; 5], vec![Unbound; 5]);
+            let count = query.intersect_packed(&lefts, &rights, &mut out_lefts, &mut out_rights);
+            let expected: Vec<Interval> = segments.iter().map(|s| s.intersection(query)).collect();
+            let found: Vec<Interval> = (0..5)
+                .map(|i| Interval::new(out_lefts[i], out_rights[i]))
+                .collect();
+            assert_eq!(found, expected);
+            assert_eq!(count, expected.iter().filter(|s| !s.is_empty()).count());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_partition_points_1() {
+        let a = Interval::new(Open(0.), Unbound);
+        let xs = [3., 0., f64::INFINITY, -2., 1e-300];
+        let (inside, outside) = a.partition_points(&xs);
+        assert_eq!(inside, vec![3., f64::INFINITY, 1e-300]);
+        assert_eq!(outside, vec![0., -2.]);
+        let mut ys = xs;
+        let n = a.partition_points_in_place(&mut ys);
+        assert_eq!(n, 3);
+        assert!(ys[..n].iter().all(|y| a.contains(*y)));
+        assert!(ys[n..].iter().all(|y| !a.contains(*y)));
+        assert_eq!(EMPTY.partition_points(&xs), (vec![], xs.to_vec()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bin_counts_1() {
+        let a = Interval::new(Open(0.), Open(1.));
+        let samples = [0., 0.1, 0.25, 0.5, 0.75, 0.999, 1., f64::NAN];
+        assert_eq!(a.bin_counts(&samples, 4), vec![1, 1, 1, 2]);
+        assert_eq!(a.bin_counts(&samples, 1), vec![5]);
+        assert_eq!(a.bin_counts(&[], 2), vec![0, 0]);
+        assert!(a.bin_counts(&samples, 0).is_empty());
+        assert!(Interval::new(Open(0.), Unbound)
+            .bin_counts(&samples, 3)
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bin_counts_2() {
+        // Degenerate intervals, whose bins are EMPTY but the last one
+        let samples = [5., 5., 4., f64::NAN];
+        assert_eq!(
+            Interval::singleton(5.).bin_counts(&samples, 3),
+            vec![0, 0, 2]
+        );
+        assert_eq!(Interval::singleton(5.).bin_counts(&samples, 1), vec![2]);
+        assert!(EMPTY.bin_counts(&samples, 3).is_empty());
+        let x = 1f64.next_up();
+        let narrow = Interval::new(Closed(1.), Closed(x));
+        let bins: Vec<Interval> = narrow.subdivide(4).collect();
+        let counts = narrow.bin_counts(&[1., x, x], 4);
+        assert_eq!(counts.iter().sum::<u64>(), 3);
+        for (bin, count) in bins.iter().zip(&counts) {
+            assert_eq!(bin.contains(1.) as u64 + 2 * bin.contains(x) as u64, *count);
+        }
+    }
+}
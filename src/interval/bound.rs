@@ -18,6 +18,84 @@ impl PartialEq for Bound {
     }
 }
 
+impl Bound {
+    /// Endpoint value, whether open or closed
+    ///
+    /// # Returns
+    ///
+    /// `None` for `Unbound`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Open, Unbound};
+    ///
+    /// assert_eq!(Open(42.).value(), Some(42.));
+    /// assert_eq!(Unbound.value(), None);
+    /// ```
+    ///
+    pub const fn value(self) -> Option<f64> {
+        match self {
+            Open(k) | Closed(k) => Some(k),
+            Unbound => None,
+        }
+    }
+
+    pub const fn is_open(self) -> bool {
+        matches!(self, Open(_))
+    }
+
+    pub const fn is_closed(self) -> bool {
+        matches!(self, Closed(_))
+    }
+
+    pub const fn is_unbound(self) -> bool {
+        matches!(self, Unbound)
+    }
+
+    /// Bound of the same kind, with endpoint value transformed by `f`
+    ///
+    /// Note that a decreasing `f` turns a left bound into a right bound, and conversely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Closed, Unbound};
+    ///
+    /// assert_eq!(Closed(2.).map(|k| k * 10.), Closed(20.));
+    /// assert_eq!(Unbound.map(|k| k * 10.), Unbound);
+    /// ```
+    ///
+    pub fn map(self, f: impl FnOnce(f64) -> f64) -> Bound {
+        match self {
+            Open(k) => Open(f(k)),
+            Closed(k) => Closed(f(k)),
+            Unbound => Unbound,
+        }
+    }
+
+    /// Same bound, with a `-0.` endpoint replaced by `0.`
+    pub(crate) const fn without_signed_zero(self) -> Bound {
+        // -0. + 0. == +0., other values are left untouched
+        match self {
+            Open(k) => Open(k + 0.),
+            Closed(k) => Closed(k + 0.),
+            Unbound => Unbound,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Bound {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Open(k) => defmt::write!(f, "Open({=f64})", k),
+            Closed(k) => defmt::write!(f, "Closed({=f64})", k),
+            Unbound => defmt::write!(f, "Unbound"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -36,4 +114,12 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_accessors_1() {
+        assert!(Open(1.).is_open() && !Open(1.).is_closed() && !Open(1.).is_unbound());
+        assert!(Closed(1.).is_closed() && Unbound.is_unbound());
+        assert_eq!(Closed(-1.).value(), Some(-1.));
+        assert_eq!(Open(1.).map(f64::sqrt), Open(1.));
+    }
 }
@@ -0,0 +1,282 @@
+use super::Interval;
+
+/// Certainly/possibly comparisons between intervals
+///
+/// An interval stands for an unknown value lying somewhere inside it. A relation certainly holds
+/// if it holds for every pair of points taken in both intervals, and possibly holds if it holds for
+/// at least one pair. Comparisons involving `EMPTY` are always false.
+///
+impl Interval {
+    /// Check if every point of `self` is strictly lower than every point of `other`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    /// let b = Interval::new(Closed(1.), Closed(2.));
+    ///
+    /// assert!(a.certainly_lt(&b));
+    /// assert!(!Interval::new(Closed(0.), Closed(1.)).certainly_lt(&b));
+    /// ```
+    ///
+    pub fn certainly_lt(&self, other: &Interval) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return false;
+        }
+        let (Interval(_, a2), Interval(b1, _)) = (self, other);
+        a2 < b1
+    }
+
+    /// Check if every point of `self` is lower than or equal to every point of `other`
+    ///
+    pub fn certainly_le(&self, other: &Interval) -> bool {
+        match (self.sup(), other.inf()) {
+            (Some(sup), Some(inf)) => sup <= inf,
+            _ => false,
+        }
+    }
+
+    /// Check if every point of `self` is strictly greater than every point of `other`
+    ///
+    pub fn certainly_gt(&self, other: &Interval) -> bool {
+        other.certainly_lt(self)
+    }
+
+    /// Check if every point of `self` is greater than or equal to every point of `other`
+    ///
+    pub fn certainly_ge(&self, other: &Interval) -> bool {
+        other.certainly_le(self)
+    }
+
+    /// Check if some point of `self` is strictly lower than some point of `other`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(1.), Closed(2.));
+    /// let b = Interval::new(Closed(0.), Open(1.5));
+    ///
+    /// assert!(a.possibly_lt(&b));
+    /// assert!(!a.possibly_lt(&Interval::new(Closed(0.), Closed(1.))));
+    /// ```
+    ///
+    pub fn possibly_lt(&self, other: &Interval) -> bool {
+        match (self.inf(), other.sup()) {
+            (Some(inf), Some(sup)) => inf < sup,
+            _ => false,
+        }
+    }
+
+    /// Check if some point of `self` is lower than or equal to some point of `other`
+    ///
+    pub fn possibly_le(&self, other: &Interval) -> bool {
+        !self.is_empty() && !other.is_empty() && !self.certainly_gt(other)
+    }
+
+    /// Check if some point of `self` is strictly greater than some point of `other`
+    ///
+    pub fn possibly_gt(&self, other: &Interval) -> bool {
+        other.possibly_lt(self)
+    }
+
+    /// Check if some point of `self` is greater than or equal to some point of `other`
+    ///
+    pub fn possibly_ge(&self, other: &Interval) -> bool {
+        other.possibly_le(self)
+    }
+
+    /// Three-valued `self < other`
+    ///
+    /// # Returns
+    ///
+    /// `Some(true)` if `self` is certainly lower than `other`, `Some(false)` if it is certainly
+    /// not, `None` if it can not be decided (or if one of the intervals is empty).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(1.));
+    /// let b = Interval::new(Closed(2.), Closed(3.));
+    /// let c = Interval::new(Closed(0.5), Closed(3.));
+    ///
+    /// assert_eq!(a.cmp_possible(&b), Some(true));
+    /// assert_eq!(b.cmp_possible(&a), Some(false));
+    /// assert_eq!(a.cmp_possible(&c), None);
+    /// ```
+    ///
+    pub fn cmp_possible(&self, other: &Interval) -> Option<bool> {
+        if self.certainly_lt(other) {
+            Some(true)
+        } else if self.certainly_ge(other) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Check if every point of `self` is strictly lower than `x`
+    ///
+    pub fn certainly_lt_scalar(&self, x: f64) -> bool {
+        self.certainly_lt(&Interval::singleton(x))
+    }
+
+    /// Check if every point of `self` is lower than or equal to `x`
+    ///
+    pub fn certainly_le_scalar(&self, x: f64) -> bool {
+        self.certainly_le(&Interval::singleton(x))
+    }
+
+    /// Check if every point of `self` is strictly greater than `x`
+    ///
+    pub fn certainly_gt_scalar(&self, x: f64) -> bool {
+        self.certainly_gt(&Interval::singleton(x))
+    }
+
+    /// Check if every point of `self` is greater than or equal to `x`
+    ///
+    pub fn certainly_ge_scalar(&self, x: f64) -> bool {
+        self.certainly_ge(&Interval::singleton(x))
+    }
+
+    /// Check if some point of `self` is strictly lower than `x`
+    ///
+    pub fn possibly_lt_scalar(&self, x: f64) -> bool {
+        self.possibly_lt(&Interval::singleton(x))
+    }
+
+    /// Check if some point of `self` is lower than or equal to `x`
+    ///
+    pub fn possibly_le_scalar(&self, x: f64) -> bool {
+        self.possibly_le(&Interval::singleton(x))
+    }
+
+    /// Check if some point of `self` is strictly greater than `x`
+    ///
+    pub fn possibly_gt_scalar(&self, x: f64) -> bool {
+        self.possibly_gt(&Interval::singleton(x))
+    }
+
+    /// Check if some point of `self` is greater than or equal to `x`
+    ///
+    pub fn possibly_ge_scalar(&self, x: f64) -> bool {
+        self.possibly_ge(&Interval::singleton(x))
+    }
+
+    /// Check if interval lies entirely before `x`: same as `certainly_lt_scalar`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(10.));
+    ///
+    /// assert!(a.is_entirely_before(10.));
+    /// assert!(!a.is_entirely_after(0.));
+    /// assert!(a.strictly_contains(5.));
+    /// assert!(!a.strictly_contains(0.) && a.contains(0.));
+    /// ```
+    ///
+    pub fn is_entirely_before(&self, x: f64) -> bool {
+        self.certainly_lt_scalar(x)
+    }
+
+    /// Check if interval lies entirely after `x`: same as `certainly_gt_scalar`
+    ///
+    pub fn is_entirely_after(&self, x: f64) -> bool {
+        self.certainly_gt_scalar(x)
+    }
+
+    /// Check if `x` lies in the interior of interval, away from its endpoints
+    ///
+    pub fn strictly_contains(&self, x: f64) -> bool {
+        self.interior().contains(x)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_certainly_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let b = Interval::new(Closed(1.), Closed(2.));
+        assert!(!a.certainly_lt(&b));
+        assert!(a.certainly_le(&b));
+        assert!(b.certainly_gt(&a) == a.certainly_lt(&b));
+        assert!(b.certainly_ge(&a));
+    }
+
+    #[test]
+    fn test_certainly_2() {
+        let a = Interval::new(Unbound, Open(1.));
+        let b = Interval::new(Open(1.), Unbound);
+        assert!(a.certainly_lt(&b));
+        assert!(b.certainly_gt(&a));
+        assert!(!a.certainly_lt(&INFINITY));
+    }
+
+    #[test]
+    fn test_possibly_1() {
+        let a = Interval::new(Closed(1.), Closed(2.));
+        let b = Interval::new(Closed(0.), Closed(1.));
+        assert!(!a.possibly_lt(&b));
+        assert!(a.possibly_le(&b));
+        assert!(a.possibly_gt(&b));
+        assert!(a.possibly_ge(&b));
+    }
+
+    #[test]
+    fn test_possibly_2() {
+        let a = Interval::new(Closed(1.), Closed(2.));
+        let b = Interval::new(Closed(0.), Open(1.));
+        assert!(!a.possibly_le(&b));
+        assert!(b.possibly_lt(&a));
+    }
+
+    #[test]
+    fn test_empty_1() {
+        let a = Interval::new(Closed(1.), Closed(2.));
+        assert!(!a.certainly_lt(&EMPTY));
+        assert!(!EMPTY.certainly_le(&a));
+        assert!(!a.possibly_lt(&EMPTY));
+        assert!(!EMPTY.possibly_le(&a));
+        assert_eq!(a.cmp_possible(&EMPTY), None);
+    }
+
+    #[test]
+    fn test_scalar_1() {
+        let a = Interval::new(Closed(1.), Open(2.));
+        assert!(a.certainly_lt_scalar(2.));
+        assert!(a.certainly_le_scalar(2.));
+        assert!(!a.certainly_gt_scalar(1.));
+        assert!(a.certainly_ge_scalar(1.));
+        assert!(a.possibly_lt_scalar(1.5));
+        assert!(a.possibly_le_scalar(1.));
+        assert!(!a.possibly_gt_scalar(2.));
+        assert!(!a.possibly_ge_scalar(2.));
+    }
+
+    #[test]
+    fn test_position_1() {
+        let a = Interval::new(Open(1.), Closed(2.));
+        assert!(a.is_entirely_after(1.) && !a.is_entirely_after(1.5));
+        assert!(!a.is_entirely_before(2.) && a.is_entirely_before(2.5));
+        assert!(a.strictly_contains(1.5) && !a.strictly_contains(2.));
+        assert!(INFINITY.strictly_contains(0.) && !INFINITY.is_entirely_after(f64::MIN));
+        assert!(!Interval::singleton(1.).strictly_contains(1.));
+        for x in [1.5, f64::NAN] {
+            assert!(!EMPTY.is_entirely_before(x) && !EMPTY.is_entirely_after(x));
+            assert!(!EMPTY.strictly_contains(x));
+        }
+        assert!(!a.is_entirely_before(f64::NAN) && !a.strictly_contains(f64::NAN));
+    }
+}
@@ -0,0 +1,295 @@
+use core::iter::FromIterator;
+
+use super::bound::Bound::{self, Closed, Open, Unbound};
+use super::{Interval, EMPTY};
+
+/// Closed left bound for a finite value, `Unbound` for `-∞`, and a bound leaving the interval
+/// empty for `+∞`
+fn left_bound(k: f64) -> Bound {
+    match k {
+        f64::NEG_INFINITY => Unbound,
+        f64::INFINITY => Open(k),
+        _ => Closed(k),
+    }
+}
+
+/// Closed right bound for a finite value, `Unbound` for `+∞`, and a bound leaving the interval
+/// empty for `-∞`
+fn right_bound(k: f64) -> Bound {
+    match k {
+        f64::INFINITY => Unbound,
+        f64::NEG_INFINITY => Open(k),
+        _ => Closed(k),
+    }
+}
+
+/// Quantile `q` of non-`NaN` samples, interpolated linearly between order statistics
+///
+/// Interpolation with an infinite order statistic has no finite value: the lower order statistic
+/// is taken for a lower quantile and the upper one otherwise, so that the resulting interval
+/// grows outward instead of collapsing to `EMPTY`. Samples are reordered.
+///
+fn quantile(samples: &mut [f64], q: f64, upper: bool) -> f64 {
+    let h = (samples.len() - 1) as f64 * q;
+    let k = h as usize;
+    let (_, &mut a, above) = samples.select_nth_unstable_by(k, f64::total_cmp);
+    let b = above.iter().copied().min_by(f64::total_cmp).unwrap_or(a);
+    let frac = h - k as f64;
+    if frac == 0. {
+        a
+    } else if a.is_infinite() || b.is_infinite() {
+        if upper {
+            b
+        } else {
+            a
+        }
+    } else {
+        a + frac * (b - a)
+    }
+}
+
+impl Interval {
+    /// Smallest closed interval containing all given points
+    ///
+    /// `NaN` points are ignored, and infinite points make interval unbounded on their side.
+    ///
+    /// # Returns
+    ///
+    /// `EMPTY` if there is no point (or only `NaN` points).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Unbound, EMPTY};
+    ///
+    /// assert_eq!(Interval::hull_of([3., -1., 2.]), Interval::new(Closed(-1.), Closed(3.)));
+    /// assert_eq!(Interval::hull_of([3., f64::NAN]), Interval::singleton(3.));
+    /// assert_eq!(Interval::hull_of([3., f64::INFINITY]), Interval::new(Closed(3.), Unbound));
+    /// assert_eq!(Interval::hull_of(Vec::new()), EMPTY);
+    /// ```
+    ///
+    pub fn hull_of(points: impl IntoIterator<Item = f64>) -> Interval {
+        let bounds =
+            points
+                .into_iter()
+                .filter(|x| !x.is_nan())
+                .fold(None, |bounds, x| match bounds {
+                    None => Some((x, x)),
+                    Some((lo, hi)) => Some((x.min(lo), x.max(hi))),
+                });
+        match bounds {
+            None => EMPTY,
+            Some((lo, hi)) => Interval::new(left_bound(lo), right_bound(hi)),
+        }
+    }
+
+    /// Closed interval between quantiles `lo_q` and `hi_q` of samples
+    ///
+    /// Quantiles are interpolated linearly between order statistics (the default method of R
+    /// and NumPy), and computed by selection in `O(n)` time. `NaN` samples are ignored, and
+    /// `samples` is reordered.
+    ///
+    /// # Returns
+    ///
+    /// `EMPTY` if there is no sample (or only `NaN` samples), or unless
+    /// `0 <= lo_q <= hi_q <= 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let mut samples: Vec<f64> = (0..=100).rev().map(f64::from).collect();
+    ///
+    /// // Central 95%
+    /// assert_eq!(
+    ///     Interval::from_quantiles(&mut samples, 0.025, 0.975),
+    ///     Interval::new(Closed(2.5), Closed(97.5))
+    /// );
+    /// assert_eq!(Interval::from_quantiles(&mut samples, 0., 1.), Interval::hull_of(samples));
+    /// ```
+    ///
+    pub fn from_quantiles(samples: &mut [f64], lo_q: f64, hi_q: f64) -> Interval {
+        // Move NaN samples to the end
+        let mut n = 0;
+        for i in 0..samples.len() {
+            if !samples[i].is_nan() {
+                samples.swap(n, i);
+                n += 1;
+            }
+        }
+        if n == 0 || !(0. <= lo_q && lo_q <= hi_q && hi_q <= 1.) {
+            return EMPTY;
+        }
+        let samples = &mut samples[..n];
+        let (lo, hi) = (
+            quantile(samples, lo_q, false),
+            quantile(samples, hi_q, true),
+        );
+        Interval::new(left_bound(lo), right_bound(hi))
+    }
+
+    /// Closed interval `[center - half_width, center + half_width]`
+    ///
+    /// # Returns
+    ///
+    /// `EMPTY` if `half_width` is negative or if an argument is `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, EMPTY};
+    ///
+    /// assert_eq!(Interval::from_midpoint(42., 0.5), Interval::new(Closed(41.5), Closed(42.5)));
+    /// assert_eq!(Interval::from_midpoint(42., 0.), Interval::singleton(42.));
+    /// assert_eq!(Interval::from_midpoint(42., -1.), EMPTY);
+    /// ```
+    ///
+    pub fn from_midpoint(center: f64, half_width: f64) -> Interval {
+        if center.is_nan() || half_width.is_nan() {
+            return EMPTY;
+        }
+        Interval::new(
+            left_bound(center - half_width),
+            right_bound(center + half_width),
+        )
+    }
+
+    /// Closed interval `[mean - k_sigma * std, mean + k_sigma * std]`
+    ///
+    /// # Returns
+    ///
+    /// `EMPTY` if `std` or `k_sigma` is negative, or if an argument is `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// assert_eq!(Interval::from_mean_std(10., 0.5, 2.), Interval::new(Closed(9.), Closed(11.)));
+    /// ```
+    ///
+    pub fn from_mean_std(mean: f64, std: f64, k_sigma: f64) -> Interval {
+        if std < 0. || k_sigma < 0. {
+            return EMPTY;
+        }
+        Interval::from_midpoint(mean, k_sigma * std)
+    }
+}
+
+/// Collect points into their hull (see `Interval::hull_of`)
+///
+impl FromIterator<f64> for Interval {
+    fn from_iter<T: IntoIterator<Item = f64>>(iter: T) -> Self {
+        Interval::hull_of(iter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::INFINITY;
+
+    #[test]
+    fn test_hull_of_1() {
+        assert_eq!(Interval::hull_of([42.]), Interval::singleton(42.));
+        assert_eq!(Interval::hull_of([f64::NAN]), EMPTY);
+        assert_eq!(
+            Interval::hull_of([f64::NEG_INFINITY, f64::INFINITY]),
+            INFINITY
+        );
+        // Infinite points lie beyond every interval of reals
+        assert_eq!(Interval::hull_of([f64::INFINITY]), EMPTY);
+        assert_eq!(Interval::hull_of([f64::NEG_INFINITY]), EMPTY);
+        assert_eq!(
+            Interval::hull_of([f64::INFINITY, 1.]),
+            Interval::new(Closed(1.), Unbound)
+        );
+    }
+
+    #[test]
+    fn test_hull_of_2() {
+        let a: Interval = [1., 5., f64::NAN, -2.].into_iter().collect();
+        assert_eq!(a, Interval::new(Closed(-2.), Closed(5.)));
+    }
+
+    #[test]
+    fn test_hull_of_3() {
+        let points = [0.5, 0.25, 0.75];
+        let a: Interval = points.iter().copied().collect();
+        assert!(points.iter().all(|x| a.contains(*x)));
+    }
+
+    #[test]
+    fn test_from_quantiles_1() {
+        let mut samples = [4., f64::NAN, 1., 3., 2.];
+        assert_eq!(
+            Interval::from_quantiles(&mut samples, 0.5, 0.5),
+            Interval::singleton(2.5)
+        );
+        assert_eq!(
+            Interval::from_quantiles(&mut samples, 0.25, 0.9),
+            Interval::new(Closed(1.75), Closed(3.7))
+        );
+        assert_eq!(Interval::from_quantiles(&mut samples, 0.9, 0.1), EMPTY);
+        assert_eq!(Interval::from_quantiles(&mut samples, 0., f64::NAN), EMPTY);
+        assert_eq!(Interval::from_quantiles(&mut [f64::NAN], 0., 1.), EMPTY);
+        assert_eq!(Interval::from_quantiles(&mut [], 0., 1.), EMPTY);
+        assert!(samples[4].is_nan());
+    }
+
+    #[test]
+    fn test_from_quantiles_2() {
+        let mut samples = [f64::NEG_INFINITY, 0., 1., f64::INFINITY];
+        assert_eq!(Interval::from_quantiles(&mut samples, 0., 1.), INFINITY);
+        assert_eq!(
+            Interval::from_quantiles(&mut samples, 0.1, 0.5),
+            Interval::new(Unbound, Closed(0.5))
+        );
+        assert_eq!(
+            Interval::from_quantiles(&mut [7.], 0.2, 0.8),
+            Interval::singleton(7.)
+        );
+        assert_eq!(
+            Interval::from_quantiles(&mut [f64::INFINITY], 0., 1.),
+            EMPTY
+        );
+        assert_eq!(
+            Interval::from_quantiles(&mut [f64::NEG_INFINITY], 0., 1.),
+            EMPTY
+        );
+    }
+
+    #[test]
+    fn test_from_quantiles_3() {
+        assert_eq!(
+            Interval::from_quantiles(&mut [0., f64::INFINITY], 0.5, 1.),
+            Interval::new(Closed(0.), Unbound)
+        );
+        assert_eq!(
+            Interval::from_quantiles(&mut [0., f64::INFINITY], 0.5, 0.5),
+            Interval::new(Closed(0.), Unbound)
+        );
+        assert_eq!(
+            Interval::from_quantiles(&mut [f64::NEG_INFINITY, 0.], 0., 0.5),
+            Interval::new(Unbound, Closed(0.))
+        );
+    }
+
+    #[test]
+    fn test_from_midpoint_1() {
+        assert_eq!(Interval::from_midpoint(f64::NAN, 1.), EMPTY);
+        assert_eq!(Interval::from_midpoint(1., f64::NAN), EMPTY);
+        assert_eq!(Interval::from_midpoint(0., f64::INFINITY), INFINITY);
+        assert_eq!(Interval::from_midpoint(f64::INFINITY, 1.), EMPTY);
+        assert_eq!(Interval::from_midpoint(f64::NEG_INFINITY, 1.), EMPTY);
+    }
+
+    #[test]
+    fn test_from_mean_std_1() {
+        let a = Interval::from_mean_std(0., 1., 3.);
+        assert_eq!(a, Interval::new(Closed(-3.), Closed(3.)));
+        assert_eq!(Interval::from_mean_std(0., -1., -3.), EMPTY);
+        assert_eq!(Interval::from_mean_std(0., 1., 0.), Interval::singleton(0.));
+    }
+}
@@ -0,0 +1,237 @@
+use core::fmt::{Display, Formatter, Result};
+
+use super::left::Left;
+use super::right::Right;
+use super::{Closed, Interval, Open, Unbound};
+#[cfg(feature = "alloc")]
+use crate::IntervalSet;
+
+/// Glyphs used to write intervals and sets as text
+///
+/// `IntervalFormat::new()` gives the notation of `Display`: `∅`, `{42.00}` for singletons,
+/// `[1.00, 2.00)` with a comma between endpoints, and ` U ` between the components of a set.
+/// Every glyph can be replaced, ie. for log formats forbidding some characters.
+///
+/// Endpoint values are rounded to two decimals, unless `IntervalFormat::exact` is set: text
+/// then parses back to the very same interval or set.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalFormat, Closed, Open, EMPTY};
+///
+/// let format = IntervalFormat::new()
+///     .with_empty("empty")
+///     .with_singleton("[", "]")
+///     .with_separator(";");
+///
+/// let a = Interval::new(Closed(1.), Open(2.));
+/// assert_eq!(format.display(&a).to_string(), "[ 1.00; 2.00)");
+/// assert_eq!(format.display(&Interval::singleton(42.)).to_string(), "[42.00]");
+/// assert_eq!(format.display(&EMPTY).to_string(), "empty");
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntervalFormat {
+    empty: &'static str,
+    singleton: (&'static str, &'static str),
+    separator: &'static str,
+    union: &'static str,
+    exact: bool,
+}
+
+impl Default for IntervalFormat {
+    fn default() -> Self {
+        IntervalFormat::new()
+    }
+}
+
+impl IntervalFormat {
+    /// Notation of `Display`
+    ///
+    pub const fn new() -> Self {
+        IntervalFormat {
+            empty: "∅",
+            singleton: ("{", "}"),
+            separator: ",",
+            union: " U ",
+            exact: false,
+        }
+    }
+
+    /// Replace `∅`, written for empty intervals and sets
+    ///
+    pub const fn with_empty(self, empty: &'static str) -> Self {
+        IntervalFormat { empty, ..self }
+    }
+
+    /// Replace the braces around singletons
+    ///
+    pub const fn with_singleton(self, open: &'static str, close: &'static str) -> Self {
+        IntervalFormat {
+            singleton: (open, close),
+            ..self
+        }
+    }
+
+    /// Replace the comma between endpoints
+    ///
+    pub const fn with_separator(self, separator: &'static str) -> Self {
+        IntervalFormat { separator, ..self }
+    }
+
+    /// Replace ` U `, written between the components of a set
+    ///
+    pub const fn with_union(self, union: &'static str) -> Self {
+        IntervalFormat { union, ..self }
+    }
+
+    /// Write endpoint values in full (the shortest text parsing back to the same `f64`) instead
+    /// of rounding them to two decimals
+    ///
+    pub const fn exact(self) -> Self {
+        IntervalFormat {
+            exact: true,
+            ..self
+        }
+    }
+
+    /// Interval, ready to be written with this format
+    ///
+    pub fn display<'a>(&'a self, interval: &'a Interval) -> impl Display + 'a {
+        Formatted(move |f: &mut Formatter<'_>| self.write_interval(f, interval))
+    }
+
+    /// Set, ready to be written with this format
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn display_set<'a>(&'a self, set: &'a IntervalSet) -> impl Display + 'a {
+        Formatted(move |f: &mut Formatter<'_>| self.write_set(f, set))
+    }
+
+    fn write_value(&self, f: &mut Formatter<'_>, k: f64) -> Result {
+        if self.exact {
+            write!(f, "{k}")
+        } else {
+            write!(f, "{k:5.2}")
+        }
+    }
+
+    pub(crate) fn write_interval(&self, f: &mut Formatter<'_>, interval: &Interval) -> Result {
+        let (open, close) = self.singleton;
+        match *interval {
+            Interval(Left(Open(k1)), Right(Open(k2))) if k1 == k2 => write!(f, "{}", self.empty),
+            Interval(Left(Unbound), Right(Unbound)) => write!(f, "(-∞{}+∞)", self.separator),
+            Interval(Left(Closed(a)), Right(Closed(b))) if a == b => {
+                write!(f, "{open}")?;
+                self.write_value(f, a)?;
+                write!(f, "{close}")
+            }
+            Interval(Left(left), Right(right)) => {
+                match left {
+                    Closed(k) | Open(k) => {
+                        write!(f, "{}", if left.is_closed() { "[" } else { "(" })?;
+                        self.write_value(f, k)?;
+                    }
+                    Unbound => write!(f, "(-∞")?,
+                }
+                write!(f, "{}", self.separator)?;
+                match right {
+                    Closed(k) | Open(k) => {
+                        self.write_value(f, k)?;
+                        write!(f, "{}", if right.is_closed() { "]" } else { ")" })
+                    }
+                    Unbound => write!(f, "+∞)"),
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn write_set(&self, f: &mut Formatter<'_>, set: &IntervalSet) -> Result {
+        if set.is_empty() {
+            return write!(f, "{}", self.empty);
+        }
+        for (i, component) in set.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.union)?;
+            }
+            self.write_interval(f, &component)?;
+        }
+        Ok(())
+    }
+}
+
+/// Adapter implementing `Display` with a closure
+struct Formatted<F: Fn(&mut Formatter<'_>) -> Result>(F);
+
+impl<F: Fn(&mut Formatter<'_>) -> Result> Display for Formatted<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (self.0)(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{EMPTY, INFINITY};
+
+    #[test]
+    fn test_default_1() {
+        let format = IntervalFormat::default();
+        for a in [
+            EMPTY,
+            INFINITY,
+            Interval::singleton(-3.),
+            Interval::new(Open(1.), Unbound),
+            Interval::new(Closed(-1.5), Open(2.)),
+        ] {
+            assert_eq!(format.display(&a).to_string(), a.to_string());
+        }
+    }
+
+    #[test]
+    fn test_glyphs_1() {
+        let format = IntervalFormat::new()
+            .with_separator(" .. ")
+            .with_empty("{}");
+        assert_eq!(format.display(&INFINITY).to_string(), "(-∞ .. +∞)");
+        assert_eq!(format.display(&EMPTY).to_string(), "{}");
+        let b = Interval::new(Unbound, Closed(0.));
+        assert_eq!(format.display(&b).to_string(), "(-∞ ..  0.00]");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display_set_1() {
+        let format = IntervalFormat::new()
+            .with_union(" | ")
+            .with_singleton("<", ">");
+        let a = IntervalSet::from(&[Interval::singleton(1.), Interval::new(Open(2.), Unbound)]);
+        assert_eq!(format.display_set(&a).to_string(), "< 1.00> | ( 2.00,+∞)");
+        assert_eq!(
+            IntervalFormat::new().display_set(&a).to_string(),
+            a.to_string()
+        );
+        let empty = IntervalFormat::new().with_empty("none");
+        assert_eq!(empty.display_set(&IntervalSet::new()).to_string(), "none");
+    }
+
+    #[test]
+    fn test_exact_1() {
+        let format = IntervalFormat::new().exact();
+        let a = Interval::new(Open(0.1 + 0.2), Closed(1e300));
+        assert_eq!(
+            format.display(&a).to_string(),
+            format!("(0.30000000000000004,{}]", 1e300)
+        );
+        assert_eq!(format.display(&a).to_string().parse::<Interval>(), Ok(a));
+        let b = Interval::new(Unbound, Open(-2.5));
+        assert_eq!(format.display(&b).to_string(), "(-∞,-2.5)");
+        assert_eq!(
+            format.display(&Interval::singleton(1. / 3.)).to_string(),
+            "{0.3333333333333333}"
+        );
+        assert_eq!(format.display(&EMPTY).to_string(), "∅");
+    }
+}
@@ -0,0 +1,308 @@
+use super::bound::Bound::{self, Closed, Open, Unbound};
+use super::Interval;
+
+use crate::math;
+use core::ops::RangeInclusive;
+
+/// Rounding policy of `Interval::to_integer_range`
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntegerPolicy {
+    /// Only integers contained in interval
+    Inner,
+    /// Every integer of the enclosing integer interval (see `Interval::enclosing_integers`)
+    Outer,
+}
+
+/// Smallest integer above a left bound
+fn first_integer(left: Bound) -> f64 {
+    match left {
+        Closed(k) => math::ceil(k),
+        Open(k) => math::floor(k) + 1.,
+        Unbound => f64::NEG_INFINITY,
+    }
+}
+
+/// Greatest integer below a right bound
+fn last_integer(right: Bound) -> f64 {
+    match right {
+        Closed(k) => math::floor(k),
+        Open(k) => math::ceil(k) - 1.,
+        Unbound => f64::INFINITY,
+    }
+}
+
+/// Convert an integral `f64` to `i64`, if every integer up to it is exactly representable
+///
+/// Beyond `2^53 - 1`, `f64` skips integers and rounding of open endpoints is no longer exact.
+fn to_i64(k: f64) -> Option<i64> {
+    const LIMIT: f64 = 9_007_199_254_740_991.; // 2^53 - 1
+
+    if (-LIMIT..=LIMIT).contains(&k) {
+        Some(k as i64)
+    } else {
+        None
+    }
+}
+
+impl Interval {
+    /// Smallest closed interval with integer endpoints containing interval
+    ///
+    /// Left endpoint is rounded down, right endpoint up. Open endpoints lying on an integer are
+    /// kept as is, since the enclosing interval must hold points arbitrarily close to them.
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty, unbounded or reaches beyond `±(2^53 - 1)`, where `f64` no
+    /// longer holds every integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound};
+    ///
+    /// assert_eq!(Interval::new(Closed(0.5), Open(3.)).enclosing_integers(), Some((0, 3)));
+    /// assert_eq!(Interval::new(Closed(-0.5), Unbound).enclosing_integers(), None);
+    /// ```
+    ///
+    pub fn enclosing_integers(&self) -> Option<(i64, i64)> {
+        if self.is_empty() || !self.is_bounded() {
+            return None;
+        }
+        let lo = to_i64(math::floor(self.inf()?))?;
+        let hi = to_i64(math::ceil(self.sup()?))?;
+        Some((lo, hi))
+    }
+
+    /// Closed interval `[lo, hi]` from integer endpoints
+    ///
+    /// Endpoints beyond `±2^53` are rounded to the nearest `f64`, so the result is exact only
+    /// within that range.
+    ///
+    /// # Returns
+    ///
+    /// `EMPTY` if `lo > hi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// assert_eq!(Interval::from_integer_range(0, 3), Interval::new(Closed(0.), Closed(3.)));
+    /// ```
+    ///
+    pub fn from_integer_range(lo: i64, hi: i64) -> Interval {
+        Interval::new(Closed(lo as f64), Closed(hi as f64))
+    }
+
+    /// Check if interval contains at least one integer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// assert!(Interval::new(Closed(0.5), Closed(1.)).contains_integer());
+    /// assert!(!Interval::new(Closed(0.5), Open(1.)).contains_integer());
+    /// ```
+    ///
+    pub fn contains_integer(&self) -> bool {
+        !self.is_empty() && first_integer(self.left()) <= last_integer(self.right())
+    }
+
+    /// Count integers contained in interval
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is unbounded or reaches beyond `±(2^53 - 1)`, where `f64` no longer
+    /// holds every integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound, EMPTY};
+    ///
+    /// assert_eq!(Interval::new(Closed(0.), Open(3.)).count_integers(), Some(3));
+    /// assert_eq!(Interval::new(Open(0.), Open(1.)).count_integers(), Some(0));
+    /// assert_eq!(Interval::new(Open(0.), Unbound).count_integers(), None);
+    /// assert_eq!(EMPTY.count_integers(), Some(0));
+    /// ```
+    ///
+    pub fn count_integers(&self) -> Option<u64> {
+        if self.is_empty() {
+            return Some(0);
+        }
+        if !self.is_bounded() {
+            return None;
+        }
+        let first = to_i64(first_integer(self.left()))?;
+        let last = to_i64(last_integer(self.right()))?;
+        Some(if last < first {
+            0
+        } else {
+            (last - first) as u64 + 1
+        })
+    }
+
+    /// Convert interval into an integer range, according to `policy`
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty, unbounded or reaches beyond `±(2^53 - 1)`, where `f64` no
+    /// longer holds every integer. With `IntegerPolicy::Inner`, an interval holding no integer
+    /// gives an empty range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, IntegerPolicy};
+    ///
+    /// let a = Interval::new(Closed(0.5), Open(3.));
+    ///
+    /// assert_eq!(a.to_integer_range(IntegerPolicy::Inner), Some(1..=2));
+    /// assert_eq!(a.to_integer_range(IntegerPolicy::Outer), Some(0..=3));
+    /// ```
+    ///
+    pub fn to_integer_range(&self, policy: IntegerPolicy) -> Option<RangeInclusive<i64>> {
+        match policy {
+            IntegerPolicy::Inner => {
+                if self.is_empty() || !self.is_bounded() {
+                    return None;
+                }
+                let first = to_i64(first_integer(self.left()))?;
+                let last = to_i64(last_integer(self.right()))?;
+                Some(first..=last)
+            }
+            IntegerPolicy::Outer => {
+                let (lo, hi) = self.enclosing_integers()?;
+                Some(lo..=hi)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_enclosing_integers_1() {
+        assert_eq!(
+            Interval::new(Open(-1.5), Open(-0.5)).enclosing_integers(),
+            Some((-2, 0))
+        );
+        assert_eq!(
+            Interval::singleton(42.).enclosing_integers(),
+            Some((42, 42))
+        );
+        assert_eq!(
+            Interval::new(Open(1.), Open(2.)).enclosing_integers(),
+            Some((1, 2))
+        );
+    }
+
+    #[test]
+    fn test_enclosing_integers_2() {
+        assert_eq!(EMPTY.enclosing_integers(), None);
+        assert_eq!(INFINITY.enclosing_integers(), None);
+        assert_eq!(
+            Interval::new(Unbound, Closed(0.)).enclosing_integers(),
+            None
+        );
+        assert_eq!(
+            Interval::new(Closed(0.), Closed(1e19)).enclosing_integers(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_integer_range_1() {
+        assert_eq!(Interval::from_integer_range(3, 3), Interval::singleton(3.));
+        assert_eq!(Interval::from_integer_range(3, 2), EMPTY);
+        let a = Interval::new(Closed(0.2), Open(2.7));
+        let (lo, hi) = a.enclosing_integers().unwrap();
+        assert_eq!(
+            Interval::from_integer_range(lo, hi),
+            Interval::new(Closed(0.), Closed(3.))
+        );
+    }
+
+    #[test]
+    fn test_contains_integer_1() {
+        assert!(Interval::new(Open(-1.), Open(1.)).contains_integer());
+        assert!(!Interval::new(Open(0.), Open(1.)).contains_integer());
+        assert!(Interval::new(Open(0.5), Unbound).contains_integer());
+        assert!(INFINITY.contains_integer());
+        assert!(!EMPTY.contains_integer());
+    }
+
+    #[test]
+    fn test_count_integers_1() {
+        assert_eq!(Interval::new(Open(-1.), Open(1.)).count_integers(), Some(1));
+        assert_eq!(
+            Interval::new(Closed(-1.), Closed(1.)).count_integers(),
+            Some(3)
+        );
+        assert_eq!(
+            Interval::new(Open(-1.5), Open(1.5)).count_integers(),
+            Some(3)
+        );
+        assert_eq!(Interval::singleton(0.5).count_integers(), Some(0));
+        assert_eq!(INFINITY.count_integers(), None);
+        assert_eq!(
+            Interval::new(Closed(0.), Closed(1e30)).count_integers(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_integer_range_1() {
+        let a = Interval::new(Open(0.), Open(1.));
+        assert!(a.to_integer_range(IntegerPolicy::Inner).unwrap().is_empty());
+        assert_eq!(a.to_integer_range(IntegerPolicy::Outer), Some(0..=1));
+    }
+
+    #[test]
+    fn test_to_integer_range_2() {
+        let a = Interval::new(Closed(-2.), Closed(2.));
+        assert_eq!(a.to_integer_range(IntegerPolicy::Inner), Some(-2..=2));
+        assert_eq!(a.to_integer_range(IntegerPolicy::Outer), Some(-2..=2));
+        let n = a.to_integer_range(IntegerPolicy::Inner).unwrap().count() as u64;
+        assert_eq!(Some(n), a.count_integers());
+    }
+
+    #[test]
+    fn test_to_integer_range_3() {
+        for policy in [IntegerPolicy::Inner, IntegerPolicy::Outer] {
+            assert_eq!(EMPTY.to_integer_range(policy), None);
+            assert_eq!(INFINITY.to_integer_range(policy), None);
+            let a = Interval::new(Closed(0.), Closed(1e19));
+            assert_eq!(a.to_integer_range(policy), None);
+        }
+    }
+
+    #[test]
+    fn test_exact_limit_1() {
+        const MAX: i64 = (1 << 53) - 1;
+        let a = Interval::new(Closed(0.), Closed(MAX as f64));
+        assert_eq!(a.enclosing_integers(), Some((0, MAX)));
+        assert_eq!(a.count_integers(), Some(MAX as u64 + 1));
+        assert_eq!(a.to_integer_range(IntegerPolicy::Inner), Some(0..=MAX));
+        assert_eq!(Interval::from_integer_range(0, MAX), a);
+        let b = Interval::new(Closed(-(MAX as f64)), Open(0.));
+        assert_eq!(b.enclosing_integers(), Some((-MAX, 0)));
+        assert_eq!(b.to_integer_range(IntegerPolicy::Inner), Some(-MAX..=-1));
+    }
+
+    #[test]
+    fn test_exact_limit_2() {
+        // 2^53 + 1 is not a f64: open bound at 2^53 would wrongly hold 2^53 itself
+        let a = Interval::new(Open(9_007_199_254_740_992.), Closed(1e16));
+        assert_eq!(a.to_integer_range(IntegerPolicy::Inner), None);
+        assert_eq!(a.enclosing_integers(), None);
+        assert_eq!(a.count_integers(), None);
+        let b = Interval::new(Closed(0.), Closed(9_007_199_254_740_992.));
+        assert_eq!(b.count_integers(), None);
+    }
+}
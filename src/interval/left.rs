@@ -1,13 +1,34 @@
-use std::cmp::Ordering;
-use std::fmt::Display;
+use core::cmp::Ordering;
+use core::fmt::Display;
 
 use super::bound::Bound::{self, Closed, Open, Unbound};
 use super::right::Right;
 
+/// Left (lower) endpoint of an interval, ordered as the sets of points it lets in
+///
+/// A left bound `b` stands for the half-line of points above it: `[k` lets `k` in while `(k`
+/// does not, so that `[k < (k`, and `(-∞` comes first. Left bounds also compare with right
+/// bounds (`Right`): `left < right` if some point lies at or above `left` and at or below
+/// `right`. These are the orderings needed by sweep-line algorithms over endpoints.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Left, Right, Open, Closed, Unbound};
+///
+/// assert!(Left(Closed(1.)) < Left(Open(1.)));
+/// assert!(Left(Unbound) < Left(Closed(-1e300)));
+/// // [1,1] holds a point, (1,1] does not
+/// assert!(Left(Closed(1.)) == Right(Closed(1.)));
+/// assert!(Left(Open(1.)) > Right(Closed(1.)));
+/// ```
+///
 #[derive(Debug, Clone, Copy)]
 pub struct Left(pub Bound);
 
 impl Left {
+    /// Lowest of two left bounds, ie. the left bound of the union
+    ///
     pub fn min(self, other: Left) -> Self {
         if self < other {
             self
@@ -16,6 +37,8 @@ impl Left {
         }
     }
 
+    /// Highest of two left bounds, ie. the left bound of the intersection
+    ///
     pub fn max(self, other: Left) -> Self {
         if self > other {
             self
@@ -24,7 +47,10 @@ impl Left {
         }
     }
 
-    pub fn closure(self, other: Right) -> bool {
+    /// Check if left bound and right bound `other` share their value, one of them at least
+    /// being closed, ie. if `other` ends where `self` starts without leaving a hole
+    ///
+    pub(crate) fn closure(self, other: Right) -> bool {
         let Left(left) = self;
         let Right(right) = other;
 
@@ -38,7 +64,7 @@ impl Left {
 }
 
 impl Display for Left {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let Left(bound) = self;
         match bound {
             Closed(k) => write!(f, "[{k:5.2}"),
@@ -48,6 +74,17 @@ impl Display for Left {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Left {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Left(Closed(k)) => defmt::write!(f, "[{=f64}", k),
+            Left(Open(k)) => defmt::write!(f, "({=f64}", k),
+            Left(Unbound) => defmt::write!(f, "(-∞"),
+        }
+    }
+}
+
 impl PartialEq for Left {
     fn eq(&self, other: &Self) -> bool {
         let (Left(k1), Left(k2)) = (self, other);
@@ -95,8 +132,10 @@ impl PartialOrd for Left {
             Some(Ordering::Greater)
         } else if self < other {
             Some(Ordering::Less)
-        } else {
+        } else if self == other {
             Some(Ordering::Equal)
+        } else {
+            None
         }
     }
 }
@@ -130,9 +169,10 @@ impl PartialOrd<Right> for Left {
             Some(Ordering::Greater)
         } else if self < other {
             Some(Ordering::Less)
-        } else {
-            assert!(self == other);
+        } else if self == other {
             Some(Ordering::Equal)
+        } else {
+            None
         }
     }
 }
@@ -575,4 +615,18 @@ mod test {
             assert!(!b1.gt(&bound));
         }
     }
+
+    #[test]
+    fn test_partial_cmp_nan_1() {
+        // NaN bounds are incomparable, as NaN values are
+        let nan = Left(Closed(f64::NAN));
+        assert_eq!(nan.partial_cmp(&nan), None);
+        assert_eq!(nan.partial_cmp(&Left(Open(1.))), None);
+        assert_eq!(nan.partial_cmp(&Right(Closed(1.))), None);
+        assert_eq!(Right(Open(f64::NAN)).partial_cmp(&Left(Closed(1.))), None);
+        assert_eq!(
+            Left(Closed(1.)).partial_cmp(&Right(Closed(1.))),
+            Some(Ordering::Equal)
+        );
+    }
 }
@@ -0,0 +1,391 @@
+use super::bound::Bound::{self, Closed, Open, Unbound};
+use super::{Interval, EMPTY};
+use crate::IntervalError;
+
+/// Bound of the complement side (ie. `2]` is followed by `(2`)
+fn flip(bound: Bound) -> Bound {
+    match bound {
+        Closed(k) => Open(k),
+        Open(k) => Closed(k),
+        Unbound => Unbound,
+    }
+}
+
+impl Interval {
+    /// Width (Lebesgue measure) of interval
+    ///
+    /// # Returns
+    ///
+    /// `0.` for `EMPTY` and singletons, `f64::INFINITY` for unbounded intervals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound, EMPTY};
+    ///
+    /// assert_eq!(Interval::new(Open(1.), Closed(3.)).width(), 2.);
+    /// assert_eq!(Interval::new(Unbound, Closed(3.)).width(), f64::INFINITY);
+    /// assert_eq!(EMPTY.width(), 0.);
+    /// ```
+    ///
+    pub fn width(&self) -> f64 {
+        match (self.inf(), self.sup()) {
+            (Some(inf), Some(sup)) => sup - inf,
+            _ => 0.,
+        }
+    }
+
+    /// Width of interval, failing on unbounded intervals instead of returning `f64::INFINITY`
+    ///
+    /// Bounded intervals wider than `f64::MAX` fail with `IntervalError::Overflow`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalError, Open, Closed, Unbound};
+    ///
+    /// assert_eq!(Interval::new(Open(1.), Closed(3.)).try_width(), Ok(2.));
+    /// assert_eq!(Interval::new(Unbound, Closed(3.)).try_width(), Err(IntervalError::Unbounded));
+    /// assert_eq!(
+    ///     Interval::new(Closed(-f64::MAX), Closed(f64::MAX)).try_width(),
+    ///     Err(IntervalError::Overflow)
+    /// );
+    /// ```
+    ///
+    pub fn try_width(&self) -> Result<f64, IntervalError> {
+        if !self.is_bounded() {
+            return Err(IntervalError::Unbounded);
+        }
+        let width = self.width();
+        if width.is_infinite() {
+            return Err(IntervalError::Overflow);
+        }
+        Ok(width)
+    }
+
+    /// Midpoint of interval
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty or unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// assert_eq!(Interval::new(Open(1.), Closed(3.)).midpoint(), Some(2.));
+    /// assert_eq!(Interval::new(Unbound, Closed(3.)).midpoint(), None);
+    /// ```
+    ///
+    pub fn midpoint(&self) -> Option<f64> {
+        if !self.is_bounded() {
+            return None;
+        }
+        let (inf, sup) = (self.inf()?, self.sup()?);
+        let width = sup - inf;
+        let mid = if width.is_finite() {
+            inf + width / 2.
+        } else {
+            // width overflows
+            inf / 2. + sup / 2.
+        };
+        Some(mid.clamp(inf, sup))
+    }
+
+    /// Length of the overlap between two intervals (ie. the width of their intersection)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(3.));
+    /// let b = Interval::new(Closed(2.), Unbound);
+    ///
+    /// assert_eq!(a.overlap_length(&b), 1.);
+    /// assert_eq!(b.overlap_length(&Interval::new(Closed(0.), Closed(1.))), 0.);
+    /// ```
+    ///
+    pub fn overlap_length(&self, other: &Interval) -> f64 {
+        if self.is_empty() || other.is_empty() {
+            return 0.;
+        }
+        let (Interval(a1, a2), Interval(b1, b2)) = (self, other);
+        let (left, right) = (a1.max(*b1), a2.min(*b2));
+        if right < left {
+            0.
+        } else {
+            Interval(left, right).width()
+        }
+    }
+
+    /// Jaccard similarity of two intervals: width of intersection over width of union
+    ///
+    /// # Returns
+    ///
+    /// A value in `[0,1]`. When the union has a zero width (ie. both intervals are degenerate),
+    /// `1.` if intervals are equal, `0.` otherwise. `NaN` if both intervals are unbounded on a
+    /// common side (the ratio of infinite widths is undefined).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(2.));
+    /// let b = Interval::new(Closed(1.), Closed(3.));
+    ///
+    /// assert_eq!(a.jaccard(&b), 1. / 3.);
+    /// assert_eq!(a.jaccard(&a), 1.);
+    /// ```
+    ///
+    pub fn jaccard(&self, other: &Interval) -> f64 {
+        let intersection = self.overlap_length(other);
+        let union = self.width() + other.width() - intersection;
+        if union == 0. {
+            if self == other {
+                1.
+            } else {
+                0.
+            }
+        } else {
+            intersection / union
+        }
+    }
+
+    /// Distance from `x` to interval
+    ///
+    /// # Returns
+    ///
+    /// `0.` if `x` belongs to interval (or to its closure), the distance to the nearest endpoint
+    /// otherwise, `f64::INFINITY` for `EMPTY` and `NaN` for a `NaN` input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert_eq!(a.distance_to_point(0.5), 0.);
+    /// assert_eq!(a.distance_to_point(3.), 2.);
+    /// assert_eq!(a.distance_to_point(-2.), 2.);
+    /// assert_eq!(Interval::new(Unbound, Closed(0.)).distance_to_point(-1e300), 0.);
+    /// assert_eq!(EMPTY.distance_to_point(0.), f64::INFINITY);
+    /// ```
+    ///
+    pub fn distance_to_point(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        match (self.inf(), self.sup()) {
+            (Some(inf), _) if x < inf => inf - x,
+            (_, Some(sup)) if x > sup => x - sup,
+            (Some(_), Some(_)) => 0.,
+            _ => f64::INFINITY,
+        }
+    }
+
+    /// Distance between two intervals: the length of the gap between them
+    ///
+    /// # Returns
+    ///
+    /// `0.` if intervals overlap or touch, `f64::INFINITY` if one of them is `EMPTY`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    /// let b = Interval::new(Closed(3.), Closed(4.));
+    ///
+    /// assert_eq!(a.distance(&b), 2.);
+    /// assert_eq!(b.distance(&a), 2.);
+    /// assert_eq!(a.distance(&Interval::new(Open(1.), Closed(2.))), 0.);
+    /// ```
+    ///
+    pub fn distance(&self, other: &Interval) -> f64 {
+        match (self.inf(), self.sup(), other.inf(), other.sup()) {
+            (Some(_), Some(a2), Some(b1), _) if a2 < b1 => b1 - a2,
+            (Some(a1), _, _, Some(b2)) if b2 < a1 => a1 - b2,
+            (Some(_), _, Some(_), _) => 0.,
+            _ => f64::INFINITY,
+        }
+    }
+
+    /// Interval lying strictly between two disjoint intervals
+    ///
+    /// # Returns
+    ///
+    /// `EMPTY` if intervals overlap, are adjacent, or if one of them is `EMPTY`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    /// let b = Interval::new(Closed(3.), Closed(4.));
+    /// let c = Interval::new(Open(1.), Closed(2.));
+    ///
+    /// assert_eq!(a.gap(&b), Interval::new(Closed(1.), Open(3.)));
+    /// assert_eq!(a.gap(&c), Interval::singleton(1.));
+    /// assert_eq!(c.gap(&Interval::new(Closed(2.), Closed(3.))), EMPTY);
+    /// ```
+    ///
+    pub fn gap(&self, other: &Interval) -> Interval {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        let (Interval(a1, a2), Interval(b1, b2)) = (self, other);
+        if a2 < b1 {
+            Interval::new(flip(a2.0), flip(b1.0))
+        } else if b2 < a1 {
+            Interval::new(flip(b2.0), flip(a1.0))
+        } else {
+            EMPTY
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_width_1() {
+        assert_eq!(Interval::singleton(42.).width(), 0.);
+        assert_eq!(Interval::new(Closed(1.), Open(1.5)).width(), 0.5);
+        assert_eq!(INFINITY.width(), f64::INFINITY);
+        assert_eq!(EMPTY.width(), 0.);
+    }
+
+    #[test]
+    fn test_try_width_1() {
+        assert_eq!(Interval::new(Closed(1.), Open(1.5)).try_width(), Ok(0.5));
+        assert_eq!(EMPTY.try_width(), Ok(0.));
+        assert_eq!(INFINITY.try_width(), Err(IntervalError::Unbounded));
+        let wide = Interval::new(Closed(-f64::MAX), Closed(f64::MAX));
+        assert_eq!(wide.try_width(), Err(IntervalError::Overflow));
+        let max = Interval::new(Closed(0.), Closed(f64::MAX));
+        assert_eq!(max.try_width(), Ok(f64::MAX));
+    }
+
+    #[test]
+    fn test_midpoint_1() {
+        assert_eq!(Interval::singleton(42.).midpoint(), Some(42.));
+        assert_eq!(
+            Interval::new(Closed(f64::MAX), Closed(f64::MAX)).midpoint(),
+            Some(f64::MAX)
+        );
+        assert_eq!(INFINITY.midpoint(), None);
+        assert_eq!(EMPTY.midpoint(), None);
+    }
+
+    #[test]
+    fn test_midpoint_2() {
+        // Halving each endpoint used to underflow
+        let tiny = 5e-324;
+        assert_eq!(Interval::singleton(tiny).midpoint(), Some(tiny));
+        let mid = Interval::new(Closed(tiny), Closed(3. * tiny)).midpoint();
+        assert_eq!(mid, Some(2. * tiny));
+        assert_eq!(
+            Interval::new(Closed(-f64::MAX), Closed(f64::MAX)).midpoint(),
+            Some(0.)
+        );
+    }
+
+    #[test]
+    fn test_overlap_length_1() {
+        let a = Interval::new(Closed(0.), Closed(10.));
+        let b = Interval::new(Open(2.), Open(4.));
+        assert_eq!(a.overlap_length(&b), 2.);
+        assert_eq!(b.overlap_length(&a), 2.);
+    }
+
+    #[test]
+    fn test_overlap_length_2() {
+        let a = Interval::new(Unbound, Closed(10.));
+        assert_eq!(a.overlap_length(&INFINITY), f64::INFINITY);
+        assert_eq!(a.overlap_length(&Interval::new(Closed(5.), Unbound)), 5.);
+        assert_eq!(a.overlap_length(&EMPTY), 0.);
+        assert_eq!(a.overlap_length(&Interval::new(Closed(10.), Unbound)), 0.);
+    }
+
+    #[test]
+    fn test_jaccard_1() {
+        let a = Interval::new(Closed(0.), Closed(4.));
+        let b = Interval::new(Open(3.), Open(5.));
+        assert_eq!(a.jaccard(&b), 0.2);
+        assert_eq!(b.jaccard(&a), 0.2);
+        assert_eq!(a.jaccard(&Interval::new(Closed(5.), Closed(6.))), 0.);
+    }
+
+    #[test]
+    fn test_jaccard_2() {
+        let a = Interval::singleton(42.);
+        assert_eq!(a.jaccard(&a), 1.);
+        assert_eq!(a.jaccard(&EMPTY), 0.);
+        assert_eq!(EMPTY.jaccard(&EMPTY), 1.);
+        assert_eq!(a.jaccard(&Interval::new(Closed(0.), Closed(1.))), 0.);
+    }
+
+    #[test]
+    fn test_jaccard_3() {
+        assert!(INFINITY.jaccard(&INFINITY).is_nan());
+        assert_eq!(INFINITY.jaccard(&Interval::singleton(0.)), 0.);
+    }
+
+    #[test]
+    fn test_distance_to_point_1() {
+        let a = Interval::new(Open(1.), Open(2.));
+        assert_eq!(a.distance_to_point(1.), 0.);
+        assert_eq!(a.distance_to_point(2.), 0.);
+        assert_eq!(a.distance_to_point(0.), 1.);
+        assert_eq!(a.distance_to_point(5.), 3.);
+    }
+
+    #[test]
+    fn test_distance_to_point_2() {
+        let a = Interval::new(Closed(1.), Unbound);
+        assert_eq!(a.distance_to_point(1e300), 0.);
+        assert_eq!(a.distance_to_point(-1.), 2.);
+        assert_eq!(INFINITY.distance_to_point(42.), 0.);
+    }
+
+    #[test]
+    fn test_distance_to_point_3() {
+        assert_eq!(EMPTY.distance_to_point(0.), f64::INFINITY);
+        assert!(INFINITY.distance_to_point(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_distance_1() {
+        let a = Interval::new(Unbound, Closed(0.));
+        let b = Interval::new(Open(10.), Unbound);
+        assert_eq!(a.distance(&b), 10.);
+        assert_eq!(b.distance(&a), 10.);
+        assert_eq!(a.distance(&INFINITY), 0.);
+        assert_eq!(a.distance(&EMPTY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_gap_1() {
+        let a = Interval::new(Unbound, Closed(0.));
+        let b = Interval::new(Open(10.), Unbound);
+        assert_eq!(a.gap(&b), Interval::new(Open(0.), Closed(10.)));
+        assert_eq!(b.gap(&a), Interval::new(Open(0.), Closed(10.)));
+    }
+
+    #[test]
+    fn test_gap_2() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let b = Interval::new(Closed(1.), Closed(2.));
+        assert_eq!(a.gap(&b), EMPTY);
+        assert_eq!(a.gap(&EMPTY), EMPTY);
+        assert_eq!(a.gap(&INFINITY), EMPTY);
+    }
+}
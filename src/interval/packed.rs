@@ -0,0 +1,153 @@
+use super::bound::Bound::{self, Closed, Open, Unbound};
+use super::Interval;
+
+/// Kind of a bound, stored on two bits: `0` open, `1` closed, `2` unbound
+const OPEN: u8 = 0;
+const CLOSED: u8 = 1;
+const UNBOUND: u8 = 2;
+
+/// Split bound into value and kind, `Unbound` being stored as `infinity`
+const fn pack(bound: Bound, infinity: f64) -> (f64, u8) {
+    match bound {
+        Open(k) => (k, OPEN),
+        Closed(k) => (k, CLOSED),
+        Unbound => (infinity, UNBOUND),
+    }
+}
+
+const fn unpack(k: f64, kind: u8) -> Bound {
+    match kind {
+        OPEN => Open(k),
+        CLOSED => Closed(k),
+        _ => Unbound,
+    }
+}
+
+/// Compact storage for an interval: two endpoint values and one byte of bound kinds
+///
+/// `Interval` holds two `Bound` enums, each padding its discriminant to the size of an `f64`:
+/// 32 bytes. `PackedInterval` takes 24 bytes, which cuts memory traffic when storing or scanning
+/// large arrays of intervals.
+///
+/// `Interval` itself keeps its two-enum layout, which its constant constructors and pattern
+/// matching over `Left` and `Right` rely on: packing is opt-in, through this separate type. It is
+/// meant for storage, and only offers a few queries: convert back to `Interval` (a cheap copy)
+/// for the rest of the API.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, PackedInterval, Closed, Open, Unbound};
+///
+/// let intervals = vec![
+///     Interval::new(Closed(0.), Open(1.)),
+///     Interval::new(Open(2.), Unbound),
+/// ];
+/// let packed: Vec<PackedInterval> = intervals.iter().map(|a| PackedInterval::new(*a)).collect();
+///
+/// assert_eq!(packed.iter().filter(|a| a.contains(0.5)).count(), 1);
+/// assert_eq!(Interval::from(packed[1]), intervals[1]);
+/// assert!(std::mem::size_of::<PackedInterval>() < std::mem::size_of::<Interval>());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackedInterval {
+    lo: f64,
+    hi: f64,
+    /// Kind of left bound in bits 0-1, kind of right bound in bits 2-3
+    kinds: u8,
+}
+
+impl PackedInterval {
+    /// Packed copy of `interval`
+    ///
+    pub const fn new(interval: Interval) -> Self {
+        let Interval(left, right) = interval;
+        let (lo, left) = pack(left.0, f64::NEG_INFINITY);
+        let (hi, right) = pack(right.0, f64::INFINITY);
+        PackedInterval {
+            lo,
+            hi,
+            kinds: left | right << 2,
+        }
+    }
+
+    /// Interval stored, as built by `Interval::new` from the stored bounds
+    ///
+    pub const fn unpack(self) -> Interval {
+        Interval::new(self.left(), self.right())
+    }
+
+    /// Left bound of stored interval
+    ///
+    pub const fn left(&self) -> Bound {
+        unpack(self.lo, self.kinds & 0b11)
+    }
+
+    /// Right bound of stored interval
+    ///
+    pub const fn right(&self) -> Bound {
+        unpack(self.hi, self.kinds >> 2)
+    }
+
+    /// Check if interval is empty, as `Interval::is_empty`
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.unpack().is_empty()
+    }
+
+    /// Check if `x` belongs to interval, as `Interval::contains`, without unpacking
+    ///
+    pub fn contains(&self, x: f64) -> bool {
+        let above = match self.kinds & 0b11 {
+            OPEN => self.lo < x,
+            CLOSED => self.lo <= x,
+            _ => !x.is_nan(),
+        };
+        let below = match self.kinds >> 2 {
+            OPEN => x < self.hi,
+            CLOSED => x <= self.hi,
+            _ => !x.is_nan(),
+        };
+        above && below
+    }
+}
+
+impl From<Interval> for PackedInterval {
+    fn from(interval: Interval) -> Self {
+        PackedInterval::new(interval)
+    }
+}
+
+impl From<PackedInterval> for Interval {
+    fn from(packed: PackedInterval) -> Self {
+        packed.unpack()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{EMPTY, INFINITY};
+
+    #[test]
+    fn test_packed_1() {
+        assert_eq!(core::mem::size_of::<PackedInterval>(), 24);
+        for a in [
+            EMPTY,
+            INFINITY,
+            Interval::singleton(-1.),
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Open(0.), Closed(1.)),
+            Interval::new(Closed(1.), Unbound),
+        ] {
+            let packed = PackedInterval::from(a);
+            assert_eq!(Interval::from(packed), a);
+            assert_eq!((packed.left(), packed.right()), (a.left(), a.right()));
+            assert_eq!(packed.is_empty(), a.is_empty());
+            for x in [-2., -1., 0., 0.5, 1., 2., f64::NAN] {
+                assert_eq!(packed.contains(x), a.contains(x), "{a} {x}");
+            }
+        }
+    }
+}
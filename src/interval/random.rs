@@ -0,0 +1,447 @@
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use super::Interval;
+
+impl Interval {
+    /// Draw a point uniformly distributed over interval
+    ///
+    /// Open endpoints are never returned.
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty or unbounded, or if it holds no representable `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, INFINITY};
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// let a = Interval::new(Open(0.), Closed(1.));
+    ///
+    /// let x = a.sample_uniform(&mut rng).unwrap();
+    /// assert!(a.contains(x));
+    /// assert_eq!(INFINITY.sample_uniform(&mut rng), None);
+    /// ```
+    ///
+    pub fn sample_uniform<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<f64> {
+        if !self.is_bounded() || self.is_empty() {
+            return None;
+        }
+        let (a, b) = (self.inf()?, self.sup()?);
+        self.clamp(a)?;
+
+        loop {
+            let u: f64 = rng.gen();
+            let x = a * (1. - u) + b * u;
+            if self.contains(x) {
+                return Some(x);
+            }
+        }
+    }
+
+    /// Draw a point of interval following the distribution of cumulative distribution function
+    /// `cdf`, restricted to interval, by inversion
+    ///
+    /// A probability is drawn uniformly between `cdf(inf)` and `cdf(sup)`, and mapped back
+    /// through `quantile`, the inverse of `cdf`. Unbounded intervals are fine as long as `cdf`
+    /// accepts infinite arguments.
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty, if its probability under `cdf` is not positive, or if
+    /// `quantile` returns `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Unbound};
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// // Exponential distribution of rate 2, beyond 1
+    /// let cdf = |x: f64| if x <= 0. { 0. } else { 1. - (-2. * x).exp() };
+    /// let quantile = |p: f64| -(1. - p).ln() / 2.;
+    /// let a = Interval::new(Closed(1.), Unbound);
+    ///
+    /// let x = a.sample_inverse_cdf(cdf, quantile, &mut rng).unwrap();
+    /// assert!(a.contains(x));
+    /// ```
+    ///
+    pub fn sample_inverse_cdf<F, Q, R>(&self, cdf: F, quantile: Q, rng: &mut R) -> Option<f64>
+    where
+        F: Fn(f64) -> f64,
+        Q: Fn(f64) -> f64,
+        R: Rng + ?Sized,
+    {
+        let (p, q) = (cdf(self.inf()?), cdf(self.sup()?));
+        if p.is_nan() || q.is_nan() || p >= q {
+            return None;
+        }
+        let u: f64 = rng.gen();
+        // quantile may be inexact, and endpoints may be open
+        self.clamp(quantile(p * (1. - u) + q * u))
+    }
+
+    /// Draw a point of bounded interval following probability density `density`, restricted to
+    /// interval, by rejection
+    ///
+    /// Points are drawn uniformly and accepted with probability `density(x) / max_density`, so
+    /// `max_density` must bound `density` over interval. Sampling does not return until a point
+    /// is accepted: `density` must not vanish over interval.
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty or unbounded, or if `max_density` is not positive and
+    /// finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// // Triangular distribution peaking at 0
+    /// let a = Interval::new(Closed(-1.), Closed(1.));
+    ///
+    /// let x = a.sample_rejection(|x| 1. - x.abs(), 1., &mut rng).unwrap();
+    /// assert!(a.contains(x));
+    /// ```
+    ///
+    pub fn sample_rejection<F, R>(&self, density: F, max_density: f64, rng: &mut R) -> Option<f64>
+    where
+        F: Fn(f64) -> f64,
+        R: Rng + ?Sized,
+    {
+        if !max_density.is_finite() || max_density <= 0. {
+            return None;
+        }
+        loop {
+            let x = self.sample_uniform(rng)?;
+            if rng.gen::<f64>() * max_density < density(x) {
+                return Some(x);
+            }
+        }
+    }
+
+    /// Draw a point of interval following the normal distribution of given mean and standard
+    /// deviation, restricted to interval
+    ///
+    /// Sampling is exact, by rejection from a normal, uniform or exponential proposal depending
+    /// on the position of interval relative to `mean`: it stays efficient far in the tails.
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty or holds no representable `f64`, if `mean` is not finite or
+    /// if `std` is not positive and finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Unbound};
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// let a = Interval::new(Closed(10.), Unbound);
+    ///
+    /// // Ten standard deviations away from the mean
+    /// let x = a.sample_truncated_normal(0., 1., &mut rng).unwrap();
+    /// assert!(a.contains(x) && x < 11.);
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    pub fn sample_truncated_normal<R: Rng + ?Sized>(
+        &self,
+        mean: f64,
+        std: f64,
+        rng: &mut R,
+    ) -> Option<f64> {
+        if !mean.is_finite() || !std.is_finite() || std <= 0. {
+            return None;
+        }
+        let nearest = self.clamp(mean)?;
+        let lo = (self.inf()? - mean) / std;
+        let hi = (self.sup()? - mean) / std;
+        if lo == f64::INFINITY || hi == f64::NEG_INFINITY {
+            // interval is too far from mean for its points to be told apart
+            return Some(nearest);
+        }
+        self.clamp(mean + std * standard_normal_within(lo, hi, rng))
+    }
+
+    /// Logarithm of the probability of interval under the normal distribution of given mean
+    /// and standard deviation
+    ///
+    /// The tail function is approximated to about 1e-7 in relative terms, which is plenty to
+    /// weight the components of a set.
+    ///
+    #[cfg(feature = "std")]
+    pub(crate) fn ln_normal_mass(&self, mean: f64, std: f64) -> f64 {
+        let (Some(a), Some(b)) = (self.inf(), self.sup()) else {
+            return f64::NEG_INFINITY;
+        };
+        let (lo, hi) = ((a - mean) / std, (b - mean) / std);
+        if lo >= 0. {
+            let (p, q) = (ln_upper_tail(lo), ln_upper_tail(hi));
+            p + (-(q - p).exp()).ln_1p()
+        } else if hi <= 0. {
+            let (p, q) = (ln_upper_tail(-hi), ln_upper_tail(-lo));
+            p + (-(q - p).exp()).ln_1p()
+        } else {
+            (-ln_upper_tail(hi).exp() - ln_upper_tail(-lo).exp()).ln_1p()
+        }
+    }
+}
+
+/// Logarithm of `P(Z > z)` for a standard normal `Z` and `z >= 0`
+///
+/// Uses the Chebyshev fit of `erfc` from Numerical Recipes, written in logarithmic form so that
+/// it does not underflow far in the tail.
+#[cfg(feature = "std")]
+fn ln_upper_tail(z: f64) -> f64 {
+    const COEFFS: [f64; 10] = [
+        -1.26551223,
+        1.00002368,
+        0.37409196,
+        0.09678418,
+        -0.18628806,
+        0.27886807,
+        -1.13520398,
+        1.48851587,
+        -0.82215223,
+        0.17087277,
+    ];
+    let x = z / core::f64::consts::SQRT_2;
+    let t = 1. / (1. + 0.5 * x);
+    let poly = COEFFS.iter().rev().fold(0., |acc, c| c + t * acc);
+    t.ln() - x * x + poly - core::f64::consts::LN_2
+}
+
+/// Draw a standard normal restricted to `[lo, hi]`, with `lo <= hi`, `lo < ∞` and `hi > -∞`
+///
+/// See C. P. Robert, "Simulation of truncated normal variables" (1995).
+#[cfg(feature = "std")]
+fn standard_normal_within<R: Rng + ?Sized>(lo: f64, hi: f64, rng: &mut R) -> f64 {
+    if hi <= 0. {
+        return -standard_normal_within(-hi, -lo, rng);
+    }
+    if lo < 0. {
+        if hi - lo >= (2. * core::f64::consts::PI).sqrt() {
+            // Box-Muller
+            loop {
+                let radius = (-2. * (1. - rng.gen::<f64>()).ln()).sqrt();
+                let z = radius * (core::f64::consts::TAU * rng.gen::<f64>()).cos();
+                if lo <= z && z <= hi {
+                    return z;
+                }
+            }
+        }
+        return uniform_proposal(lo, hi, 0., rng);
+    }
+
+    // tail: exponential proposal of optimal rate, unless interval is narrow
+    let root = lo.hypot(2.);
+    let alpha = lo / 2. + root / 2.;
+    let narrow = 2. * core::f64::consts::E.sqrt() / (lo + root) * (-lo / (lo + root)).exp();
+    if hi - lo <= narrow {
+        return uniform_proposal(lo, hi, lo, rng);
+    }
+    loop {
+        let excess = -(1. - rng.gen::<f64>()).ln() / alpha;
+        let z = lo + excess;
+        let shift = excess - 2. / (lo + root);
+        if z <= hi && rng.gen::<f64>() <= (-shift * shift / 2.).exp() {
+            return z;
+        }
+    }
+}
+
+/// Draw a standard normal restricted to bounded `[lo, hi]` from uniform proposals, `peak`
+/// being the point of `[lo, hi]` of highest density
+#[cfg(feature = "std")]
+fn uniform_proposal<R: Rng + ?Sized>(lo: f64, hi: f64, peak: f64, rng: &mut R) -> f64 {
+    loop {
+        let u: f64 = rng.gen();
+        let z = lo * (1. - u) + hi * u;
+        if rng.gen::<f64>() <= ((peak * peak - z * z) / 2.).exp() {
+            return z;
+        }
+    }
+}
+
+/// Uniform distribution over a bounded interval
+///
+/// # Panics
+///
+/// Sampling panics if interval is empty or unbounded (see `Interval::sample_uniform` for a
+/// non-panicking version).
+///
+impl Distribution<f64> for Interval {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        match self.sample_uniform(rng) {
+            Some(x) => x,
+            None => panic!("can not sample uniformly from {self}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_sample_uniform_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = Interval::new(Open(1.), Open(1.5));
+        for _ in 0..1000 {
+            assert!(a.contains(a.sample_uniform(&mut rng).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_sample_uniform_2() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = Interval::new(Open(1.), Closed(1f64.next_up()));
+        assert_eq!(a.sample_uniform(&mut rng), Some(1f64.next_up()));
+        assert_eq!(Interval::singleton(42.).sample_uniform(&mut rng), Some(42.));
+    }
+
+    #[test]
+    fn test_sample_uniform_3() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(EMPTY.sample_uniform(&mut rng), None);
+        assert_eq!(INFINITY.sample_uniform(&mut rng), None);
+        assert_eq!(
+            Interval::new(Closed(0.), Unbound).sample_uniform(&mut rng),
+            None
+        );
+        let a = Interval::new(Open(1.), Open(1f64.next_up()));
+        assert_eq!(a.sample_uniform(&mut rng), None);
+    }
+
+    #[test]
+    fn test_distribution_1() {
+        let rng = StdRng::seed_from_u64(0);
+        let a = Interval::new(Closed(-10.), Open(10.));
+        let samples: Vec<f64> = a.sample_iter(rng).take(10000).collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!(samples.iter().all(|x| a.contains(*x)));
+        assert!(mean.abs() < 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_distribution_2() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let _: f64 = INFINITY.sample(&mut rng);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sample_truncated_normal_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| INFINITY.sample_truncated_normal(1., 2., &mut rng).unwrap())
+            .collect();
+        let mean = samples.iter().sum::<f64>() / 10000.;
+        let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / 10000.;
+        assert!((mean - 1.).abs() < 0.1 && (var - 4.).abs() < 0.2);
+
+        let a = Interval::new(Open(-1.), Closed(1.));
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| a.sample_truncated_normal(0., 1., &mut rng).unwrap())
+            .collect();
+        let inner = samples.iter().filter(|x| x.abs() < 0.5).count();
+        assert!(samples.iter().all(|x| a.contains(*x)));
+        // P(|Z| < 0.5) / P(|Z| < 1) ≈ 0.561
+        assert!((5350..5850).contains(&inner));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sample_truncated_normal_2() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let tail = Interval::new(Unbound, Open(-10.));
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| tail.sample_truncated_normal(0., 1., &mut rng).unwrap())
+            .collect();
+        let mean = samples.iter().sum::<f64>() / 10000.;
+        assert!(samples.iter().all(|x| tail.contains(*x)));
+        // E[Z | Z < -10] ≈ -10.098
+        assert!((mean + 10.098).abs() < 0.01);
+
+        let narrow = Interval::new(Closed(3.), Closed(3.01));
+        for _ in 0..1000 {
+            let x = narrow.sample_truncated_normal(0., 1., &mut rng).unwrap();
+            assert!(narrow.contains(x));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sample_truncated_normal_3() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = Interval::new(Closed(1e300), Unbound);
+        assert_eq!(a.sample_truncated_normal(0., 1e-300, &mut rng), Some(1e300));
+        assert_eq!(
+            Interval::singleton(2.).sample_truncated_normal(0., 1., &mut rng),
+            Some(2.)
+        );
+        assert_eq!(EMPTY.sample_truncated_normal(0., 1., &mut rng), None);
+        assert_eq!(INFINITY.sample_truncated_normal(0., -1., &mut rng), None);
+        assert_eq!(
+            INFINITY.sample_truncated_normal(f64::NAN, 1., &mut rng),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_ln_normal_mass_1() {
+        assert!(INFINITY.ln_normal_mass(3., 2.).abs() < 1e-9);
+        let half = Interval::new(Closed(3.), Unbound).ln_normal_mass(3., 2.);
+        assert!((half - 0.5f64.ln()).abs() < 1e-6);
+        let central = Interval::new(Closed(-1.96), Closed(1.96)).ln_normal_mass(0., 1.);
+        assert!((central - 0.95f64.ln()).abs() < 1e-4);
+        let far = Interval::new(Closed(-41.), Closed(-40.)).ln_normal_mass(0., 1.);
+        assert!((far + 804.6).abs() < 0.1);
+        assert_eq!(EMPTY.ln_normal_mass(0., 1.), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_sample_inverse_cdf_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        // Exponential distribution of rate 1: memoryless beyond 2
+        let cdf = |x: f64| if x <= 0. { 0. } else { 1. - (-x).exp() };
+        let quantile = |p: f64| -(1. - p).ln();
+        let a = Interval::new(Open(2.), Unbound);
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| a.sample_inverse_cdf(cdf, quantile, &mut rng).unwrap())
+            .collect();
+        let mean = samples.iter().sum::<f64>() / 10000.;
+        assert!(samples.iter().all(|x| a.contains(*x)));
+        assert!((mean - 3.).abs() < 0.05);
+        let b = Interval::new(Unbound, Closed(-1.));
+        assert_eq!(b.sample_inverse_cdf(cdf, quantile, &mut rng), None);
+    }
+
+    #[test]
+    fn test_sample_rejection_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| a.sample_rejection(|x| 2. * x, 2., &mut rng).unwrap())
+            .collect();
+        let mean = samples.iter().sum::<f64>() / 10000.;
+        assert!(samples.iter().all(|x| a.contains(*x)));
+        assert!((mean - 2. / 3.).abs() < 0.02);
+        assert_eq!(a.sample_rejection(|x| x, 0., &mut rng), None);
+        assert_eq!(INFINITY.sample_rejection(|_| 1., 1., &mut rng), None);
+    }
+}
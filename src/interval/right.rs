@@ -1,13 +1,31 @@
-use std::cmp::Ordering;
-use std::fmt::Display;
+use core::cmp::Ordering;
+use core::fmt::Display;
 
 use super::bound::Bound::{self, Closed, Open, Unbound};
 use super::left::Left;
 
+/// Right (upper) endpoint of an interval, ordered as the sets of points it lets in
+///
+/// A right bound stands for the half-line of points below it: `k)` < `k]`, and `+∞)` comes
+/// last. See `Left` for the comparisons between left and right bounds.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Left, Right, Open, Closed, Unbound};
+///
+/// assert!(Right(Open(1.)) < Right(Closed(1.)));
+/// assert!(Right(Closed(1e300)) < Right(Unbound));
+/// // [1,1) holds no point
+/// assert!(Right(Open(1.)).is_before(Left(Closed(1.))));
+/// ```
+///
 #[derive(Debug, Clone, Copy)]
 pub struct Right(pub Bound);
 
 impl Right {
+    /// Lowest of two right bounds, ie. the right bound of the intersection
+    ///
     pub fn min(self, other: Right) -> Self {
         if self < other {
             self
@@ -16,6 +34,8 @@ impl Right {
         }
     }
 
+    /// Highest of two right bounds, ie. the right bound of the union
+    ///
     pub fn max(self, other: Right) -> Self {
         if self > other {
             self
@@ -24,7 +44,10 @@ impl Right {
         }
     }
 
-    pub fn closure(self, other: Left) -> bool {
+    /// Check if right bound and left bound `other` share their value, one of them at least
+    /// being closed, ie. if `other` starts where `self` ends without leaving a hole
+    ///
+    pub(crate) fn closure(self, other: Left) -> bool {
         let Left(left) = other;
         let Right(right) = self;
 
@@ -35,10 +58,26 @@ impl Right {
             _ => false,
         }
     }
+
+    /// Check if right bound lies strictly before left bound, i.e. if they enclose no point
+    ///
+    /// Same as `self < other`, usable in constant expressions.
+    ///
+    pub const fn is_before(self, other: Left) -> bool {
+        let (Right(right), Left(left)) = (self, other);
+        match (right, left) {
+            (Open(k1), Open(k2)) => k1 <= k2,    // ..k1[ < ]k2..
+            (Open(k1), Closed(k2)) => k1 <= k2,  // ..k1[ < [k2..
+            (Closed(k1), Closed(k2)) => k1 < k2, // ..k1] < [k2..
+            (Closed(k1), Open(k2)) => k1 <= k2,  // ..k1] < ]k2..
+            (Unbound, _) => false,
+            (_, Unbound) => false,
+        }
+    }
 }
 
 impl Display for Right {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Right(Closed(k)) => write!(f, "{k:5.2}]"),
             Right(Open(k)) => write!(f, "{k:5.2})"),
@@ -47,6 +86,17 @@ impl Display for Right {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Right {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Right(Closed(k)) => defmt::write!(f, "{=f64}]", k),
+            Right(Open(k)) => defmt::write!(f, "{=f64})", k),
+            Right(Unbound) => defmt::write!(f, "+∞)"),
+        }
+    }
+}
+
 impl PartialEq for Right {
     fn eq(&self, other: &Self) -> bool {
         let (Right(k1), Right(k2)) = (self, other);
@@ -94,8 +144,10 @@ impl PartialOrd for Right {
             Some(Ordering::Greater)
         } else if self < other {
             Some(Ordering::Less)
-        } else {
+        } else if self == other {
             Some(Ordering::Equal)
+        } else {
+            None
         }
     }
 }
@@ -114,15 +166,7 @@ impl PartialOrd<Left> for Right {
     }
 
     fn lt(&self, other: &Left) -> bool {
-        let (Right(right), Left(left)) = (self, other);
-        match (right, left) {
-            (Open(k1), Open(k2)) => k1 <= k2,    // ..k1[ < ]k2..
-            (Open(k1), Closed(k2)) => k1 <= k2,  // ..k1[ < [k2..
-            (Closed(k1), Closed(k2)) => k1 < k2, // ..k1] < [k2..
-            (Closed(k1), Open(k2)) => k1 <= k2,  // ..k1] < ]k2..
-            (Unbound, _) => false,
-            (_, Unbound) => false,
-        }
+        self.is_before(*other)
     }
 
     fn partial_cmp(&self, other: &Left) -> Option<Ordering> {
@@ -130,9 +174,10 @@ impl PartialOrd<Left> for Right {
             Some(Ordering::Greater)
         } else if self < other {
             Some(Ordering::Less)
-        } else {
-            assert!(self == other);
+        } else if self == other {
             Some(Ordering::Equal)
+        } else {
+            None
         }
     }
 }
@@ -586,4 +631,18 @@ mod test {
     //           assert!(!b1.gt(&bound));
     //       }
     //   }
+
+    #[test]
+    fn test_partial_cmp_nan_1() {
+        // NaN bounds are incomparable, as NaN values are
+        let nan = Right(Closed(f64::NAN));
+        assert_eq!(nan.partial_cmp(&nan), None);
+        assert_eq!(nan.partial_cmp(&Right(Open(1.))), None);
+        assert_eq!(nan.partial_cmp(&Left(Closed(1.))), None);
+        assert_eq!(Left(Open(f64::NAN)).partial_cmp(&Right(Closed(1.))), None);
+        assert_eq!(
+            Right(Closed(1.)).partial_cmp(&Left(Closed(1.))),
+            Some(Ordering::Equal)
+        );
+    }
 }
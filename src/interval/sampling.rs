@@ -0,0 +1,147 @@
+use super::Interval;
+
+impl Interval {
+    /// Iterate over `n` evenly spaced points of a bounded interval
+    ///
+    /// Closed endpoints are part of the sequence, open ones are excluded: points are spread as if
+    /// the open endpoints were extra points of the grid. As a consequence, `n == 1` yields the left
+    /// endpoint of a left-closed interval, and the midpoint of an open interval.
+    ///
+    /// # Returns
+    ///
+    /// An iterator which yields nothing if interval is empty or unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(1.));
+    /// let b = Interval::new(Closed(0.), Open(1.));
+    /// let c = Interval::new(Open(0.), Open(1.));
+    ///
+    /// assert_eq!(a.linspace(3).collect::<Vec<f64>>(), vec![0., 0.5, 1.]);
+    /// assert_eq!(b.linspace(4).collect::<Vec<f64>>(), vec![0., 0.25, 0.5, 0.75]);
+    /// assert_eq!(c.linspace(3).collect::<Vec<f64>>(), vec![0.25, 0.5, 0.75]);
+    /// ```
+    ///
+    pub fn linspace(&self, n: usize) -> impl Iterator<Item = f64> {
+        let n = if self.is_bounded() && !self.is_empty() {
+            n
+        } else {
+            0
+        };
+        let (a, b) = (self.inf().unwrap_or(0.), self.sup().unwrap_or(0.));
+        let skip_left = usize::from(self.is_left_open());
+        let grid = n + skip_left + usize::from(self.is_right_open());
+        let steps = grid.saturating_sub(1).max(1) as f64;
+
+        (skip_left..skip_left + n).map(move |j| {
+            if j + 1 == grid && grid > 1 {
+                b
+            } else {
+                a + (b - a) * (j as f64 / steps)
+            }
+        })
+    }
+
+    /// Iterate over the points of a bounded interval on a grid of given `step`
+    ///
+    /// Grid is anchored on left endpoint: sequence starts from it when it is closed, from the
+    /// first grid point inside interval otherwise. Points are computed as `left + i * step` to
+    /// avoid accumulating rounding errors.
+    ///
+    /// # Returns
+    ///
+    /// An iterator which yields nothing if interval is empty or unbounded, or if `step` is not
+    /// strictly positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(1.));
+    /// let b = Interval::new(Open(0.), Open(1.));
+    ///
+    /// assert_eq!(a.iter_step(0.25).collect::<Vec<f64>>(), vec![0., 0.25, 0.5, 0.75, 1.]);
+    /// assert_eq!(b.iter_step(0.25).collect::<Vec<f64>>(), vec![0.25, 0.5, 0.75]);
+    /// ```
+    ///
+    pub fn iter_step(&self, step: f64) -> impl Iterator<Item = f64> {
+        let this = *self;
+        let valid = this.is_bounded() && !this.is_empty() && step > 0.;
+        let a = this.inf().unwrap_or(0.);
+        let first = if valid {
+            usize::from(this.is_left_open())
+        } else {
+            0
+        };
+
+        (first..)
+            .map(move |i| a + i as f64 * step)
+            .take_while(move |x| valid && this.contains(*x))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_linspace_1() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert_eq!(a.linspace(2).collect::<Vec<_>>(), vec![0.5, 1.]);
+        assert_eq!(a.linspace(1).collect::<Vec<_>>(), vec![1.]);
+        assert_eq!(a.linspace(0).count(), 0);
+    }
+
+    #[test]
+    fn test_linspace_2() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.linspace(1).collect::<Vec<_>>(), vec![0.]);
+        assert_eq!(a.linspace(2).collect::<Vec<_>>(), vec![0., 1.]);
+        assert_eq!(
+            Interval::singleton(42.).linspace(2).collect::<Vec<_>>(),
+            vec![42., 42.]
+        );
+    }
+
+    #[test]
+    fn test_linspace_3() {
+        let a = Interval::new(Closed(-1.), Open(7.));
+        assert!(a.linspace(1000).all(|x| a.contains(x)));
+        assert_eq!(EMPTY.linspace(3).count(), 0);
+        assert_eq!(INFINITY.linspace(3).count(), 0);
+        assert_eq!(Interval::new(Closed(0.), Unbound).linspace(3).count(), 0);
+    }
+
+    #[test]
+    fn test_iter_step_1() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        assert_eq!(a.iter_step(0.5).collect::<Vec<_>>(), vec![0., 0.5]);
+        assert_eq!(a.iter_step(2.).collect::<Vec<_>>(), vec![0.]);
+        assert_eq!(a.iter_step(0.1).count(), 10);
+    }
+
+    #[test]
+    fn test_iter_step_2() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert_eq!(a.iter_step(2.).count(), 0);
+        assert_eq!(a.iter_step(0.).count(), 0);
+        assert_eq!(a.iter_step(-1.).count(), 0);
+        assert_eq!(a.iter_step(f64::NAN).count(), 0);
+    }
+
+    #[test]
+    fn test_iter_step_3() {
+        assert_eq!(EMPTY.iter_step(1.).count(), 0);
+        assert_eq!(INFINITY.iter_step(1.).count(), 0);
+        assert_eq!(Interval::new(Unbound, Closed(0.)).iter_step(1.).count(), 0);
+        assert_eq!(
+            Interval::singleton(42.).iter_step(1.).collect::<Vec<_>>(),
+            vec![42.]
+        );
+    }
+}
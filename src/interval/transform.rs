@@ -0,0 +1,722 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::bound::Bound::{self, Closed, Open, Unbound};
+use super::left::Left;
+use super::right::Right;
+use super::{Interval, EMPTY};
+use crate::math;
+
+/// Cut point `i` of `[a, b]` into `n` equal parts, non-decreasing with `i`
+pub(super) fn subdivision_cut(a: f64, b: f64, n: usize, i: usize) -> f64 {
+    let t = i as f64 / n as f64;
+    if (b - a).is_finite() {
+        a + (b - a) * t
+    } else {
+        // width overflows
+        a * (1. - t) + b * t
+    }
+}
+
+/// Rounding mode of `Interval::round_endpoints`
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round left endpoint down and right endpoint up: rounded interval contains original one
+    Outward,
+    /// Round both endpoints to the nearest value (half away from zero)
+    Nearest,
+}
+
+/// Round `k` to `decimals` places, `direction` being -1 (down), 1 (up) or 0 (nearest)
+///
+/// Result may overflow to an infinity.
+fn round_to(k: f64, decimals: i32, direction: i8) -> f64 {
+    // finite values are all below 10^309, so that fewer decimals round them alike, while
+    // 10^decimals would underflow to zero
+    let scale = math::powi(10f64, decimals.max(-308));
+    let scaled = k * scale;
+    if !scaled.is_finite() || scaled.abs() >= math::powi(2f64, f64::MANTISSA_DIGITS as i32) {
+        return k;
+    }
+    match direction {
+        -1 => {
+            let r = math::floor(scaled);
+            if r / scale > k {
+                (r - 1.) / scale
+            } else {
+                r / scale
+            }
+        }
+        1 => {
+            let r = math::ceil(scaled);
+            if r / scale < k {
+                (r + 1.) / scale
+            } else {
+                r / scale
+            }
+        }
+        _ => math::round(scaled) / scale,
+    }
+}
+
+/// Move a bound by `delta`, keeping its kind
+fn shift(bound: Bound, delta: f64) -> Bound {
+    match bound {
+        Closed(k) => Closed(k + delta),
+        Open(k) => Open(k + delta),
+        Unbound => Unbound,
+    }
+}
+
+impl Interval {
+    /// Linearly map `x` from interval range to `target` range
+    ///
+    /// Points outside of `self` are extrapolated. Note that open or closed endpoints are not
+    /// taken into account: only endpoint values matter.
+    ///
+    /// # Returns
+    ///
+    /// `None` if one of the intervals is empty or unbounded, or if `self` is degenerate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    /// let b = Interval::new(Closed(100.), Open(200.));
+    ///
+    /// assert_eq!(a.remap(2.5, &b), Some(125.));
+    /// assert_eq!(b.remap(125., &a), Some(2.5));
+    /// assert_eq!(a.remap(2.5, &Interval::new(Closed(0.), Unbound)), None);
+    /// ```
+    ///
+    pub fn remap(&self, x: f64, target: &Interval) -> Option<f64> {
+        if !self.is_bounded() || !target.is_bounded() || self.is_degenerate() {
+            return None;
+        }
+        let (a1, a2) = (self.inf()?, self.sup()?);
+        let (b1, b2) = (target.inf()?, target.sup()?);
+        Some(b1 + (x - a1) * (b2 - b1) / (a2 - a1))
+    }
+
+    /// Interval interpolated between `self` (`t = 0`) and `other` (`t = 1`)
+    ///
+    /// Endpoints are interpolated separately, as `(1 - t) * a + t * b`, so that `t = 0` and
+    /// `t = 1` give back the operands exactly. In between, an endpoint is closed if both
+    /// interpolated endpoints are closed, open otherwise.
+    ///
+    /// # Returns
+    ///
+    /// `None` if an interval is empty or unbounded, or if `t` lies outside of `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(2.));
+    /// let b = Interval::new(Closed(10.), Open(20.));
+    ///
+    /// assert_eq!(a.lerp(&b, 0.5), Some(Interval::new(Closed(5.), Open(11.))));
+    /// assert_eq!(a.lerp(&b, 1.), Some(b));
+    /// assert_eq!(a.lerp(&b, 1.5), None);
+    /// assert_eq!(a.lerp(&Interval::new(Closed(0.), Unbound), 0.5), None);
+    /// ```
+    ///
+    pub fn lerp(&self, other: &Interval, t: f64) -> Option<Interval> {
+        let valid = |a: &Interval| !a.is_empty() && a.is_bounded();
+        if !valid(self) || !valid(other) || !(0. ..=1.).contains(&t) {
+            return None;
+        }
+        if t == 0. || t == 1. {
+            return Some(if t == 0. { *self } else { *other });
+        }
+        let mix = |a: Bound, b: Bound| match (a, b) {
+            (Closed(x), Closed(y)) => Some(Closed((1. - t) * x + t * y)),
+            (a, b) => Some(Open((1. - t) * a.value()? + t * b.value()?)),
+        };
+        Some(Interval::new(
+            mix(self.left(), other.left())?,
+            mix(self.right(), other.right())?,
+        ))
+    }
+
+    /// Split interval at `x` into the parts lying below and above `x`
+    ///
+    /// Point `x` itself (if it belongs to interval) goes to the lower part when `keep_point_left`
+    /// is set, to the upper part otherwise. A part may be `EMPTY`, and their union is always
+    /// equal to `self`: a `NaN` cut splits nothing off, and leaves the whole interval as lower
+    /// part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    ///
+    /// assert_eq!(
+    ///     a.split_at(4., true),
+    ///     (Interval::new(Closed(0.), Closed(4.)), Interval::new(Open(4.), Closed(10.)))
+    /// );
+    /// assert_eq!(
+    ///     a.split_at(4., false),
+    ///     (Interval::new(Closed(0.), Open(4.)), Interval::new(Closed(4.), Closed(10.)))
+    /// );
+    /// assert_eq!(a.split_at(12., true), (a, EMPTY));
+    /// ```
+    ///
+    pub fn split_at(&self, x: f64, keep_point_left: bool) -> (Interval, Interval) {
+        if self.is_empty() {
+            return (EMPTY, EMPTY);
+        }
+        if x.is_nan() {
+            return (*self, EMPTY);
+        }
+        let (cut_right, cut_left) = if keep_point_left {
+            (Right(Closed(x)), Left(Open(x)))
+        } else {
+            (Right(Open(x)), Left(Closed(x)))
+        };
+        let Interval(left, right) = *self;
+        let (Right(lower_right), Left(upper_left)) = (right.min(cut_right), left.max(cut_left));
+        (
+            Interval::new(left.0, lower_right),
+            Interval::new(upper_left, right.0),
+        )
+    }
+
+    /// Split interval in two halves at its midpoint
+    ///
+    /// Midpoint goes to the upper half.
+    ///
+    /// # Returns
+    ///
+    /// `None` if interval is empty or unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    ///
+    /// assert_eq!(
+    ///     a.bisect(),
+    ///     Some((Interval::new(Closed(0.), Open(5.)), Interval::new(Closed(5.), Closed(10.))))
+    /// );
+    /// ```
+    ///
+    pub fn bisect(&self) -> Option<(Interval, Interval)> {
+        Some(self.split_at(self.midpoint()?, false))
+    }
+
+    /// Split interval into `n` contiguous subintervals of equal width
+    ///
+    /// First subinterval keeps the left bound of `self`, last one keeps its right bound, and the
+    /// inner cut points are closed on their right side (ie. `[x,y)` tiles), so that subintervals
+    /// are disjoint and their union is exactly `self`. Degenerate intervals produce `EMPTY`
+    /// subintervals but the last one.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the subintervals, which yields nothing if `self` is empty or unbounded,
+    /// or if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Open(0.), Closed(3.));
+    /// let tiles: Vec<Interval> = a.subdivide(3).collect();
+    ///
+    /// assert_eq!(
+    ///     tiles,
+    ///     vec![
+    ///         Interval::new(Open(0.), Open(1.)),
+    ///         Interval::new(Closed(1.), Open(2.)),
+    ///         Interval::new(Closed(2.), Closed(3.)),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    pub fn subdivide(&self, n: usize) -> impl Iterator<Item = Interval> {
+        let this = *self;
+        let n = if this.is_bounded() && !this.is_empty() {
+            n
+        } else {
+            0
+        };
+        let (a, b) = (this.inf().unwrap_or(0.), this.sup().unwrap_or(0.));
+        let cut = move |i: usize| subdivision_cut(a, b, n, i);
+        let Interval(first, last) = this;
+
+        // rounded cuts may reach an endpoint of a narrow interval: keep tiles within it
+        (0..n).map(move |i| {
+            let Left(left) = if i == 0 {
+                first
+            } else {
+                Left(Closed(cut(i))).max(first)
+            };
+            let Right(right) = if i + 1 == n {
+                last
+            } else {
+                Right(Open(cut(i + 1))).min(last)
+            };
+            Interval::new(left, right)
+        })
+    }
+
+    /// Tiling of interval by `n` contiguous tiles of equal width
+    ///
+    /// Tiles are those of `Interval::subdivide`: they are half-open, the first one keeping the
+    /// left bound of interval and the last one its right bound, so that every point of interval
+    /// belongs to exactly one tile. Tiles narrower than the float spacing may be empty.
+    ///
+    /// # Returns
+    ///
+    /// `n` tiles, or none if interval is empty or unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let domain = Interval::new(Closed(0.), Closed(1.));
+    /// let tiles = domain.partition(4);
+    ///
+    /// assert_eq!(tiles.len(), 4);
+    /// assert_eq!(tiles[1], Interval::new(Closed(0.25), Open(0.5)));
+    /// assert_eq!(tiles[3], Interval::new(Closed(0.75), Closed(1.)));
+    /// assert!(tiles.windows(2).all(|pair| pair[0].is_adjacent(&pair[1])));
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn partition(&self, n: usize) -> Vec<Interval> {
+        self.subdivide(n).collect()
+    }
+
+    /// Translate interval by `d`, endpoints keeping their kind
+    ///
+    #[cfg(feature = "alloc")]
+    pub(crate) fn translate(&self, d: f64) -> Interval {
+        if self.is_empty() {
+            return EMPTY;
+        }
+        Interval::new(shift(self.left(), d), shift(self.right(), d))
+    }
+
+    /// Widen interval by moving both endpoints outward by `eps`
+    ///
+    /// Endpoints keep their kind. A negative `eps` shrinks interval (see `Interval::deflate`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert_eq!(a.inflate(0.5), Interval::new(Closed(-0.5), Open(1.5)));
+    /// assert_eq!(Interval::new(Unbound, Open(1.)).inflate(1.), Interval::new(Unbound, Open(2.)));
+    /// assert_eq!(EMPTY.inflate(1.), EMPTY);
+    /// ```
+    ///
+    pub fn inflate(&self, eps: f64) -> Interval {
+        if self.is_empty() {
+            return EMPTY;
+        }
+        Interval::new(shift(self.left(), -eps), shift(self.right(), eps))
+    }
+
+    /// Shrink interval by moving both endpoints inward by `eps`
+    ///
+    /// Endpoints keep their kind, and interval becomes `EMPTY` when endpoints cross.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert_eq!(a.deflate(0.25), Interval::new(Closed(0.25), Open(0.75)));
+    /// assert_eq!(a.deflate(0.5), EMPTY);
+    /// ```
+    ///
+    pub fn deflate(&self, eps: f64) -> Interval {
+        self.inflate(-eps)
+    }
+
+    /// Widen interval proportionally to its width
+    ///
+    /// Each endpoint moves outward by `factor * width / 2`, so that width is multiplied by
+    /// `1 + factor`. When width is zero or not finite (unbounded or huge intervals), each finite
+    /// endpoint moves by `factor` times its own magnitude instead. A negative `factor` shrinks
+    /// interval (possibly to `EMPTY`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound};
+    ///
+    /// let a = Interval::new(Closed(1.), Open(3.));
+    /// let b = Interval::new(Closed(10.), Unbound);
+    ///
+    /// assert_eq!(a.widen_relative(0.5), Interval::new(Closed(0.5), Open(3.5)));
+    /// assert_eq!(b.widen_relative(0.1), Interval::new(Closed(9.), Unbound));
+    /// ```
+    ///
+    pub fn widen_relative(&self, factor: f64) -> Interval {
+        if self.is_empty() {
+            return EMPTY;
+        }
+        let width = self.width();
+        if width > 0. && width.is_finite() {
+            return self.inflate(factor * width / 2.);
+        }
+        let widen = |bound: Bound, direction: f64| match bound {
+            Closed(k) | Open(k) => shift(bound, direction * factor * k.abs()),
+            Unbound => Unbound,
+        };
+        Interval::new(widen(self.left(), -1.), widen(self.right(), 1.))
+    }
+
+    /// Round endpoints to `decimals` decimal places
+    ///
+    /// Endpoints keep their kind. With `RoundingMode::Outward`, rounded interval is guaranteed to
+    /// contain the original one (ie. it is safe to publish); with `RoundingMode::Nearest` it is
+    /// not, and interval may even become `EMPTY`. A negative `decimals` rounds to tens, hundreds...
+    /// down to `-308`, which lower values stand for. Endpoints rounded outward beyond `f64::MAX`
+    /// become unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, RoundingMode};
+    ///
+    /// let a = Interval::new(Closed(0.123), Open(4.561));
+    ///
+    /// assert_eq!(
+    ///     a.round_endpoints(2, RoundingMode::Outward),
+    ///     Interval::new(Closed(0.12), Open(4.57))
+    /// );
+    /// assert_eq!(
+    ///     a.round_endpoints(2, RoundingMode::Nearest),
+    ///     Interval::new(Closed(0.12), Open(4.56))
+    /// );
+    /// ```
+    ///
+    pub fn round_endpoints(&self, decimals: i32, mode: RoundingMode) -> Interval {
+        if self.is_empty() {
+            return EMPTY;
+        }
+        let (down, up) = match mode {
+            RoundingMode::Outward => (-1, 1),
+            RoundingMode::Nearest => (0, 0),
+        };
+        // endpoints rounded beyond the largest `f64` go unbound on their side, or leave
+        // interval empty on the other side
+        let round = |bound: Bound, direction: i8, outer: f64| match bound {
+            Closed(k) | Open(k) => match round_to(k, decimals, direction) {
+                r if r == outer => Unbound,
+                r if r.is_infinite() => Open(r),
+                r => bound.map(|_| r),
+            },
+            Unbound => Unbound,
+        };
+        Interval::new(
+            round(self.left(), down, f64::NEG_INFINITY),
+            round(self.right(), up, f64::INFINITY),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_remap_1() {
+        let a = Interval::new(Open(-1.), Open(1.));
+        let b = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.remap(-1., &b), Some(0.));
+        assert_eq!(a.remap(0., &b), Some(0.5));
+        assert_eq!(a.remap(3., &b), Some(2.));
+    }
+
+    #[test]
+    fn test_remap_2() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.remap(0.5, &Interval::singleton(42.)), Some(42.));
+        assert_eq!(Interval::singleton(42.).remap(42., &a), None);
+    }
+
+    #[test]
+    fn test_remap_3() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.remap(0.5, &EMPTY), None);
+        assert_eq!(EMPTY.remap(0.5, &a), None);
+        assert_eq!(INFINITY.remap(0.5, &a), None);
+        assert_eq!(Interval::new(Unbound, Closed(0.)).remap(0.5, &a), None);
+    }
+
+    #[test]
+    fn test_split_at_1() {
+        let a = Interval::new(Open(0.), Open(10.));
+        assert_eq!(a.split_at(0., true), (EMPTY, a));
+        assert_eq!(a.split_at(0., false), (EMPTY, a));
+        assert_eq!(a.split_at(10., true), (a, EMPTY));
+        assert_eq!(a.split_at(-1., false), (EMPTY, a));
+    }
+
+    #[test]
+    fn test_split_at_2() {
+        let (lower, upper) = INFINITY.split_at(0., false);
+        assert_eq!(lower, Interval::new(Unbound, Open(0.)));
+        assert_eq!(upper, Interval::new(Closed(0.), Unbound));
+    }
+
+    #[test]
+    fn test_split_at_3() {
+        let a = Interval::singleton(42.);
+        assert_eq!(a.split_at(42., true), (a, EMPTY));
+        assert_eq!(a.split_at(42., false), (EMPTY, a));
+        assert_eq!(EMPTY.split_at(42., false), (EMPTY, EMPTY));
+    }
+
+    #[test]
+    fn test_split_at_4() {
+        let a = Interval::new(Closed(0.), Open(10.));
+        assert_eq!(a.split_at(f64::NAN, true), (a, EMPTY));
+        assert_eq!(a.split_at(f64::NAN, false), (a, EMPTY));
+        assert_eq!(INFINITY.split_at(f64::NAN, false), (INFINITY, EMPTY));
+        assert_eq!(EMPTY.split_at(f64::NAN, true), (EMPTY, EMPTY));
+    }
+
+    #[test]
+    fn test_bisect_1() {
+        let a = Interval::new(Open(0.), Open(1.));
+        assert_eq!(
+            a.bisect(),
+            Some((
+                Interval::new(Open(0.), Open(0.5)),
+                Interval::new(Closed(0.5), Open(1.))
+            ))
+        );
+        assert_eq!(INFINITY.bisect(), None);
+        assert_eq!(EMPTY.bisect(), None);
+    }
+
+    #[test]
+    fn test_subdivide_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let tiles: Vec<Interval> = a.subdivide(4).collect();
+        assert_eq!(tiles.len(), 4);
+        assert_eq!(tiles[0], Interval::new(Closed(0.), Open(0.25)));
+        assert_eq!(tiles[3], Interval::new(Closed(0.75), Closed(1.)));
+        for w in tiles.windows(2) {
+            assert!(w[0].is_adjacent(&w[1]));
+        }
+    }
+
+    #[test]
+    fn test_subdivide_2() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        assert_eq!(a.subdivide(1).collect::<Vec<_>>(), vec![a]);
+        assert_eq!(a.subdivide(0).count(), 0);
+        assert_eq!(INFINITY.subdivide(3).count(), 0);
+        assert_eq!(EMPTY.subdivide(3).count(), 0);
+    }
+
+    #[test]
+    fn test_subdivide_3() {
+        // Width of interval overflows: cuts used to be NaN, and tiles EMPTY
+        let a = Interval::new(Closed(-f64::MAX), Closed(f64::MAX));
+        let tiles: Vec<Interval> = a.subdivide(2).collect();
+        assert_eq!(tiles[0], Interval::new(Closed(-f64::MAX), Open(0.)));
+        assert_eq!(tiles[1], Interval::new(Closed(0.), Closed(f64::MAX)));
+    }
+
+    #[test]
+    fn test_subdivide_4() {
+        // One-ulp interval: rounded cuts must not reach past the open endpoint
+        let a = Interval::new(Open(1.), Closed(1f64.next_up()));
+        let tiles: Vec<Interval> = a.subdivide(4).collect();
+        assert_eq!(tiles.len(), 4);
+        for tile in &tiles {
+            assert!(!tile.contains(1.), "{tile}");
+            assert_eq!(tile.intersection(a), *tile, "{tile}");
+        }
+        let b = Interval::new(Closed(1.), Open(1f64.next_up()));
+        assert!(b.subdivide(4).all(|tile| !tile.contains(1f64.next_up())));
+    }
+
+    #[test]
+    fn test_inflate_1() {
+        let a = Interval::singleton(1.);
+        assert_eq!(a.inflate(1.), Interval::new(Closed(0.), Closed(2.)));
+        assert_eq!(a.inflate(-1.), EMPTY);
+        assert_eq!(INFINITY.inflate(1.), INFINITY);
+    }
+
+    #[test]
+    fn test_deflate_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.deflate(0.5), Interval::singleton(0.5));
+        assert_eq!(a.deflate(-1.), Interval::new(Closed(-1.), Closed(2.)));
+        assert_eq!(INFINITY.deflate(1.), INFINITY);
+        assert_eq!(EMPTY.deflate(1.), EMPTY);
+    }
+
+    #[test]
+    fn test_widen_relative_1() {
+        let a = Interval::new(Closed(-1.), Closed(1.));
+        assert_eq!(a.widen_relative(1.), Interval::new(Closed(-2.), Closed(2.)));
+        assert_eq!(
+            a.widen_relative(-0.5),
+            Interval::new(Closed(-0.5), Closed(0.5))
+        );
+        assert_eq!(a.widen_relative(-2.), EMPTY);
+    }
+
+    #[test]
+    fn test_widen_relative_2() {
+        let a = Interval::singleton(-10.);
+        assert_eq!(
+            a.widen_relative(0.1),
+            Interval::new(Closed(-11.), Closed(-9.))
+        );
+        assert_eq!(
+            Interval::singleton(0.).widen_relative(0.1),
+            Interval::singleton(0.)
+        );
+    }
+
+    #[test]
+    fn test_widen_relative_3() {
+        let a = Interval::new(Closed(-f64::MAX), Closed(f64::MAX));
+        assert_eq!(
+            a.widen_relative(-0.5),
+            Interval::new(Closed(-f64::MAX / 2.), Closed(f64::MAX / 2.))
+        );
+        assert_eq!(INFINITY.widen_relative(1.), INFINITY);
+        assert_eq!(EMPTY.widen_relative(1.), EMPTY);
+    }
+
+    #[test]
+    fn test_round_endpoints_1() {
+        let a = Interval::new(Closed(0.1 + 0.2), Closed(0.7));
+        let b = a.round_endpoints(1, RoundingMode::Outward);
+        assert_eq!(b, Interval::new(Closed(0.3), Closed(0.7)));
+        assert!(b.inf().unwrap() <= a.inf().unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_partition_1() {
+        let a = Interval::new(Open(-1.), Open(2.));
+        let tiles = a.partition(3);
+        assert_eq!(tiles[0], Interval::new(Open(-1.), Open(0.)));
+        assert_eq!(tiles[2], Interval::new(Closed(1.), Open(2.)));
+        for x in [-0.5, 0., 1., 1.99] {
+            assert_eq!(tiles.iter().filter(|tile| tile.contains(x)).count(), 1);
+        }
+        assert!(EMPTY.partition(3).is_empty() && INFINITY.partition(3).is_empty());
+        assert!(a.partition(0).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_partition_2() {
+        // Each point of a one-ulp interval lies in exactly one tile, and tiles stay within it
+        for a in [
+            Interval::new(Open(1.), Closed(1f64.next_up())),
+            Interval::new(Closed(1.), Open(1f64.next_up())),
+            Interval::new(Closed(1.), Closed(1f64.next_up())),
+        ] {
+            let tiles = a.partition(4);
+            assert!(tiles.iter().all(|tile| tile.intersection(a) == *tile));
+            for x in [1., 1f64.next_up()] {
+                let count = tiles.iter().filter(|tile| tile.contains(x)).count();
+                assert_eq!(count, usize::from(a.contains(x)), "{a} {x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_lerp_1() {
+        let a = Interval::new(Open(-1.), Closed(1.));
+        let b = Interval::new(Closed(3.), Closed(5.));
+        assert_eq!(a.lerp(&b, 0.), Some(a));
+        assert_eq!(a.lerp(&b, 0.25), Some(Interval::new(Open(0.), Closed(2.))));
+        assert_eq!(b.lerp(&a, 0.75), a.lerp(&b, 0.25));
+        assert_eq!(
+            a.lerp(&Interval::singleton(1.), 0.5),
+            Some(Interval::new(Open(0.), Closed(1.)))
+        );
+        assert_eq!(a.lerp(&b, f64::NAN), None);
+        assert_eq!(a.lerp(&b, -0.1), None);
+        assert_eq!(a.lerp(&EMPTY, 0.), None);
+        assert_eq!(INFINITY.lerp(&b, 1.), None);
+    }
+
+    #[test]
+    fn test_round_endpoints_2() {
+        let a = Interval::new(Open(-1234.5), Unbound);
+        assert_eq!(
+            a.round_endpoints(-2, RoundingMode::Outward),
+            Interval::new(Open(-1300.), Unbound)
+        );
+        assert_eq!(
+            a.round_endpoints(-2, RoundingMode::Nearest),
+            Interval::new(Open(-1200.), Unbound)
+        );
+    }
+
+    #[test]
+    fn test_round_endpoints_3() {
+        let a = Interval::new(Closed(0.41), Closed(0.44));
+        assert_eq!(
+            a.round_endpoints(1, RoundingMode::Nearest),
+            Interval::singleton(0.4)
+        );
+        let b = Interval::new(Open(0.41), Open(0.44));
+        assert_eq!(b.round_endpoints(1, RoundingMode::Nearest), EMPTY);
+        assert_eq!(EMPTY.round_endpoints(1, RoundingMode::Outward), EMPTY);
+    }
+
+    #[test]
+    fn test_round_endpoints_4() {
+        let a = Interval::new(Closed(1e300), Closed(f64::MAX));
+        assert_eq!(a.round_endpoints(2, RoundingMode::Outward), a);
+    }
+
+    #[test]
+    fn test_round_endpoints_5() {
+        // Rounding to powers of ten beyond the range of f64
+        let a = Interval::new(Closed(-5.), Open(3.));
+        for decimals in [-309, -324, -400, i32::MIN] {
+            let b = a.round_endpoints(decimals, RoundingMode::Outward);
+            assert_eq!(b.hull(a), b, "{decimals}");
+            assert!(b.inf().unwrap() <= -1e307 && b.sup().unwrap() >= 1e307);
+            assert_eq!(
+                a.round_endpoints(decimals, RoundingMode::Nearest),
+                EMPTY,
+                "{decimals}"
+            );
+        }
+        let b = Interval::new(Closed(f64::MAX), Unbound);
+        let c = b.round_endpoints(-308, RoundingMode::Outward);
+        assert_eq!(c.hull(b), c);
+        assert_eq!(b.round_endpoints(-308, RoundingMode::Nearest), EMPTY);
+        assert_eq!(
+            Interval::new(Closed(0.), Closed(1.)).round_endpoints(i32::MAX, RoundingMode::Nearest),
+            Interval::new(Closed(0.), Closed(1.))
+        );
+    }
+}
@@ -0,0 +1,378 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use super::{Interval, EMPTY, INFINITY};
+
+/// Axis-aligned box of ℝⁿ: cartesian product of `N` intervals
+///
+/// A box is empty as soon as one of its axes is empty, in which case all its axes are stored as
+/// `EMPTY`, so that all empty boxes are equal.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalBox, Closed, Open};
+///
+/// let a = IntervalBox::new([
+///     Interval::new(Closed(0.), Closed(2.)),
+///     Interval::new(Closed(0.), Open(1.)),
+/// ]);
+/// let b = IntervalBox::new([
+///     Interval::new(Closed(1.), Closed(3.)),
+///     Interval::new(Closed(0.), Closed(4.)),
+/// ]);
+///
+/// assert!(a.contains([2., 0.5]));
+/// assert!(!a.contains([2., 1.]));
+/// assert_eq!(a.volume(), 2.);
+/// assert_eq!(
+///     a.intersection(&b),
+///     IntervalBox::new([
+///         Interval::new(Closed(1.), Closed(2.)),
+///         Interval::new(Closed(0.), Open(1.)),
+///     ])
+/// );
+/// assert_eq!(format!("{a}"), "[ 0.00, 2.00] × [ 0.00, 1.00)");
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntervalBox<const N: usize> {
+    axes: [Interval; N],
+}
+
+impl<const N: usize> IntervalBox<N> {
+    /// Build box from its projections on each axis
+    ///
+    pub fn new(axes: [Interval; N]) -> Self {
+        if axes.iter().any(|axis| axis.is_empty()) {
+            IntervalBox::empty()
+        } else {
+            IntervalBox { axes }
+        }
+    }
+
+    pub fn empty() -> Self {
+        IntervalBox { axes: [EMPTY; N] }
+    }
+
+    /// Whole space ℝⁿ
+    ///
+    pub fn infinity() -> Self {
+        IntervalBox {
+            axes: [INFINITY; N],
+        }
+    }
+
+    /// Projections of box on each axis
+    ///
+    pub fn axes(&self) -> &[Interval; N] {
+        &self.axes
+    }
+
+    /// Check if box is empty
+    ///
+    /// The box of dimension zero is a single point, hence not empty.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|axis| axis.is_empty())
+    }
+
+    /// Check if box contains `point`
+    ///
+    pub fn contains(&self, point: [f64; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, x)| axis.contains(x))
+    }
+
+    /// Intersection of two boxes, axis by axis
+    ///
+    pub fn intersection(&self, other: &IntervalBox<N>) -> Self {
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(other.axes) {
+            *axis = axis.intersection(other);
+        }
+        IntervalBox::new(axes)
+    }
+
+    /// Smallest box containing both boxes
+    ///
+    pub fn hull(&self, other: &IntervalBox<N>) -> Self {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(other.axes) {
+            *axis = axis.hull(other);
+        }
+        IntervalBox { axes }
+    }
+
+    /// Volume of box: product of the widths of its axes
+    ///
+    /// # Returns
+    ///
+    /// `0.` for an empty or flat box (even if unbounded), `f64::INFINITY` for any other unbounded
+    /// box.
+    ///
+    pub fn volume(&self) -> f64 {
+        if self.is_empty() || self.axes.iter().any(|axis| axis.width() == 0.) {
+            return 0.;
+        }
+        self.axes.iter().map(|axis| axis.width()).product()
+    }
+
+    /// Center of box
+    ///
+    /// # Returns
+    ///
+    /// `None` if box is empty or unbounded.
+    ///
+    pub fn midpoint(&self) -> Option<[f64; N]> {
+        let mut center = [0.; N];
+        for (k, axis) in center.iter_mut().zip(&self.axes) {
+            *k = axis.midpoint()?;
+        }
+        Some(center)
+    }
+
+    /// Axis along which box is the widest
+    ///
+    /// # Returns
+    ///
+    /// `None` if box is empty or of dimension zero. First widest axis in case of a tie.
+    ///
+    pub fn widest_dimension(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut widest: Option<(usize, f64)> = None;
+        for (i, axis) in self.axes.iter().enumerate() {
+            let width = axis.width();
+            if widest.is_none_or(|(_, max)| width > max) {
+                widest = Some((i, width));
+            }
+        }
+        widest.map(|(i, _)| i)
+    }
+
+    /// Split box in two halves along its widest dimension (see `Interval::bisect`)
+    ///
+    /// # Returns
+    ///
+    /// `None` if box is empty, of dimension zero, or unbounded along its widest dimension.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalBox, Closed, Open};
+    ///
+    /// let a = IntervalBox::new([
+    ///     Interval::new(Closed(0.), Closed(1.)),
+    ///     Interval::new(Closed(0.), Closed(4.)),
+    /// ]);
+    ///
+    /// assert_eq!(a.widest_dimension(), Some(1));
+    /// assert_eq!(
+    ///     a.bisect(),
+    ///     Some((
+    ///         IntervalBox::new([
+    ///             Interval::new(Closed(0.), Closed(1.)),
+    ///             Interval::new(Closed(0.), Open(2.)),
+    ///         ]),
+    ///         IntervalBox::new([
+    ///             Interval::new(Closed(0.), Closed(1.)),
+    ///             Interval::new(Closed(2.), Closed(4.)),
+    ///         ]),
+    ///     ))
+    /// );
+    /// ```
+    ///
+    pub fn bisect(&self) -> Option<(Self, Self)> {
+        let i = self.widest_dimension()?;
+        let (lower, upper) = self.axes[i].bisect()?;
+        let (mut lower_axes, mut upper_axes) = (self.axes, self.axes);
+        lower_axes[i] = lower;
+        upper_axes[i] = upper;
+        Some((IntervalBox::new(lower_axes), IntervalBox::new(upper_axes)))
+    }
+
+    /// Split box recursively until all pieces have a width of at most `max_width` on every axis
+    ///
+    /// Pieces are produced lazily, in depth-first order from a work stack, lower halves first.
+    /// Pieces which cannot be bisected any further (unbounded along their widest dimension, or
+    /// too narrow for a floating-point midpoint) are yielded as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_width` is not strictly positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalBox, Closed};
+    ///
+    /// let a = IntervalBox::new([
+    ///     Interval::new(Closed(0.), Closed(1.)),
+    ///     Interval::new(Closed(0.), Closed(4.)),
+    /// ]);
+    /// let pieces: Vec<IntervalBox<2>> = a.subdivide(1.).collect();
+    ///
+    /// assert_eq!(pieces.len(), 4);
+    /// assert_eq!(pieces.iter().map(|piece| piece.volume()).sum::<f64>(), 4.);
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn subdivide(&self, max_width: f64) -> impl Iterator<Item = IntervalBox<N>> {
+        assert!(max_width > 0., "max_width must be strictly positive");
+        let mut stack = Vec::new();
+        if !self.is_empty() {
+            stack.push(*self);
+        }
+        core::iter::from_fn(move || loop {
+            let piece = stack.pop()?;
+            if piece.axes.iter().all(|axis| axis.width() <= max_width) {
+                return Some(piece);
+            }
+            match piece.bisect() {
+                Some((lower, upper)) if lower != piece && upper != piece => {
+                    stack.push(upper);
+                    stack.push(lower);
+                }
+                _ => return Some(piece),
+            }
+        })
+    }
+}
+
+impl<const N: usize> From<[Interval; N]> for IntervalBox<N> {
+    fn from(axes: [Interval; N]) -> Self {
+        IntervalBox::new(axes)
+    }
+}
+
+impl<const N: usize> Display for IntervalBox<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "∅");
+        }
+        let mut axes = self.axes.iter();
+        if let Some(head) = axes.next() {
+            write!(f, "{head}")?;
+        }
+        for axis in axes {
+            write!(f, " × {axis}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound};
+
+    #[test]
+    fn test_new_1() {
+        let a = IntervalBox::new([Interval::new(Closed(0.), Closed(1.)), EMPTY]);
+        assert!(a.is_empty());
+        assert_eq!(a, IntervalBox::empty());
+        assert_eq!(a.volume(), 0.);
+        assert_eq!(format!("{a}"), "∅");
+        assert!(!IntervalBox::<0>::new([]).is_empty());
+        assert_eq!(IntervalBox::<0>::new([]).volume(), 1.);
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        let a = IntervalBox::new([
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(0.), Closed(1.)),
+        ]);
+        let b = IntervalBox::new([
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(0.), Closed(1.)),
+        ]);
+        assert!(a.intersection(&b).is_empty());
+        assert_eq!(a.intersection(&IntervalBox::infinity()), a);
+    }
+
+    #[test]
+    fn test_hull_1() {
+        let a = IntervalBox::new([
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Unbound, Closed(1.)),
+        ]);
+        let b = IntervalBox::new([Interval::new(Open(2.), Closed(3.)), Interval::singleton(5.)]);
+        let hull = a.hull(&b);
+        assert_eq!(
+            hull,
+            IntervalBox::new([
+                Interval::new(Closed(0.), Closed(3.)),
+                Interval::new(Unbound, Closed(5.)),
+            ])
+        );
+        assert_eq!(hull.volume(), f64::INFINITY);
+        assert_eq!(a.hull(&IntervalBox::empty()), a);
+        assert!(hull.contains([1.5, -1e300]));
+        let flat = IntervalBox::new([INFINITY, Interval::singleton(0.)]);
+        assert_eq!(flat.volume(), 0.);
+    }
+
+    #[test]
+    fn test_widest_dimension_1() {
+        assert_eq!(IntervalBox::<0>::new([]).widest_dimension(), None);
+        assert_eq!(IntervalBox::<2>::empty().widest_dimension(), None);
+        let a = IntervalBox::new([
+            Interval::new(Closed(0.), Closed(2.)),
+            Interval::new(Closed(0.), Closed(2.)),
+            Interval::new(Closed(0.), Unbound),
+        ]);
+        assert_eq!(a.widest_dimension(), Some(2));
+        assert_eq!(a.bisect(), None);
+        let b = IntervalBox::new([Interval::singleton(1.), Interval::new(Open(0.), Open(1.))]);
+        assert_eq!(b.widest_dimension(), Some(1));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_subdivide_1() {
+        let a = IntervalBox::new([
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(0.), Open(2.)),
+        ]);
+        let pieces: Vec<IntervalBox<2>> = a.subdivide(0.5).collect();
+        assert_eq!(pieces.len(), 8);
+        assert_eq!(
+            pieces[0],
+            IntervalBox::new([
+                Interval::new(Closed(0.), Open(0.5)),
+                Interval::new(Closed(0.), Open(0.5)),
+            ])
+        );
+        assert!(pieces.iter().all(|piece| piece.volume() == 0.25));
+        assert!(!pieces.iter().any(|piece| piece.contains([1., 2.])));
+        assert_eq!(
+            pieces
+                .iter()
+                .filter(|piece| piece.contains([0.5, 1.]))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_subdivide_2() {
+        let a = IntervalBox::new([Interval::new(Closed(1.), Closed(1f64.next_up()))]);
+        assert_eq!(a.subdivide(f64::MIN_POSITIVE).collect::<Vec<_>>(), vec![a]);
+        let b = IntervalBox::new([INFINITY]);
+        assert_eq!(b.subdivide(1.).collect::<Vec<_>>(), vec![b]);
+        assert_eq!(IntervalBox::<1>::empty().subdivide(1.).count(), 0);
+    }
+}
@@ -0,0 +1,379 @@
+use super::{Closed, Interval, Open, Unbound, EMPTY};
+use alloc::vec::Vec;
+
+/// Part of `a` lying before `b`
+fn part_below(a: &Interval, b: &Interval) -> Interval {
+    match b.left() {
+        Closed(k) => a.split_at(k, false).0,
+        Open(k) => a.split_at(k, true).0,
+        Unbound => EMPTY,
+    }
+}
+
+/// Part of `a` lying after `b`
+fn part_above(a: &Interval, b: &Interval) -> Interval {
+    match b.right() {
+        Closed(k) => a.split_at(k, true).1,
+        Open(k) => a.split_at(k, false).1,
+        Unbound => EMPTY,
+    }
+}
+
+/// Map from disjoint intervals to values
+///
+/// Entries are kept sorted by key. Keys never overlap, so that a point is mapped to at most one
+/// value.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalMap, Closed, Open, Unbound};
+///
+/// let mut rates = IntervalMap::new();
+/// rates.insert(Interval::new(Closed(0.), Unbound), 0.05);
+/// rates.insert(Interval::new(Closed(10.), Unbound), 0.1);
+///
+/// assert_eq!(rates.get(5.), Some(&0.05));
+/// assert_eq!(rates.get(10.), Some(&0.1));
+/// assert_eq!(rates.get(-1.), None);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalMap<V> {
+    entries: Vec<(Interval, V)>,
+}
+
+impl<V> Default for IntervalMap<V> {
+    fn default() -> Self {
+        IntervalMap::new()
+    }
+}
+
+impl<V> IntervalMap<V> {
+    pub fn new() -> Self {
+        IntervalMap {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Index of the first entry which is not entirely before `key`
+    fn lower_index(&self, key: &Interval) -> usize {
+        self.entries
+            .partition_point(|(segment, _)| segment.certainly_lt(key))
+    }
+
+    /// Range of indices of the entries overlapping a non-empty `key`
+    fn overlap_range(&self, key: &Interval) -> (usize, usize) {
+        let start = self.lower_index(key);
+        let end = start
+            + self.entries[start..]
+                .iter()
+                .take_while(|(segment, _)| !key.certainly_lt(segment))
+                .count();
+        (start, end)
+    }
+
+    /// Insert a value for a key which overlaps no existing key
+    ///
+    /// # Returns
+    ///
+    /// `Err(value)` (leaving map unchanged) if key is empty or overlaps an existing key.
+    ///
+    pub fn try_insert(&mut self, key: Interval, value: V) -> Result<(), V> {
+        if key.is_empty() {
+            return Err(value);
+        }
+        let i = self.lower_index(&key);
+        match self.entries.get(i) {
+            Some((segment, _)) if !key.certainly_lt(segment) => Err(value),
+            _ => {
+                self.entries.insert(i, (key, value));
+                Ok(())
+            }
+        }
+    }
+
+    /// Insert a value for a key, overwriting existing values on the region covered by key
+    ///
+    /// Existing entries overlapping key are truncated (or split in two pieces, hence the `Clone`
+    /// bound) so that they only keep the part lying outside of key: last writer wins. Inserting
+    /// an empty key does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalMap, Closed, Open};
+    ///
+    /// let mut map = IntervalMap::new();
+    /// map.insert(Interval::new(Closed(0.), Closed(10.)), 'a');
+    /// map.insert(Interval::new(Closed(2.), Open(5.)), 'b');
+    ///
+    /// let entries: Vec<(Interval, char)> = map.iter().map(|(k, v)| (*k, *v)).collect();
+    /// assert_eq!(
+    ///     entries,
+    ///     vec![
+    ///         (Interval::new(Closed(0.), Open(2.)), 'a'),
+    ///         (Interval::new(Closed(2.), Open(5.)), 'b'),
+    ///         (Interval::new(Closed(5.), Closed(10.)), 'a'),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    pub fn insert(&mut self, key: Interval, value: V)
+    where
+        V: Clone,
+    {
+        if key.is_empty() {
+            return;
+        }
+        let (start, end) = self.overlap_range(&key);
+        let mut replacement = Vec::new();
+        for (segment, old) in self.entries.drain(start..end) {
+            let below = part_below(&segment, &key);
+            if !below.is_empty() {
+                replacement.push((below, old.clone()));
+            }
+            let above = part_above(&segment, &key);
+            if !above.is_empty() {
+                replacement.push((above, old));
+            }
+        }
+        replacement.push((key, value));
+        replacement.sort_by(|(a, _), (b, _)| a.inf().partial_cmp(&b.inf()).unwrap());
+        self.entries.splice(start..start, replacement);
+    }
+
+    /// Insert a value for a key, resolving conflicts with existing values through `merge`
+    ///
+    /// On the region where key overlaps an existing entry, value becomes
+    /// `merge(existing, value)`; on the rest of key, value is inserted as is. Existing entries
+    /// keep their value outside of key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalMap, Closed, Open};
+    ///
+    /// let mut map = IntervalMap::new();
+    /// map.insert(Interval::new(Closed(0.), Open(2.)), 1);
+    /// map.insert_with(Interval::new(Closed(1.), Open(3.)), 10, |old, new| old + new);
+    ///
+    /// assert_eq!(map.get(0.5), Some(&1));
+    /// assert_eq!(map.get(1.5), Some(&11));
+    /// assert_eq!(map.get(2.5), Some(&10));
+    /// ```
+    ///
+    pub fn insert_with<F>(&mut self, key: Interval, value: V, mut merge: F)
+    where
+        V: Clone,
+        F: FnMut(&V, &V) -> V,
+    {
+        if key.is_empty() {
+            return;
+        }
+        let (start, end) = self.overlap_range(&key);
+        let mut replacement = Vec::new();
+        let mut rest = key;
+        for (segment, old) in self.entries.drain(start..end) {
+            let below = part_below(&segment, &key);
+            if !below.is_empty() {
+                replacement.push((below, old.clone()));
+            }
+            let gap = part_below(&rest, &segment);
+            if !gap.is_empty() {
+                replacement.push((gap, value.clone()));
+            }
+            replacement.push((segment.intersection(key), merge(&old, &value)));
+            rest = part_above(&rest, &segment);
+            let above = part_above(&segment, &key);
+            if !above.is_empty() {
+                replacement.push((above, old));
+            }
+        }
+        if !rest.is_empty() {
+            replacement.push((rest, value));
+        }
+        replacement.sort_by(|(a, _), (b, _)| a.inf().partial_cmp(&b.inf()).unwrap());
+        self.entries.splice(start..start, replacement);
+    }
+
+    /// Value mapped to point `x`
+    ///
+    pub fn get(&self, x: f64) -> Option<&V> {
+        self.get_key_value(x).map(|(_, value)| value)
+    }
+
+    /// Key containing point `x`, and its value
+    ///
+    pub fn get_key_value(&self, x: f64) -> Option<(&Interval, &V)> {
+        let i = self
+            .entries
+            .partition_point(|(segment, _)| segment.certainly_lt_scalar(x));
+        match self.entries.get(i) {
+            Some((segment, value)) if segment.contains(x) => Some((segment, value)),
+            _ => None,
+        }
+    }
+
+    /// Entries, in increasing order of keys
+    pub(crate) fn into_entries(self) -> Vec<(Interval, V)> {
+        self.entries
+    }
+
+    /// Iterate over entries, in increasing order of keys
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval, &V)> {
+        self.entries.iter().map(|(segment, value)| (segment, value))
+    }
+
+    /// Iterate over entries whose key overlaps `query`, in increasing order of keys
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalMap, Closed, Open};
+    ///
+    /// let mut map = IntervalMap::new();
+    /// map.insert(Interval::new(Closed(0.), Open(1.)), 'a');
+    /// map.insert(Interval::new(Closed(1.), Open(2.)), 'b');
+    /// map.insert(Interval::new(Closed(2.), Open(3.)), 'c');
+    ///
+    /// let query = Interval::new(Closed(0.5), Closed(1.5));
+    /// let values: Vec<char> = map.overlapping(&query).map(|(_, v)| *v).collect();
+    ///
+    /// assert_eq!(values, vec!['a', 'b']);
+    /// ```
+    ///
+    pub fn overlapping<'a>(
+        &'a self,
+        query: &'a Interval,
+    ) -> impl Iterator<Item = (&'a Interval, &'a V)> + 'a {
+        let start = if query.is_empty() {
+            self.entries.len()
+        } else {
+            self.lower_index(query)
+        };
+        self.entries[start..]
+            .iter()
+            .take_while(move |(segment, _)| !query.certainly_lt(segment))
+            .map(|(segment, value)| (segment, value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    fn sample() -> IntervalMap<u32> {
+        let mut map = IntervalMap::new();
+        map.try_insert(Interval::new(Closed(2.), Open(3.)), 2)
+            .unwrap();
+        map.try_insert(Interval::new(Unbound, Open(1.)), 0).unwrap();
+        map.try_insert(Interval::new(Closed(1.), Open(2.)), 1)
+            .unwrap();
+        map
+    }
+
+    #[test]
+    fn test_insert_1() {
+        let map = sample();
+        assert_eq!(map.len(), 3);
+        let values: Vec<u32> = map.iter().map(|(_, v)| *v).collect();
+        assert_eq!(values, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_insert_2() {
+        let mut map = sample();
+        assert_eq!(
+            map.try_insert(Interval::new(Closed(2.5), Closed(4.)), 3),
+            Err(3)
+        );
+        assert_eq!(map.try_insert(Interval::singleton(0.), 3), Err(3));
+        assert_eq!(map.try_insert(EMPTY, 3), Err(3));
+        assert_eq!(
+            map.try_insert(Interval::new(Closed(3.), Unbound), 3),
+            Ok(())
+        );
+        assert_eq!(map.try_insert(INFINITY, 4), Err(4));
+        assert_eq!(map.len(), 4);
+    }
+
+    #[test]
+    fn test_insert_3() {
+        let mut map = sample();
+        map.insert(Interval::new(Open(0.), Closed(2.)), 9);
+        let entries: Vec<(Interval, u32)> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(
+            entries,
+            vec![
+                (Interval::new(Unbound, Closed(0.)), 0),
+                (Interval::new(Open(0.), Closed(2.)), 9),
+                (Interval::new(Open(2.), Open(3.)), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_4() {
+        let mut map = sample();
+        map.insert(INFINITY, 9);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(42.), Some(&9));
+        map.insert(EMPTY, 10);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_insert_with_1() {
+        let mut map = sample();
+        map.insert_with(Interval::new(Closed(-1.), Closed(4.)), 10, |old, new| {
+            old + new
+        });
+        let entries: Vec<(Interval, u32)> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(
+            entries,
+            vec![
+                (Interval::new(Unbound, Open(-1.)), 0),
+                (Interval::new(Closed(-1.), Open(1.)), 10),
+                (Interval::new(Closed(1.), Open(2.)), 11),
+                (Interval::new(Closed(2.), Open(3.)), 12),
+                (Interval::new(Closed(3.), Closed(4.)), 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_1() {
+        let map = sample();
+        assert_eq!(map.get(-1e300), Some(&0));
+        assert_eq!(map.get(1.), Some(&1));
+        assert_eq!(map.get(2.999), Some(&2));
+        assert_eq!(map.get(3.), None);
+        assert_eq!(map.get(f64::NAN), None);
+        assert_eq!(IntervalMap::<u32>::new().get(0.), None);
+    }
+
+    #[test]
+    fn test_overlapping_1() {
+        let map = sample();
+        let values = |query: Interval| map.overlapping(&query).map(|(_, v)| *v).collect::<Vec<_>>();
+        assert_eq!(values(Interval::singleton(2.)), vec![2]);
+        assert_eq!(values(Interval::new(Open(1.), Closed(2.))), vec![1, 2]);
+        assert_eq!(
+            values(Interval::new(Closed(3.), Unbound)),
+            Vec::<u32>::new()
+        );
+        assert_eq!(values(INFINITY), vec![0, 1, 2]);
+        assert_eq!(values(EMPTY), Vec::<u32>::new());
+    }
+}
@@ -1,38 +1,187 @@
-use super::{Interval, INFINITY};
+mod batch;
+mod layout;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rand")]
+mod random;
+mod samples;
+mod shared;
+mod storage;
+
+use super::{
+    Bound, Closed, CoalesceIntervals, Coverage, Interval, IntervalContext, IntervalFormat, Left,
+    Open, Unbound, EMPTY, INFINITY,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use auto_ops::impl_op_ex;
-use std::fmt::Display;
+use core::fmt::Display;
+use core::ops::Range;
+pub use layout::{IntervalSetRef, LayoutError};
+pub use shared::SharedIntervalSet;
+use storage::Endpoints;
 
-#[derive(Default, Clone)]
-pub struct IntervalSet {
-    union: Vec<Interval>,
+/// Binary operation on sets, see `IntervalSet::merge_with`
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetOp {
+    Union,
+    Intersection,
+    Difference,
 }
 
-impl Display for IntervalSet {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.is_empty() {
-            write!(f, "∅")
-        } else {
-            let (head, tail) = (self.union[0], &self.union[1..]);
-            write!(f, "{head}")?;
-            for i in tail {
-                write!(f, " U {i}")?;
+/// Kinds of bounds given to `(start, end)` pairs, see `IntervalSet::from_pairs`
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundPolicy {
+    /// `[start, end]`
+    Closed,
+    /// `[start, end)`, the usual convention for time ranges and indices
+    ClosedOpen,
+    /// `(start, end]`
+    OpenClosed,
+    /// `(start, end)`
+    Open,
+}
+
+impl BoundPolicy {
+    /// Interval from `start` to `end` with bounds of this policy
+    ///
+    /// # Returns
+    ///
+    /// `EMPTY` if `start` is greater than `end`, or if the pair holds no point under this policy.
+    ///
+    pub fn interval(self, start: f64, end: f64) -> Interval {
+        match self {
+            BoundPolicy::Closed => Interval::new(Closed(start), Closed(end)),
+            BoundPolicy::ClosedOpen => Interval::new(Closed(start), Open(end)),
+            BoundPolicy::OpenClosed => Interval::new(Open(start), Closed(end)),
+            BoundPolicy::Open => Interval::new(Open(start), Open(end)),
+        }
+    }
+}
+
+/// Bound of the complement on the other side of an endpoint
+fn flip(bound: Bound) -> Bound {
+    match bound {
+        Closed(k) => Open(k),
+        Open(k) => Closed(k),
+        Unbound => Unbound,
+    }
+}
+
+/// Intersection of two sorted lists of disjoint intervals
+fn intersect_components(a: &Endpoints, b: &Endpoints) -> Endpoints {
+    let mut res = Endpoints::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let (a, b) = (a.get(i), b.get(j));
+        let common = a.intersection(b);
+        if !common.is_empty() {
+            res.push(common);
+        }
+        match (a.sup(), b.sup()) {
+            (Some(a2), Some(b2)) if a2 < b2 => i += 1,
+            (Some(a2), Some(b2)) if b2 < a2 => j += 1,
+            _ => {
+                i += 1;
+                j += 1;
             }
-            Ok(())
         }
     }
+    res
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct IntervalSet {
+    union: Endpoints,
+}
+
+/// Notation of `IntervalFormat::new()`
+///
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        IntervalFormat::new().write_set(f, self)
+    }
 }
 
 impl IntervalSet {
     pub fn new() -> Self {
-        IntervalSet { union: Vec::new() }
+        IntervalSet {
+            union: Endpoints::new(),
+        }
     }
 
     pub fn from(array: &[Interval]) -> Self {
-        let mut i = IntervalSet::new();
-        for segment in array {
-            i = i.union_interval(segment);
+        IntervalSet::union_all(array.iter().copied())
+    }
+
+    /// Union of any number of intervals
+    ///
+    /// Intervals are sorted by left bound, then merged in a single sweep: `O(n log n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::union_all([
+    ///     Interval::new(Closed(4.), Closed(5.)),
+    ///     Interval::new(Closed(0.), Open(2.)),
+    ///     Interval::new(Closed(1.), Closed(3.)),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     a,
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Closed(3.)),
+    ///         Interval::new(Closed(4.), Closed(5.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn union_all(intervals: impl IntoIterator<Item = Interval>) -> Self {
+        let mut segments: Vec<Interval> = intervals
+            .into_iter()
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        // `[k..` sorts before `(k..`, so that `(k..` may be merged with `..k)` and `[k,k]`
+        segments.sort_by(|a, b| Left(a.left()).partial_cmp(&Left(b.left())).unwrap());
+
+        IntervalSet {
+            union: CoalesceIntervals::new(segments).collect(),
         }
-        i
+    }
+
+    /// Union of the intervals spanned by `(start, end)` pairs, with bounds given by `policy`
+    ///
+    /// Pairs are sorted and merged as in `IntervalSet::union_all`. Pairs holding no point
+    /// (`start > end`, a `NaN`, or `start == end` unless `policy` is `BoundPolicy::Closed`) are
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{BoundPolicy, Interval, IntervalSet, Closed, Open};
+    ///
+    /// let meetings = [(14., 15.), (9., 10.), (10., 11.5)];
+    /// let busy = IntervalSet::from_pairs(&meetings, BoundPolicy::ClosedOpen);
+    ///
+    /// assert_eq!(
+    ///     busy,
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(9.), Open(11.5)),
+    ///         Interval::new(Closed(14.), Open(15.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn from_pairs(pairs: &[(f64, f64)], policy: BoundPolicy) -> Self {
+        IntervalSet::union_all(
+            pairs
+                .iter()
+                .map(|&(start, end)| policy.interval(start, end)),
+        )
     }
 
     pub fn is_empty(&self) -> bool {
@@ -40,7 +189,376 @@ impl IntervalSet {
     }
 
     pub fn is_infinity(&self) -> bool {
-        self.union.len() == 1 && self.union[0] == INFINITY
+        self.union.len() == 1 && self.union.get(0) == INFINITY
+    }
+
+    /// Number of components
+    ///
+    pub fn len(&self) -> usize {
+        self.union.len()
+    }
+
+    /// Component of index `i`, components being sorted in increasing order
+    ///
+    /// Components are stored as a flat array of endpoints rather than as intervals, hence are
+    /// accessed by index rather than through a slice.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    ///
+    pub fn get(&self, i: usize) -> Interval {
+        assert!(i < self.union.len(), "component index out of bounds");
+        self.union.get(i)
+    }
+
+    /// Index of the component containing `x`
+    ///
+    /// Indices are stable as long as set is not modified, so that external data can be
+    /// associated with components by index.
+    ///
+    /// # Returns
+    ///
+    /// `None` if `x` does not belong to set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let shifts = IntervalSet::from(&[
+    ///     Interval::new(Closed(8.), Open(12.)),
+    ///     Interval::new(Closed(13.), Open(17.)),
+    /// ]);
+    /// let staff = ["alice", "bob"];
+    ///
+    /// assert_eq!(shifts.component_of(14.).map(|i| staff[i]), Some("bob"));
+    /// assert_eq!(shifts.get(1), Interval::new(Closed(13.), Open(17.)));
+    /// assert_eq!(shifts.component_of(12.5), None);
+    /// ```
+    ///
+    pub fn component_of(&self, x: f64) -> Option<usize> {
+        let i = self.union.locate(x);
+        (i < self.union.len() && self.union.get(i).contains(x)).then_some(i)
+    }
+
+    /// Iterate over components of set, in increasing order
+    ///
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Interval> + ExactSizeIterator + '_ {
+        self.union.iter()
+    }
+
+    /// Check if `x` belongs to set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Closed(4.)),
+    /// ]);
+    ///
+    /// assert!(a.contains(0.));
+    /// assert!(!a.contains(1.));
+    /// assert!(a.contains(4.));
+    /// ```
+    ///
+    pub fn contains(&self, x: f64) -> bool {
+        self.component_of(x).is_some()
+    }
+
+    /// Total width (Lebesgue measure) of set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Closed(4.)),
+    /// ]);
+    ///
+    /// assert_eq!(a.measure(), 3.);
+    /// ```
+    ///
+    pub fn measure(&self) -> f64 {
+        self.union.iter().map(|segment| segment.width()).sum()
+    }
+
+    /// Jaccard similarity of two sets: measure of intersection over measure of union
+    ///
+    /// Same conventions as `Interval::jaccard` apply for zero or infinite measures.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Closed(1.)),
+    ///     Interval::new(Closed(2.), Closed(3.)),
+    /// ]);
+    /// let b = IntervalSet::from(&[Interval::new(Closed(0.), Closed(3.))]);
+    ///
+    /// assert_eq!(a.jaccard(&b), 2. / 3.);
+    /// ```
+    ///
+    pub fn jaccard(&self, other: &IntervalSet) -> f64 {
+        let mut intersection = 0.;
+        let (mut i, mut j) = (0, 0);
+        while i < self.union.len() && j < other.union.len() {
+            let (a, b) = (self.union.get(i), other.union.get(j));
+            intersection += a.overlap_length(&b);
+            match (a.sup(), b.sup()) {
+                (Some(a2), Some(b2)) if a2 < b2 => i += 1,
+                (Some(a2), Some(b2)) if b2 < a2 => j += 1,
+                _ => {
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        let union = self.measure() + other.measure() - intersection;
+        if union == 0. {
+            if self == other {
+                1.
+            } else {
+                0.
+            }
+        } else {
+            intersection / union
+        }
+    }
+
+    /// Widen every component by `eps` (see `Interval::inflate`)
+    ///
+    /// Components which come to overlap are merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Closed(4.)),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     a.inflate(0.5),
+    ///     IntervalSet::from(&[Interval::new(Closed(-0.5), Closed(4.5))])
+    /// );
+    /// ```
+    ///
+    pub fn inflate(&self, eps: f64) -> Self {
+        self.union
+            .iter()
+            .map(|segment| segment.inflate(eps))
+            .fold(IntervalSet::new(), |res, segment| {
+                res.union_interval(&segment)
+            })
+    }
+
+    /// Shrink every component by `eps` (see `Interval::deflate`)
+    ///
+    /// Components which become empty are dropped.
+    ///
+    pub fn deflate(&self, eps: f64) -> Self {
+        self.inflate(-eps)
+    }
+
+    /// Keep only the components for which `f` returns `true`
+    ///
+    /// Dropping components only widens gaps, so the remaining ones need no merging.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let mut a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Closed(2.1)),
+    ///     Interval::new(Closed(3.), Closed(5.)),
+    /// ]);
+    /// a.retain(|component| component.width() >= 1.);
+    ///
+    /// assert_eq!(
+    ///     a,
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Open(1.)),
+    ///         Interval::new(Closed(3.), Closed(5.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn retain(&mut self, f: impl FnMut(&Interval) -> bool) {
+        self.union.retain(f);
+    }
+
+    /// Set of the components returned by `f`, `None` dropping the component
+    ///
+    /// Returned components may overlap or be out of order: they are merged as in
+    /// `IntervalSet::union_all`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Open(3.)),
+    ///     Interval::new(Closed(8.), Open(9.)),
+    /// ]);
+    /// let window = Interval::new(Closed(0.), Closed(5.));
+    /// // Drop components outside of window, and extend the others by 1
+    /// let b = a.filter_map_components(|component| {
+    ///     let inside = component.intersection(window);
+    ///     (!inside.is_empty()).then(|| inside.inflate(1.))
+    /// });
+    ///
+    /// assert_eq!(b, IntervalSet::from(&[Interval::new(Closed(-1.), Open(4.))]));
+    /// ```
+    ///
+    pub fn filter_map_components(&self, f: impl FnMut(Interval) -> Option<Interval>) -> Self {
+        IntervalSet::union_all(self.union.iter().filter_map(f))
+    }
+
+    /// Check if sets are equal up to `eps`
+    ///
+    /// Exact equality seldom holds after floating-point computations. Here gaps narrower than
+    /// `eps` are bridged, components narrower than `eps` are ignored, and endpoints closer than
+    /// `eps` match (see `IntervalContext::set_eq`).
+    ///
+    /// # Panics
+    ///
+    /// If `eps` is negative or `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[Interval::new(Closed(0.), Open(0.3))]);
+    /// let b = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(0.1)),
+    ///     Interval::new(Closed(0.1 + 1e-12), Open(0.1 + 0.2)),
+    /// ]);
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.approx_eq(&b, 1e-9));
+    /// ```
+    ///
+    pub fn approx_eq(&self, other: &IntervalSet, eps: f64) -> bool {
+        IntervalContext::new(eps).set_eq(self, other)
+    }
+
+    /// Topological boundary of set: the set of finite endpoints of its components
+    ///
+    /// Endpoints come in increasing order, an endpoint shared by two components (ie. `1` in
+    /// `[0,1) U (1,2]`) appearing once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open, Unbound};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Unbound, Open(0.)),
+    ///     Interval::new(Open(0.), Closed(1.)),
+    ///     Interval::new(Closed(2.), Closed(3.)),
+    /// ]);
+    /// let points: Vec<f64> = a.boundary().iter().filter_map(|p| p.inf()).collect();
+    ///
+    /// assert_eq!(points, vec![0., 1., 2., 3.]);
+    /// ```
+    ///
+    pub fn boundary(&self) -> Self {
+        let mut res = IntervalSet::new();
+        let ends = self.union.iter().flat_map(|segment| {
+            let (left, right) = segment.into_bounds();
+            [left.value(), right.value()]
+        });
+        for k in ends.flatten() {
+            if res.union.last().and_then(|last| last.inf()) != Some(k) {
+                res.union.push(Interval::singleton(k));
+            }
+        }
+        res
+    }
+
+    /// Image of set by `x ↦ scale * x + offset`
+    ///
+    /// Components are mapped in a single pass. A negative `scale` reverses their order and
+    /// swaps the sides of their bounds, and a zero `scale` collapses a non-empty set to the
+    /// singleton `{offset}`. Components brought together by rounding are merged.
+    ///
+    /// # Panics
+    ///
+    /// If `scale` or `offset` is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open, Unbound};
+    ///
+    /// // Seconds to minutes
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(60.)),
+    ///     Interval::new(Closed(120.), Unbound),
+    /// ]);
+    /// assert_eq!(
+    ///     a.map_affine(1. / 60., 0.),
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Open(1.)),
+    ///         Interval::new(Closed(2.), Unbound),
+    ///     ])
+    /// );
+    /// // Time to countdown
+    /// assert_eq!(
+    ///     a.map_affine(-1., 180.),
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Unbound, Closed(60.)),
+    ///         Interval::new(Open(120.), Closed(180.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn map_affine(&self, scale: f64, offset: f64) -> Self {
+        assert!(
+            scale.is_finite() && offset.is_finite(),
+            "scale and offset must be finite"
+        );
+        if scale == 0. {
+            if self.is_empty() {
+                return IntervalSet::new();
+            }
+            return IntervalSet::from(&[Interval::singleton(offset)]);
+        }
+        let image = |segment: Interval| {
+            let (left, right) = segment.into_bounds();
+            let (left, right) = (
+                left.map(|k| scale * k + offset),
+                right.map(|k| scale * k + offset),
+            );
+            if scale > 0. {
+                Interval::new(left, right)
+            } else {
+                Interval::new(right, left)
+            }
+        };
+        let images = self.union.iter().map(image);
+        let union = if scale > 0. {
+            CoalesceIntervals::new(images).collect()
+        } else {
+            CoalesceIntervals::new(images.rev()).collect()
+        };
+        IntervalSet { union }
     }
 
     pub fn union_interval(&self, interval: &Interval) -> Self {
@@ -48,14 +566,14 @@ impl IntervalSet {
         let mut current = *interval;
 
         for (i, segment) in self.union.iter().enumerate() {
-            match current.union(*segment) {
-                (a, Some(b)) if a == current && b == *segment => {
+            match current.union(segment) {
+                (a, Some(b)) if a == current && b == segment => {
                     res.union.push(current);
-                    res.union.extend_from_slice(&self.union[i..]);
+                    res.union.extend_from(&self.union, i);
                     return res;
                 }
                 (_, Some(_)) => {
-                    res.union.push(*segment);
+                    res.union.push(segment);
                 }
                 (new, None) => {
                     current = new;
@@ -70,12 +588,289 @@ impl IntervalSet {
     }
 
     pub fn union_intervals(&self, intervals: &IntervalSet) -> Self {
-        let mut res = self.clone();
-        for segment in intervals.union.iter() {
-            res = res.union_interval(segment)
+        self.merge_with(intervals, SetOp::Union)
+    }
+
+    /// Intersection of two sets
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(2.)),
+    ///     Interval::new(Closed(3.), Closed(5.)),
+    /// ]);
+    /// let b = IntervalSet::from(&[Interval::new(Closed(1.), Closed(4.))]);
+    ///
+    /// assert_eq!(
+    ///     a.intersection(&b),
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(1.), Open(2.)),
+    ///         Interval::new(Closed(3.), Closed(4.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn intersection(&self, other: &IntervalSet) -> Self {
+        self.merge_with(other, SetOp::Intersection)
+    }
+
+    /// Points of `self` which do not belong to `other`
+    ///
+    pub fn difference(&self, other: &IntervalSet) -> Self {
+        self.merge_with(other, SetOp::Difference)
+    }
+
+    /// Indices of the components meeting `pred`, for a predicate holding on a contiguous run of
+    /// components starting next to `inf`
+    ///
+    /// # Returns
+    ///
+    /// An empty range at the index of the first component not lying before `inf` if no
+    /// component meets `pred`.
+    ///
+    fn span(&self, inf: f64, pred: impl Fn(Interval) -> bool) -> Range<usize> {
+        let at = self.union.locate(inf);
+        let Some(from) = (at.saturating_sub(1)..self.union.len())
+            .take(3)
+            .find(|&i| pred(self.union.get(i)))
+        else {
+            return at..at;
+        };
+        let to = (from..self.union.len())
+            .find(|&i| !pred(self.union.get(i)))
+            .unwrap_or(self.union.len());
+        from..to
+    }
+
+    /// Remove the points of `interval` from set, in place
+    ///
+    /// Only the components meeting `interval` are edited: they are shrunk, split in two or
+    /// dropped. Same as `difference` with a single interval, without rebuilding the whole set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let mut agenda = IntervalSet::from(&[
+    ///     Interval::new(Closed(9.), Open(12.)),
+    ///     Interval::new(Closed(14.), Open(18.)),
+    /// ]);
+    /// agenda.remove(&Interval::new(Closed(11.), Open(15.)));
+    ///
+    /// assert_eq!(
+    ///     agenda,
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(9.), Open(11.)),
+    ///         Interval::new(Closed(15.), Open(18.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn remove(&mut self, interval: &Interval) {
+        let Some(inf) = interval.inf() else {
+            return;
+        };
+        let range = self.span(inf, |segment| !segment.intersection(*interval).is_empty());
+        if range.is_empty() {
+            return;
+        }
+        let before = match interval.left() {
+            Unbound => EMPTY,
+            left => self
+                .union
+                .get(range.start)
+                .intersection(Interval::new(Unbound, flip(left))),
+        };
+        let after = match interval.right() {
+            Unbound => EMPTY,
+            right => self
+                .union
+                .get(range.end - 1)
+                .intersection(Interval::new(flip(right), Unbound)),
+        };
+        let pieces = [before, after]
+            .into_iter()
+            .filter(|piece| !piece.is_empty());
+        self.union.splice(range, pieces);
+    }
+
+    /// Toggle membership of the points of `interval`, in place
+    ///
+    /// Points of `interval` are removed from set if they belong to it, and added otherwise:
+    /// this is the symmetric difference with a single interval. Only the components meeting or
+    /// touching `interval` are edited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let mut selection = IntervalSet::from(&[Interval::new(Closed(0.), Open(4.))]);
+    /// selection.toggle(&Interval::new(Closed(2.), Open(6.)));
+    ///
+    /// assert_eq!(
+    ///     selection,
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Open(2.)),
+    ///         Interval::new(Closed(4.), Open(6.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn toggle(&mut self, interval: &Interval) {
+        let Some(inf) = interval.inf() else {
+            return;
+        };
+        let range = self.span(inf, |segment| segment.union(*interval).1.is_none());
+        let local = IntervalSet {
+            union: range.clone().map(|i| self.union.get(i)).collect(),
+        };
+        let toggled = IntervalSet::from(&[*interval]);
+        let res = local
+            .difference(&toggled)
+            .union_intervals(&toggled.difference(&local));
+        self.union.splice(range, res.union.iter());
+    }
+
+    /// Remove point `x` from set, in place
+    ///
+    /// A component containing `x` in its interior is split into two pieces open at `x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let mut a = IntervalSet::from(&[Interval::new(Closed(0.), Closed(2.))]);
+    /// a.remove_point(1.);
+    /// a.remove_point(2.);
+    ///
+    /// assert_eq!(
+    ///     a,
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Open(1.)),
+    ///         Interval::new(Open(1.), Open(2.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn remove_point(&mut self, x: f64) {
+        self.remove(&Interval::singleton(x));
+    }
+
+    /// Combine two sets in a single pass over both sorted lists of components: `O(n + m)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, SetOp, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[Interval::new(Closed(0.), Closed(4.))]);
+    /// let b = IntervalSet::from(&[Interval::new(Closed(1.), Open(2.))]);
+    ///
+    /// assert_eq!(a.merge_with(&b, SetOp::Union), a);
+    /// assert_eq!(a.merge_with(&b, SetOp::Intersection), b);
+    /// assert_eq!(
+    ///     a.merge_with(&b, SetOp::Difference),
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Open(1.)),
+    ///         Interval::new(Closed(2.), Closed(4.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn merge_with(&self, other: &IntervalSet, op: SetOp) -> Self {
+        let union = match op {
+            SetOp::Union => {
+                let mut a = self.union.iter().peekable();
+                let mut b = other.union.iter().peekable();
+                let sorted = core::iter::from_fn(|| match (a.peek(), b.peek()) {
+                    (Some(x), Some(y)) if Left(x.left()) <= Left(y.left()) => a.next(),
+                    (_, Some(_)) => b.next(),
+                    _ => a.next(),
+                });
+                CoalesceIntervals::new(sorted).collect()
+            }
+            SetOp::Intersection => intersect_components(&self.union, &other.union),
+            SetOp::Difference => intersect_components(&self.union, &other.gaps()),
+        };
+        IntervalSet { union }
+    }
+
+    /// Components of the complement of set
+    fn gaps(&self) -> Endpoints {
+        let mut gaps = Endpoints::new();
+        let mut left = Unbound;
+        for segment in self.union.iter() {
+            if segment.left() != Unbound {
+                gaps.push(Interval::new(left, flip(segment.left())));
+            }
+            left = flip(segment.right());
+        }
+        if self
+            .union
+            .last()
+            .is_none_or(|segment| segment.right() != Unbound)
+        {
+            gaps.push(Interval::new(left, Unbound));
+        }
+        gaps
+    }
+
+    /// Intersection of any number of sets
+    ///
+    /// Stops consuming `sets` as soon as the running intersection becomes empty.
+    ///
+    /// # Returns
+    ///
+    /// The whole real line if there is no set.
+    ///
+    pub fn intersection_all<'a>(sets: impl IntoIterator<Item = &'a IntervalSet>) -> Self {
+        let mut res = IntervalSet::from(&[INFINITY]);
+        for set in sets {
+            res = res.intersection(set);
+            if res.is_empty() {
+                break;
+            }
         }
         res
     }
+
+    /// Points belonging to at least `k` of given sets
+    ///
+    /// Computed with a single sweep over the endpoints of all components (see `Coverage`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let sensors = [
+    ///     IntervalSet::from(&[Interval::new(Closed(0.), Closed(10.))]),
+    ///     IntervalSet::from(&[Interval::new(Closed(5.), Closed(15.))]),
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Open(2.)),
+    ///         Interval::new(Closed(8.), Closed(20.)),
+    ///     ]),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     IntervalSet::covered_by_at_least(&sensors, 3),
+    ///     IntervalSet::from(&[Interval::new(Closed(8.), Closed(10.))])
+    /// );
+    /// ```
+    ///
+    pub fn covered_by_at_least<'a>(
+        sets: impl IntoIterator<Item = &'a IntervalSet>,
+        k: usize,
+    ) -> Self {
+        let components = sets.into_iter().flat_map(|set| set.union.iter());
+        Coverage::new(components).at_least(k)
+    }
 }
 
 impl PartialEq for IntervalSet {
@@ -92,8 +887,8 @@ impl PartialEq for IntervalSet {
             return true;
         }
 
-        for (i, segment) in self.union.iter().enumerate() {
-            if *segment != other.union[i] {
+        for (a, b) in self.union.iter().zip(other.union.iter()) {
+            if a != b {
                 return false;
             }
         }
@@ -102,22 +897,209 @@ impl PartialEq for IntervalSet {
     }
 }
 
+#[cfg(feature = "std")]
 impl_op_ex!(| |lhs: &IntervalSet, rhs: &Interval| -> IntervalSet {
     lhs.union_interval(rhs)
 });
 
+#[cfg(feature = "std")]
 impl_op_ex!(| |lhs: &Interval, rhs: &IntervalSet| -> IntervalSet {
     rhs.union_interval(lhs)
 });
 
+#[cfg(feature = "std")]
 impl_op_ex!(| |lhs: &IntervalSet, rhs: &IntervalSet| -> IntervalSet {
-    lhs.union_intervals(rhs)
+    lhs.merge_with(rhs, SetOp::Union)
+});
+
+#[cfg(feature = "std")]
+impl_op_ex!(&|lhs: &IntervalSet, rhs: &Interval| -> IntervalSet {
+    lhs.merge_with(&IntervalSet::from(&[*rhs]), SetOp::Intersection)
 });
 
-#[cfg(test)]
+#[cfg(feature = "std")]
+impl_op_ex!(&|lhs: &Interval, rhs: &IntervalSet| -> IntervalSet {
+    rhs.merge_with(&IntervalSet::from(&[*lhs]), SetOp::Intersection)
+});
+
+#[cfg(feature = "std")]
+impl_op_ex!(&|lhs: &IntervalSet, rhs: &IntervalSet| -> IntervalSet {
+    lhs.merge_with(rhs, SetOp::Intersection)
+});
+
+#[cfg(feature = "std")]
+impl_op_ex!(-|lhs: &IntervalSet, rhs: &Interval| -> IntervalSet {
+    lhs.merge_with(&IntervalSet::from(&[*rhs]), SetOp::Difference)
+});
+
+#[cfg(feature = "std")]
+impl_op_ex!(-|lhs: &Interval, rhs: &IntervalSet| -> IntervalSet {
+    IntervalSet::from(&[*lhs]).merge_with(rhs, SetOp::Difference)
+});
+
+#[cfg(feature = "std")]
+impl_op_ex!(-|lhs: &IntervalSet, rhs: &IntervalSet| -> IntervalSet {
+    lhs.merge_with(rhs, SetOp::Difference)
+});
+
+#[cfg(all(test, feature = "std"))]
 mod test {
     use super::*;
-    use crate::{Closed, EMPTY};
+    use crate::EMPTY;
+
+    #[test]
+    fn test_covered_by_at_least_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(2.), Closed(3.)),
+        ]);
+        let b = IntervalSet::from(&[Interval::new(Open(0.5), Closed(2.))]);
+        let sets = [a.clone(), b.clone(), IntervalSet::new()];
+        assert_eq!(IntervalSet::covered_by_at_least(&sets, 1), &a | &b);
+        assert_eq!(
+            IntervalSet::covered_by_at_least(&sets, 2),
+            IntervalSet::from(&[Interval::new(Open(0.5), Open(1.)), Interval::singleton(2.),])
+        );
+        assert!(IntervalSet::covered_by_at_least(&sets, 3).is_empty());
+        assert!(IntervalSet::covered_by_at_least(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn test_union_all_1() {
+        assert!(IntervalSet::union_all([EMPTY, EMPTY]).is_empty());
+        assert!(IntervalSet::union_all([Interval::singleton(1.), INFINITY]).is_infinity());
+        let a = IntervalSet::union_all([
+            Interval::new(Open(0.), Closed(1.)),
+            Interval::new(Closed(2.), Closed(3.)),
+            EMPTY,
+            Interval::singleton(0.),
+            Interval::new(Open(1.), Open(2.)),
+            Interval::new(Open(3.), Unbound),
+            Interval::new(Unbound, Open(-1.)),
+        ]);
+        assert_eq!(
+            a,
+            IntervalSet::from(&[Interval::new(Unbound, Open(-1.))])
+                | Interval::new(Closed(0.), Unbound)
+        );
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Closed(0.)),
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::new(Closed(3.), Unbound),
+        ]);
+        let b = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(2.), Open(4.)),
+        ]);
+        assert_eq!(
+            a.intersection(&b),
+            IntervalSet::from(&[
+                Interval::singleton(0.),
+                Interval::singleton(2.),
+                Interval::new(Closed(3.), Open(4.)),
+            ])
+        );
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_merge_with_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Open(3.), Closed(4.)),
+        ]);
+        let b = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Open(2.), Closed(3.)),
+            Interval::singleton(5.),
+        ]);
+        assert_eq!(
+            a.merge_with(&b, SetOp::Union),
+            IntervalSet::from(&[Interval::new(Unbound, Closed(4.)), Interval::singleton(5.)])
+        );
+        assert_eq!(
+            a.merge_with(&b, SetOp::Intersection),
+            IntervalSet::from(&[Interval::singleton(1.)])
+        );
+        assert_eq!(
+            a.merge_with(&b, SetOp::Difference),
+            IntervalSet::from(&[
+                Interval::new(Unbound, Open(0.)),
+                Interval::new(Open(1.), Closed(2.)),
+                Interval::new(Open(3.), Closed(4.)),
+            ])
+        );
+        assert_eq!(
+            b.merge_with(&a, SetOp::Difference),
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Open(1.)),
+                Interval::new(Open(2.), Closed(3.)),
+                Interval::singleton(5.),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_merge_with_2() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Closed(1.))]);
+        let e = IntervalSet::new();
+        let inf = IntervalSet::from(&[INFINITY]);
+        assert_eq!(a.merge_with(&e, SetOp::Union), a);
+        assert_eq!(e.merge_with(&a, SetOp::Union), a);
+        assert!(a.merge_with(&e, SetOp::Intersection).is_empty());
+        assert_eq!(a.merge_with(&e, SetOp::Difference), a);
+        assert!(a.merge_with(&inf, SetOp::Difference).is_empty());
+        assert_eq!(
+            inf.merge_with(&a, SetOp::Difference),
+            IntervalSet::from(&[
+                Interval::new(Unbound, Open(0.)),
+                Interval::new(Open(1.), Unbound),
+            ])
+        );
+        assert!(inf.merge_with(&e, SetOp::Difference).is_infinity());
+    }
+
+    #[test]
+    fn test_operators_1() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Closed(2.))]);
+        let b = Interval::new(Open(1.), Closed(3.));
+        assert_eq!(
+            &a | b,
+            IntervalSet::from(&[Interval::new(Closed(0.), Closed(3.))])
+        );
+        assert_eq!(
+            &a & b,
+            IntervalSet::from(&[Interval::new(Open(1.), Closed(2.))])
+        );
+        assert_eq!(b & &a, &a & b);
+        assert_eq!(
+            &a - b,
+            IntervalSet::from(&[Interval::new(Closed(0.), Closed(1.))])
+        );
+        assert_eq!(
+            b - &a,
+            IntervalSet::from(&[Interval::new(Open(2.), Closed(3.))])
+        );
+        assert_eq!(&a - &a, IntervalSet::new());
+    }
+
+    #[test]
+    fn test_intersection_all_1() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Closed(2.))]);
+        let b = IntervalSet::from(&[Interval::new(Open(1.), Closed(3.))]);
+        let c = IntervalSet::from(&[Interval::new(Open(2.), Closed(3.))]);
+        assert_eq!(
+            IntervalSet::intersection_all([&a, &b]),
+            IntervalSet::from(&[Interval::new(Open(1.), Closed(2.))])
+        );
+        assert!(IntervalSet::intersection_all([&a, &b, &c]).is_empty());
+        assert!(IntervalSet::intersection_all([]).is_infinity());
+    }
 
     #[test]
     fn test_empty_1() {
@@ -145,7 +1127,7 @@ mod test {
         let b = Interval::new(Closed(42.), Closed(43.));
         let c = a | b;
         assert!(!c.is_empty());
-        assert_eq!(c.union[0], b);
+        assert_eq!(c.union.get(0), b);
     }
 
     #[test]
@@ -154,7 +1136,7 @@ mod test {
         let b = Interval::new(Closed(42.), Closed(43.));
         let c = b | a;
         assert!(!c.is_empty());
-        assert_eq!(c.union[0], b);
+        assert_eq!(c.union.get(0), b);
     }
 
     #[test]
@@ -172,4 +1154,340 @@ mod test {
 
         assert!((a | b).is_infinity());
     }
+
+    #[test]
+    fn test_contains_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Open(0.), Open(1.)),
+            Interval::singleton(2.),
+            Interval::new(Open(3.), Closed(4.)),
+        ]);
+        for x in [0.5, 2., 4.] {
+            assert!(a.contains(x));
+        }
+        for x in [-1., 0., 1., 1.5, 3., 5., f64::NAN] {
+            assert!(!a.contains(x));
+        }
+        assert!(!IntervalSet::new().contains(0.));
+    }
+
+    #[test]
+    fn test_component_of_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Open(0.), Open(1.)),
+            Interval::singleton(2.),
+            Interval::new(Open(3.), Unbound),
+        ]);
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.component_of(0.5), Some(0));
+        assert_eq!(a.component_of(2.), Some(1));
+        assert_eq!(a.component_of(1e300), Some(2));
+        assert_eq!(a.get(1), Interval::singleton(2.));
+        for x in [0., 1., 3., f64::NAN] {
+            assert_eq!(a.component_of(x), None);
+        }
+        assert_eq!(IntervalSet::new().component_of(0.), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_1() {
+        IntervalSet::from(&[INFINITY]).get(1);
+    }
+
+    #[test]
+    fn test_retain_1() {
+        let mut a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::singleton(1.),
+            Interval::new(Open(2.), Closed(3.)),
+        ]);
+        let b = a.clone();
+        a.retain(|_| true);
+        assert_eq!(a, b);
+        a.retain(|component| component.is_bounded());
+        assert_eq!(
+            a,
+            IntervalSet::from(&[Interval::singleton(1.), Interval::new(Open(2.), Closed(3.))])
+        );
+        a.retain(|_| false);
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn test_retain_2() {
+        // Predicate is called once per component, in order
+        let mut a = IntervalSet::from(&[
+            Interval::singleton(1.),
+            Interval::singleton(2.),
+            Interval::singleton(3.),
+        ]);
+        let mut calls = 0;
+        a.retain(|_| {
+            calls += 1;
+            calls != 2
+        });
+        assert_eq!(calls, 3);
+        assert_eq!(
+            a,
+            IntervalSet::from(&[Interval::singleton(1.), Interval::singleton(3.)])
+        );
+    }
+
+    #[test]
+    fn test_filter_map_components_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(2.), Open(3.)),
+        ]);
+        // Components touching after mapping are merged
+        let b = a.filter_map_components(|component| Some(component.inflate(0.5)));
+        assert_eq!(
+            b,
+            IntervalSet::from(&[Interval::new(Closed(-0.5), Open(3.5))])
+        );
+        assert_eq!(b.len(), 1);
+        assert!(a.filter_map_components(|_| None).is_empty());
+    }
+
+    #[test]
+    fn test_boundary_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::singleton(3.),
+            Interval::new(Open(4.), Unbound),
+        ]);
+        assert_eq!(
+            a.boundary(),
+            IntervalSet::from(&[0., 1., 2., 3., 4.].map(Interval::singleton))
+        );
+        assert_eq!(a.boundary().boundary(), a.boundary());
+        assert!(IntervalSet::new().boundary().is_empty());
+        assert!(IntervalSet::from(&[INFINITY]).boundary().is_empty());
+    }
+
+    #[test]
+    fn test_map_affine_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::singleton(1.),
+            Interval::new(Open(2.), Closed(3.)),
+        ]);
+        assert_eq!(a.map_affine(1., 0.), a);
+        assert_eq!(
+            a.map_affine(-2., 1.),
+            IntervalSet::from(&[
+                Interval::new(Closed(-5.), Open(-3.)),
+                Interval::singleton(-1.),
+                Interval::new(Open(1.), Unbound),
+            ])
+        );
+        assert_eq!(a.map_affine(-1., 0.).map_affine(-1., 0.), a);
+        assert_eq!(
+            a.map_affine(0., 5.),
+            IntervalSet::from(&[Interval::singleton(5.)])
+        );
+        assert!(IntervalSet::new().map_affine(0., 5.).is_empty());
+    }
+
+    #[test]
+    fn test_map_affine_2() {
+        // Gap between components vanishes by rounding to subnormals
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(1.2), Closed(2.)),
+        ]);
+        let tiny = f64::from_bits(1);
+        let b = a.map_affine(tiny, 0.);
+        assert_eq!(b.len(), 1);
+        assert_eq!(b.get(0), Interval::new(Closed(0.), Closed(2. * tiny)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_map_affine_3() {
+        IntervalSet::new().map_affine(f64::NAN, 0.);
+    }
+
+    #[test]
+    fn test_remove_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(3.), Closed(4.)),
+            Interval::new(Open(5.), Unbound),
+        ]);
+        for interval in [
+            EMPTY,
+            INFINITY,
+            Interval::new(Open(2.), Open(3.)),
+            Interval::new(Closed(0.5), Closed(3.5)),
+            Interval::new(Open(-1.), Closed(1.)),
+            Interval::new(Closed(4.), Unbound),
+            Interval::new(Unbound, Closed(10.)),
+            Interval::singleton(1.5),
+            Interval::singleton(6.),
+        ] {
+            let mut b = a.clone();
+            b.remove(&interval);
+            assert_eq!(b, a.difference(&IntervalSet::from(&[interval])));
+        }
+    }
+
+    #[test]
+    fn test_remove_point_1() {
+        let mut a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Open(1.), Closed(2.)),
+        ]);
+        a.remove_point(1.);
+        assert_eq!(a.len(), 2);
+        a.remove_point(0.);
+        a.remove_point(2.);
+        assert_eq!(
+            a,
+            IntervalSet::from(&[
+                Interval::new(Open(0.), Open(1.)),
+                Interval::new(Open(1.), Open(2.)),
+            ])
+        );
+        a.remove_point(f64::NAN);
+        assert_eq!(a.len(), 2);
+    }
+
+    #[test]
+    fn test_union_all_2() {
+        // Components starting at the same point, open one first
+        let pieces = [
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::singleton(1.),
+            Interval::new(Closed(0.), Open(1.)),
+        ];
+        let expected = IntervalSet::from(&[Interval::new(Closed(0.), Closed(2.))]);
+        assert_eq!(IntervalSet::union_all(pieces), expected);
+        let a = IntervalSet::from(&[pieces[0], pieces[2]]);
+        assert_eq!(a.len(), 2);
+        assert_eq!(
+            a.union_intervals(&IntervalSet::from(&[pieces[1]])),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_from_pairs_1() {
+        let pairs = [
+            (3., 4.),
+            (0., 1.),
+            (1., 2.),
+            (5., 5.),
+            (7., 6.),
+            (f64::NAN, 8.),
+        ];
+        assert_eq!(
+            IntervalSet::from_pairs(&pairs, BoundPolicy::Closed),
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Closed(2.)),
+                Interval::new(Closed(3.), Closed(4.)),
+                Interval::singleton(5.),
+            ])
+        );
+        assert_eq!(
+            IntervalSet::from_pairs(&pairs, BoundPolicy::OpenClosed),
+            IntervalSet::from(&[
+                Interval::new(Open(0.), Closed(2.)),
+                Interval::new(Open(3.), Closed(4.)),
+            ])
+        );
+        let open = IntervalSet::from_pairs(&pairs, BoundPolicy::Open);
+        assert_eq!(open.len(), 3);
+        assert!(!open.contains(1.));
+        assert!(IntervalSet::from_pairs(&[], BoundPolicy::ClosedOpen).is_empty());
+    }
+
+    #[test]
+    fn test_toggle_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(3.), Closed(4.)),
+            Interval::new(Open(5.), Unbound),
+        ]);
+        for interval in [
+            EMPTY,
+            INFINITY,
+            Interval::new(Open(2.), Open(3.)),
+            Interval::new(Closed(0.5), Closed(3.5)),
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(4.), Unbound),
+            Interval::singleton(1.5),
+            Interval::singleton(5.),
+            Interval::new(Open(4.2), Open(4.8)),
+        ] {
+            let mut b = a.clone();
+            b.toggle(&interval);
+            let other = IntervalSet::from(&[interval]);
+            let expected = a.difference(&other).union_intervals(&other.difference(&a));
+            assert_eq!(b, expected);
+            b.toggle(&interval);
+            assert_eq!(b, a);
+        }
+    }
+
+    #[test]
+    fn test_measure_1() {
+        assert_eq!(IntervalSet::new().measure(), 0.);
+        assert_eq!((IntervalSet::new() | INFINITY).measure(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_jaccard_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(2.)),
+            Interval::new(Closed(4.), Closed(6.)),
+        ]);
+        let b = IntervalSet::from(&[
+            Interval::new(Closed(1.), Open(5.)),
+            Interval::new(Closed(7.), Closed(8.)),
+        ]);
+        assert_eq!(a.jaccard(&b), 2. / 7.);
+        assert_eq!(b.jaccard(&a), 2. / 7.);
+        assert_eq!(a.jaccard(&a), 1.);
+    }
+
+    #[test]
+    fn test_jaccard_2() {
+        let a = IntervalSet::new();
+        let b = IntervalSet::from(&[Interval::new(Closed(0.), Closed(2.))]);
+        assert_eq!(a.jaccard(&a), 1.);
+        assert_eq!(a.jaccard(&b), 0.);
+    }
+
+    #[test]
+    fn test_inflate_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(3.), Closed(4.)),
+        ]);
+        assert_eq!(
+            a.inflate(0.5),
+            IntervalSet::from(&[
+                Interval::new(Closed(-0.5), Open(1.5)),
+                Interval::new(Closed(2.5), Closed(4.5)),
+            ])
+        );
+        assert!(IntervalSet::new().inflate(1.).is_empty());
+    }
+
+    #[test]
+    fn test_deflate_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(3.), Closed(6.)),
+        ]);
+        assert_eq!(
+            a.deflate(1.),
+            IntervalSet::from(&[Interval::new(Closed(4.), Closed(5.))])
+        );
+    }
 }
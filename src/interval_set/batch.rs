@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+
+use super::IntervalSet;
+
+/// Up to this many components, points are tested against every component without branching
+const LINEAR_SCAN_MAX: usize = 8;
+
+impl IntervalSet {
+    /// Check membership of many points at once (see `IntervalSet::contains`)
+    ///
+    /// Sets with few components test every point against every component with branch-free
+    /// comparisons (see `Interval::contains_batch`); larger sets locate each point by binary
+    /// search.
+    ///
+    /// # Panics
+    ///
+    /// If `xs` and `out` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Closed(3.)),
+    /// ]);
+    /// let xs = [0., 1., 2.5, 4.];
+    /// let mut out = [false; 4];
+    /// a.contains_batch(&xs, &mut out);
+    ///
+    /// assert_eq!(out, [true, false, true, false]);
+    /// ```
+    ///
+    pub fn contains_batch(&self, xs: &[f64], out: &mut [bool]) {
+        assert_eq!(xs.len(), out.len(), "xs and out must have the same length");
+        if self.union.len() > LINEAR_SCAN_MAX {
+            for (res, x) in out.iter_mut().zip(xs) {
+                *res = self.contains(*x);
+            }
+            return;
+        }
+        out.fill(false);
+        for segment in self.union.iter() {
+            let (lo, hi) = segment.thresholds();
+            for (res, x) in out.iter_mut().zip(xs) {
+                *res |= (lo <= *x) & (*x <= hi);
+            }
+        }
+    }
+
+    /// Membership of every point of `xs` (see `IntervalSet::contains`)
+    ///
+    /// Sorted points are classified in a single sweep along components, other points by binary
+    /// search (see `IntervalSet::locate_points`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Closed(3.)),
+    /// ]);
+    ///
+    /// assert_eq!(a.classify_points(&[0., 1., 2.5, 4.]), vec![true, false, true, false]);
+    /// ```
+    ///
+    pub fn classify_points(&self, xs: &[f64]) -> Vec<bool> {
+        self.locate_points(xs)
+            .into_iter()
+            .map(|i| i.is_some())
+            .collect()
+    }
+
+    /// Index of the component containing each point of `xs`, `None` for points outside of set
+    ///
+    /// When `xs` is sorted, points and components are swept together in `O(n + m)` time for
+    /// `n` points and `m` components. Otherwise each point is located by binary search (see
+    /// `IntervalSet::component_of`), in `O(n log m)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Closed(3.)),
+    /// ]);
+    ///
+    /// assert_eq!(a.locate_points(&[0., 1., 2.5]), vec![Some(0), None, Some(1)]);
+    /// ```
+    ///
+    pub fn locate_points(&self, xs: &[f64]) -> Vec<Option<usize>> {
+        if !xs.windows(2).all(|pair| pair[0] <= pair[1]) {
+            return xs.iter().map(|x| self.component_of(*x)).collect();
+        }
+        let mut i = 0;
+        xs.iter()
+            .map(|x| {
+                while i < self.union.len() && self.union.ends_before(i, *x) {
+                    i += 1;
+                }
+                (i < self.union.len() && self.union.get(i).contains(*x)).then_some(i)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Interval, Open, Unbound};
+
+    #[test]
+    fn test_contains_batch_1() {
+        let xs: Vec<f64> = (-10..60).map(|i| i as f64 / 2.).collect();
+        for n in [0, 1, 3, 20] {
+            let a = IntervalSet::union_all(
+                (0..n).map(|i| Interval::new(Open(i as f64), Closed(i as f64 + 0.5))),
+            );
+            let mut out = vec![true; xs.len()];
+            a.contains_batch(&xs, &mut out);
+            let expected: Vec<bool> = xs.iter().map(|x| a.contains(*x)).collect();
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn test_locate_points_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::singleton(1.),
+            Interval::new(Open(2.), Closed(3.)),
+        ]);
+        let sorted = [f64::NEG_INFINITY, -1., 0., 1., 1.5, 2., 2.5, 3., 3.5];
+        let expected = [
+            Some(0),
+            Some(0),
+            None,
+            Some(1),
+            None,
+            None,
+            Some(2),
+            Some(2),
+            None,
+        ];
+        assert_eq!(a.locate_points(&sorted), expected);
+        let shuffled = [3., f64::NAN, -1., 1., 2.];
+        assert_eq!(
+            a.locate_points(&shuffled),
+            vec![Some(2), None, Some(0), Some(1), None]
+        );
+        assert_eq!(
+            a.classify_points(&shuffled),
+            vec![true, false, true, true, false]
+        );
+        assert!(IntervalSet::new()
+            .locate_points(&sorted)
+            .iter()
+            .all(Option::is_none));
+    }
+}
@@ -0,0 +1,356 @@
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::storage::{join, BoundKind};
+use super::IntervalSet;
+use crate::Interval;
+
+/// Magic bytes opening the binary layout
+const MAGIC: &[u8; 4] = b"IVLS";
+/// Version of the binary layout
+const VERSION: u16 = 1;
+/// Size of the header: magic, version, reserved `u16`, number of components as `u64`
+const HEADER_LEN: usize = 16;
+
+#[cfg(feature = "std")]
+fn kind_to_byte(kind: BoundKind) -> u8 {
+    match kind {
+        BoundKind::Open => 0,
+        BoundKind::Closed => 1,
+        BoundKind::Unbound => 2,
+    }
+}
+
+fn byte_to_kind(byte: u8) -> Option<BoundKind> {
+    match byte {
+        0 => Some(BoundKind::Open),
+        1 => Some(BoundKind::Closed),
+        2 => Some(BoundKind::Unbound),
+        _ => None,
+    }
+}
+
+/// Error returned when bytes do not hold a valid interval set layout
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutError {
+    reason: &'static str,
+}
+
+impl LayoutError {
+    fn new(reason: &'static str) -> Self {
+        LayoutError { reason }
+    }
+}
+
+impl Display for LayoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid interval set layout: {}", self.reason)
+    }
+}
+
+impl core::error::Error for LayoutError {}
+
+impl IntervalSet {
+    /// Write set in a versioned binary layout, readable in place by `IntervalSetRef`
+    ///
+    /// Layout (all numbers little-endian):
+    ///
+    /// - header: magic `IVLS`, version `u16`, reserved `u16`, number `n` of components `u64`
+    /// - `2n` endpoint values as `f64`, alternating left and right endpoints, sorted
+    /// - `2n` endpoint kinds as `u8`: `0` open, `1` closed, `2` unbound
+    ///
+    #[cfg(feature = "std")]
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&0u16.to_le_bytes())?;
+        writer.write_all(&(self.union.len() as u64).to_le_bytes())?;
+        for (k, _) in self.union.ends() {
+            writer.write_all(&k.to_le_bytes())?;
+        }
+        let kinds: Vec<u8> = self
+            .union
+            .ends()
+            .map(|(_, kind)| kind_to_byte(kind))
+            .collect();
+        writer.write_all(&kinds)
+    }
+}
+
+/// Read-only view of an interval set over bytes written by `IntervalSet::write_to`
+///
+/// Components are decoded on access, without copying the bytes, so that the view can be built
+/// over a memory-mapped file.
+///
+/// # Example
+///
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use interval::{Interval, IntervalSet, IntervalSetRef, Closed, Open};
+///
+/// let a = IntervalSet::from(&[
+///     Interval::new(Closed(0.), Open(1.)),
+///     Interval::new(Closed(2.), Closed(3.)),
+/// ]);
+/// let mut bytes = Vec::new();
+/// a.write_to(&mut bytes).unwrap();
+///
+/// let view = IntervalSetRef::from_bytes(&bytes).unwrap();
+/// assert!(view.contains(2.5));
+/// assert!(!view.contains(1.));
+/// assert_eq!(
+///     view.intersection(&Interval::new(Closed(0.5), Closed(2.))),
+///     IntervalSet::from(&[
+///         Interval::new(Closed(0.5), Open(1.)),
+///         Interval::singleton(2.),
+///     ])
+/// );
+/// assert_eq!(view.to_interval_set(), a);
+/// ```
+///
+#[derive(Debug, Clone, Copy)]
+pub struct IntervalSetRef<'a> {
+    values: &'a [u8],
+    kinds: &'a [u8],
+}
+
+impl<'a> IntervalSetRef<'a> {
+    /// Check bytes, and build a view over them
+    ///
+    /// Besides header and size, every component is checked in `O(n)`: endpoints must be of a
+    /// known kind and not `NaN`, and components must be non-empty, sorted, and separated by gaps
+    /// (as in any `IntervalSet`).
+    ///
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, LayoutError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(LayoutError::new("truncated header"));
+        }
+        if &bytes[..4] != MAGIC {
+            return Err(LayoutError::new("bad magic"));
+        }
+        if u16::from_le_bytes([bytes[4], bytes[5]]) != VERSION {
+            return Err(LayoutError::new("unsupported version"));
+        }
+        let n = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let ends = usize::try_from(n)
+            .ok()
+            .and_then(|n| n.checked_mul(2))
+            .ok_or(LayoutError::new("too many components"))?;
+        if Some(bytes.len()) != ends.checked_mul(9).map(|len| HEADER_LEN + len) {
+            return Err(LayoutError::new("size does not match number of components"));
+        }
+        let (values, kinds) = bytes[HEADER_LEN..].split_at(8 * ends);
+        if kinds.iter().any(|byte| byte_to_kind(*byte).is_none()) {
+            return Err(LayoutError::new("unknown endpoint kind"));
+        }
+        let view = IntervalSetRef { values, kinds };
+        view.check_components()?;
+        Ok(view)
+    }
+
+    /// Check that components are those of a valid set
+    fn check_components(&self) -> Result<(), LayoutError> {
+        if (0..2 * self.len()).any(|i| self.end(i).0.is_nan()) {
+            return Err(LayoutError::new("NaN endpoint"));
+        }
+        let mut previous: Option<Interval> = None;
+        for segment in self.iter() {
+            if segment.is_empty() {
+                return Err(LayoutError::new("empty component"));
+            }
+            if let Some(previous) = previous {
+                if !matches!(previous.union(segment), (lower, Some(_)) if lower == previous) {
+                    return Err(LayoutError::new("components not sorted or not disjoint"));
+                }
+            }
+            previous = Some(segment);
+        }
+        Ok(())
+    }
+
+    /// Number of components
+    ///
+    pub fn len(&self) -> usize {
+        self.kinds.len() / 2
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    fn end(&self, i: usize) -> (f64, BoundKind) {
+        let value = f64::from_le_bytes(self.values[8 * i..8 * i + 8].try_into().unwrap());
+        (value, byte_to_kind(self.kinds[i]).unwrap())
+    }
+
+    /// Component of index `i`
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    ///
+    pub fn get(&self, i: usize) -> Interval {
+        Interval::new(join(self.end(2 * i)), join(self.end(2 * i + 1)))
+    }
+
+    /// Iterate over components, in increasing order
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        (0..self.len()).map(|i| self.get(i))
+    }
+
+    /// Index of the first component not entirely before `query`
+    fn lower_index(&self, query: &Interval) -> usize {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.get(mid).certainly_lt(query) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Check if `x` belongs to set, in `O(log n)`
+    ///
+    pub fn contains(&self, x: f64) -> bool {
+        if x.is_nan() {
+            return false;
+        }
+        let i = self.lower_index(&Interval::singleton(x));
+        i < self.len() && self.get(i).contains(x)
+    }
+
+    /// Intersection of set with an interval, decoding only the overlapping components
+    ///
+    pub fn intersection(&self, query: &Interval) -> IntervalSet {
+        let mut res = IntervalSet::new();
+        if query.is_empty() {
+            return res;
+        }
+        for i in self.lower_index(query)..self.len() {
+            let segment = self.get(i);
+            if query.certainly_lt(&segment) {
+                break;
+            }
+            let overlap = segment.intersection(*query);
+            if !overlap.is_empty() {
+                res.union.push(overlap);
+            }
+        }
+        res
+    }
+
+    /// Copy components into an owned set
+    ///
+    pub fn to_interval_set(&self) -> IntervalSet {
+        IntervalSet {
+            union: self.iter().collect(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, INFINITY};
+
+    fn bytes(set: &IntervalSet) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        set.write_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_round_trip_1() {
+        let sets = [
+            IntervalSet::new(),
+            IntervalSet::from(&[INFINITY]),
+            IntervalSet::from(&[
+                Interval::new(Unbound, Open(-1.)),
+                Interval::singleton(0.),
+                Interval::new(Open(1.), Unbound),
+            ]),
+        ];
+        for set in sets {
+            let bytes = bytes(&set);
+            assert_eq!(bytes.len(), HEADER_LEN + 18 * set.union.len());
+            let view = IntervalSetRef::from_bytes(&bytes).unwrap();
+            assert_eq!(view.len(), set.union.len());
+            assert_eq!(view.to_interval_set(), set);
+        }
+    }
+
+    #[test]
+    fn test_contains_1() {
+        let set = IntervalSet::from(&[
+            Interval::new(Unbound, Open(-1.)),
+            Interval::singleton(0.),
+            Interval::new(Open(1.), Closed(2.)),
+        ]);
+        let bytes = bytes(&set);
+        let view = IntervalSetRef::from_bytes(&bytes).unwrap();
+        for x in [-1e300, -1., 0., 0.5, 1., 1.5, 2., 3., f64::NAN] {
+            assert_eq!(view.contains(x), set.contains(x));
+        }
+        let query = Interval::new(Closed(-2.), Closed(1.5));
+        assert_eq!(
+            view.intersection(&query),
+            set.intersection(&IntervalSet::from(&[query]))
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_1() {
+        let good = bytes(&IntervalSet::from(&[Interval::singleton(0.)]));
+        assert!(IntervalSetRef::from_bytes(&good[..10]).is_err());
+        assert!(IntervalSetRef::from_bytes(&good[..good.len() - 1]).is_err());
+        let mut bad = good.clone();
+        bad[0] = b'X';
+        assert_eq!(
+            IntervalSetRef::from_bytes(&bad).unwrap_err(),
+            LayoutError::new("bad magic")
+        );
+        let mut bad = good.clone();
+        bad[4] = 2;
+        assert!(IntervalSetRef::from_bytes(&bad).is_err());
+        let mut bad = good.clone();
+        *bad.last_mut().unwrap() = 7;
+        assert!(IntervalSetRef::from_bytes(&bad).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_2() {
+        // Corrupt endpoint values are rejected
+        let good = bytes(&IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(2.), Closed(3.)),
+        ]));
+        assert!(IntervalSetRef::from_bytes(&good).is_ok());
+        let corrupt = |values: [f64; 4]| {
+            let mut bad = good.clone();
+            for (i, k) in values.iter().enumerate() {
+                let at = HEADER_LEN + 8 * i;
+                bad[at..at + 8].copy_from_slice(&k.to_le_bytes());
+            }
+            IntervalSetRef::from_bytes(&bad).map(|_| ()).unwrap_err()
+        };
+        assert_eq!(
+            corrupt([0., f64::NAN, 2., 3.]),
+            LayoutError::new("NaN endpoint")
+        );
+        assert_eq!(
+            corrupt([1., 0., 2., 3.]),
+            LayoutError::new("empty component")
+        );
+        let unordered = LayoutError::new("components not sorted or not disjoint");
+        assert_eq!(corrupt([2., 3., 0., 1.]), unordered);
+        assert_eq!(corrupt([0., 2.5, 2., 3.]), unordered);
+        // [0,1) and [1,3] would merge into a single component
+        assert_eq!(corrupt([0., 1., 1., 3.]), unordered);
+    }
+}
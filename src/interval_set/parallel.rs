@@ -0,0 +1,96 @@
+use rayon::prelude::*;
+
+use super::{IntervalSet, SetOp};
+use crate::Interval;
+
+/// Number of intervals handled by a single sequential sweep
+const CHUNK_SIZE: usize = 4096;
+
+impl IntervalSet {
+    /// Parallel version of `IntervalSet::union_all`
+    ///
+    /// Intervals are split in chunks, each one normalized by a local sweep on its own thread,
+    /// then partial sets are merged pairwise (see `IntervalSet::merge_with`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let intervals: Vec<Interval> = (0..100_000)
+    ///     .map(|i| Interval::new(Closed(i as f64), Open(i as f64 + 1.)))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     IntervalSet::par_union_all(&intervals),
+    ///     IntervalSet::from(&[Interval::new(Closed(0.), Open(100_000.))])
+    /// );
+    /// ```
+    ///
+    pub fn par_union_all(intervals: &[Interval]) -> Self {
+        intervals
+            .par_chunks(CHUNK_SIZE)
+            .map(|chunk| IntervalSet::union_all(chunk.iter().copied()))
+            .reduce(IntervalSet::new, |a, b| a.merge_with(&b, SetOp::Union))
+    }
+
+    /// Union of any number of sets, merged pairwise in parallel
+    ///
+    pub fn par_union_sets(sets: &[IntervalSet]) -> Self {
+        sets.par_iter()
+            .cloned()
+            .reduce(IntervalSet::new, |a, b| a.merge_with(&b, SetOp::Union))
+    }
+
+    /// Check membership of many points in parallel (see `IntervalSet::contains_batch`)
+    ///
+    /// # Returns
+    ///
+    /// Membership of each point of `xs`, in the same order.
+    ///
+    pub fn par_contains_all(&self, xs: &[f64]) -> Vec<bool> {
+        let mut out = vec![false; xs.len()];
+        out.par_chunks_mut(CHUNK_SIZE)
+            .zip(xs.par_chunks(CHUNK_SIZE))
+            .for_each(|(out, xs)| self.contains_batch(xs, out));
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open};
+
+    #[test]
+    fn test_par_union_all_1() {
+        let intervals: Vec<Interval> = (0..20_000)
+            .rev()
+            .map(|i| Interval::new(Closed(3. * i as f64), Open(3. * i as f64 + 2.)))
+            .collect();
+        assert!(IntervalSet::par_union_all(&[]).is_empty());
+        assert_eq!(
+            IntervalSet::par_union_all(&intervals),
+            IntervalSet::union_all(intervals.iter().copied())
+        );
+    }
+
+    #[test]
+    fn test_par_union_sets_1() {
+        let sets: Vec<IntervalSet> = (0..100)
+            .map(|i| IntervalSet::from(&[Interval::new(Closed(i as f64), Closed(i as f64 + 1.))]))
+            .collect();
+        assert_eq!(
+            IntervalSet::par_union_sets(&sets),
+            IntervalSet::from(&[Interval::new(Closed(0.), Closed(100.))])
+        );
+    }
+
+    #[test]
+    fn test_par_contains_all_1() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Open(1.))]);
+        let xs: Vec<f64> = (0..10_000).map(|i| i as f64 / 5_000. - 0.5).collect();
+        let expected: Vec<bool> = xs.iter().map(|x| a.contains(*x)).collect();
+        assert_eq!(a.par_contains_all(&xs), expected);
+    }
+}
@@ -0,0 +1,316 @@
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use alloc::vec::Vec;
+
+use super::{Interval, IntervalSet};
+
+impl IntervalSet {
+    /// Draw a point uniformly distributed over the total measure of set
+    ///
+    /// A component is picked with a probability proportional to its width, then a point is drawn
+    /// uniformly inside it. A set made of isolated points only (ie. of measure zero) picks one of
+    /// them uniformly.
+    ///
+    /// # Returns
+    ///
+    /// `None` if set is empty or unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Open, Closed};
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Open(10.), Closed(13.)),
+    /// ]);
+    ///
+    /// let x = a.sample_uniform(&mut rng).unwrap();
+    /// assert!(a.contains(x));
+    /// ```
+    ///
+    pub fn sample_uniform<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<f64> {
+        let total = self.measure();
+        if self.is_empty() || !total.is_finite() {
+            return None;
+        }
+        if total == 0. {
+            let i = rng.gen_range(0..self.union.len());
+            return self.union.get(i).sample_uniform(rng);
+        }
+        self.pick(|segment| segment.width(), rng)?
+            .sample_uniform(rng)
+    }
+
+    /// Draw a point of set following the distribution of cumulative distribution function
+    /// `cdf`, restricted to set, by inversion
+    ///
+    /// A component is picked with a probability proportional to its probability under `cdf`,
+    /// then a point is drawn inside it (see `Interval::sample_inverse_cdf`).
+    ///
+    /// # Returns
+    ///
+    /// `None` if set has no positive probability under `cdf`, or if `quantile` returns `NaN`.
+    ///
+    pub fn sample_inverse_cdf<F, Q, R>(&self, cdf: F, quantile: Q, rng: &mut R) -> Option<f64>
+    where
+        F: Fn(f64) -> f64,
+        Q: Fn(f64) -> f64,
+        R: Rng + ?Sized,
+    {
+        let component = self.pick(
+            |segment| match (segment.inf(), segment.sup()) {
+                (Some(a), Some(b)) => cdf(b) - cdf(a),
+                _ => 0.,
+            },
+            rng,
+        )?;
+        component.sample_inverse_cdf(cdf, quantile, rng)
+    }
+
+    /// Draw a point of bounded set following probability density `density`, restricted to set,
+    /// by rejection
+    ///
+    /// Points are drawn uniformly over set (see `IntervalSet::sample_uniform`) and accepted with
+    /// probability `density(x) / max_density`, as in `Interval::sample_rejection`.
+    ///
+    /// # Returns
+    ///
+    /// `None` if set is empty or unbounded, or if `max_density` is not positive and finite.
+    ///
+    pub fn sample_rejection<F, R>(&self, density: F, max_density: f64, rng: &mut R) -> Option<f64>
+    where
+        F: Fn(f64) -> f64,
+        R: Rng + ?Sized,
+    {
+        if !max_density.is_finite() || max_density <= 0. {
+            return None;
+        }
+        loop {
+            let x = self.sample_uniform(rng)?;
+            if rng.gen::<f64>() * max_density < density(x) {
+                return Some(x);
+            }
+        }
+    }
+
+    /// Draw a point of set following the normal distribution of given mean and standard
+    /// deviation, restricted to set
+    ///
+    /// A component is picked with a probability proportional to its normal probability, then a
+    /// point is drawn inside it (see `Interval::sample_truncated_normal`). Isolated points have
+    /// no probability and are never drawn.
+    ///
+    /// # Returns
+    ///
+    /// `None` if set has measure zero, if `mean` is not finite or if `std` is not positive and
+    /// finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Unbound};
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// // Far tails on both sides of the mean
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Unbound, Closed(-20.)),
+    ///     Interval::new(Closed(20.), Unbound),
+    /// ]);
+    ///
+    /// let x = a.sample_truncated_normal(0., 1., &mut rng).unwrap();
+    /// assert!(a.contains(x));
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    pub fn sample_truncated_normal<R: Rng + ?Sized>(
+        &self,
+        mean: f64,
+        std: f64,
+        rng: &mut R,
+    ) -> Option<f64> {
+        if !mean.is_finite() || !std.is_finite() || std <= 0. {
+            return None;
+        }
+        let ln_mass = |segment: &Interval| match segment.width() > 0. {
+            true => segment.ln_normal_mass(mean, std),
+            false => f64::NEG_INFINITY,
+        };
+        // rescale in logarithmic space: far tails all have probabilities underflowing to zero
+        let max = self
+            .union
+            .iter()
+            .map(|s| ln_mass(&s))
+            .fold(f64::NEG_INFINITY, f64::max);
+        if max == f64::NEG_INFINITY {
+            return None;
+        }
+        let component = self.pick(|segment| (ln_mass(segment) - max).exp(), rng)?;
+        component.sample_truncated_normal(mean, std, rng)
+    }
+
+    /// Pick a component with a probability proportional to `weight`
+    ///
+    /// # Returns
+    ///
+    /// `None` if no weight is positive.
+    ///
+    fn pick<F, R>(&self, weight: F, rng: &mut R) -> Option<Interval>
+    where
+        F: Fn(&Interval) -> f64,
+        R: Rng + ?Sized,
+    {
+        let weights: Vec<(Interval, f64)> = self
+            .union
+            .iter()
+            .map(|segment| (segment, weight(&segment)))
+            .filter(|(_, w)| *w > 0. && w.is_finite())
+            .collect();
+        let total: f64 = weights.iter().map(|(_, w)| w).sum();
+        if weights.is_empty() || !total.is_finite() {
+            return None;
+        }
+
+        let mut target = rng.gen::<f64>() * total;
+        let mut chosen = None;
+        for (segment, w) in weights {
+            chosen = Some(segment);
+            if target < w {
+                break;
+            }
+            target -= w;
+        }
+        chosen
+    }
+}
+
+/// Uniform distribution over the total measure of a bounded set
+///
+/// # Panics
+///
+/// Sampling panics if set is empty or unbounded (see `IntervalSet::sample_uniform` for a
+/// non-panicking version).
+///
+impl Distribution<f64> for IntervalSet {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        match self.sample_uniform(rng) {
+            Some(x) => x,
+            None => panic!("can not sample uniformly from {self}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Interval, Open, Unbound};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_sample_uniform_1() {
+        let rng = StdRng::seed_from_u64(0);
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::singleton(5.),
+            Interval::new(Open(10.), Closed(13.)),
+        ]);
+        let samples: Vec<f64> = (&a).sample_iter(rng).take(10000).collect();
+        let low = samples.iter().filter(|x| **x < 1.).count();
+
+        assert!(samples.iter().all(|x| a.contains(*x)));
+        assert!(!samples.contains(&5.));
+        assert!((2000..3000).contains(&low));
+    }
+
+    #[test]
+    fn test_sample_uniform_2() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = IntervalSet::from(&[Interval::singleton(1.), Interval::singleton(5.)]);
+        for _ in 0..100 {
+            assert!(a.contains(a.sample_uniform(&mut rng).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_sample_uniform_3() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Unbound)]);
+        assert_eq!(IntervalSet::new().sample_uniform(&mut rng), None);
+        assert_eq!(a.sample_uniform(&mut rng), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sample_truncated_normal_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(-1.), Closed(0.)),
+            Interval::singleton(3.),
+            Interval::new(Open(10.), Open(11.)),
+        ]);
+        for _ in 0..1000 {
+            let x = a.sample_truncated_normal(0., 1., &mut rng).unwrap();
+            assert!((-1. ..=0.).contains(&x));
+        }
+        let b = IntervalSet::from(&[Interval::singleton(3.)]);
+        assert_eq!(b.sample_truncated_normal(0., 1., &mut rng), None);
+        assert_eq!(a.sample_truncated_normal(0., 0., &mut rng), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sample_truncated_normal_2() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Closed(-40.)),
+            Interval::new(Closed(40.), Unbound),
+        ]);
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| a.sample_truncated_normal(0., 1., &mut rng).unwrap())
+            .collect();
+        let low = samples.iter().filter(|x| **x < 0.).count();
+        assert!(samples.iter().all(|x| a.contains(*x) && x.abs() < 41.));
+        assert!((4500..5500).contains(&low));
+    }
+
+    #[test]
+    fn test_sample_inverse_cdf_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        // Uniform distribution over [0, 4]
+        let cdf = |x: f64| (x / 4.).clamp(0., 1.);
+        let quantile = |p: f64| 4. * p;
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(1.)),
+            Interval::new(Closed(3.), Closed(5.)),
+            Interval::new(Open(6.), Unbound),
+        ]);
+        for _ in 0..1000 {
+            let x = a.sample_inverse_cdf(cdf, quantile, &mut rng).unwrap();
+            assert!((0. ..1.).contains(&x) || (3. ..=4.).contains(&x));
+        }
+        let b = IntervalSet::from(&[Interval::new(Closed(6.), Closed(7.))]);
+        assert_eq!(b.sample_inverse_cdf(cdf, quantile, &mut rng), None);
+    }
+
+    #[test]
+    fn test_sample_rejection_1() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(2.), Closed(3.)),
+        ]);
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| a.sample_rejection(|x| x, 3., &mut rng).unwrap())
+            .collect();
+        let low = samples.iter().filter(|x| **x < 1.5).count();
+        assert!(samples.iter().all(|x| a.contains(*x)));
+        // weights 1/2 and 5/2
+        assert!((1400..1900).contains(&low));
+        assert_eq!(a.sample_rejection(|x| x, f64::NAN, &mut rng), None);
+    }
+}
@@ -0,0 +1,158 @@
+use super::IntervalSet;
+use crate::{Closed, Interval, Open};
+
+impl IntervalSet {
+    /// Covered regions and gaps of a stream of sorted samples (eg. timestamps)
+    ///
+    /// Consecutive samples at most `max_gap` apart belong to the same covered closed interval,
+    /// spanning from its first to its last sample. Samples further apart delimit an open gap.
+    /// `NaN` samples are ignored. Gaps only lie between the first and the last sample.
+    ///
+    /// # Returns
+    ///
+    /// `(covered, gaps)`, two disjoint sets whose union is the hull of samples.
+    ///
+    /// # Panics
+    ///
+    /// If samples are not sorted in increasing order, or if `max_gap` is negative or `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let timestamps = [0., 1., 2., 10., 11., 20.];
+    /// let (covered, gaps) = IntervalSet::from_samples(timestamps, 1.5);
+    ///
+    /// assert_eq!(
+    ///     covered,
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Closed(0.), Closed(2.)),
+    ///         Interval::new(Closed(10.), Closed(11.)),
+    ///         Interval::singleton(20.),
+    ///     ])
+    /// );
+    /// assert_eq!(
+    ///     gaps,
+    ///     IntervalSet::from(&[
+    ///         Interval::new(Open(2.), Open(10.)),
+    ///         Interval::new(Open(11.), Open(20.)),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    pub fn from_samples(
+        samples: impl IntoIterator<Item = f64>,
+        max_gap: f64,
+    ) -> (IntervalSet, IntervalSet) {
+        assert!(max_gap >= 0., "max_gap must be non-negative");
+        let mut covered = IntervalSet::new();
+        let mut gaps = IntervalSet::new();
+        let mut current: Option<(f64, f64)> = None;
+
+        for x in samples.into_iter().filter(|x| !x.is_nan()) {
+            current = match current {
+                None => Some((x, x)),
+                Some((first, last)) => {
+                    assert!(x >= last, "samples must be sorted");
+                    // repeated infinite samples are 0 apart, though their difference is NaN
+                    if x == last || x - last <= max_gap {
+                        Some((first, x))
+                    } else {
+                        covered
+                            .union
+                            .push(Interval::new(Closed(first), Closed(last)));
+                        gaps.union.push(Interval::new(Open(last), Open(x)));
+                        Some((x, x))
+                    }
+                }
+            };
+        }
+        if let Some((first, last)) = current {
+            covered
+                .union
+                .push(Interval::new(Closed(first), Closed(last)));
+        }
+        (covered, gaps)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_samples_1() {
+        let (covered, gaps) = IntervalSet::from_samples(Vec::new(), 1.);
+        assert!(covered.is_empty() && gaps.is_empty());
+        let (covered, gaps) = IntervalSet::from_samples([f64::NAN, 3., f64::NAN], 1.);
+        assert_eq!(covered, IntervalSet::from(&[Interval::singleton(3.)]));
+        assert!(gaps.is_empty());
+    }
+
+    #[test]
+    fn test_from_samples_2() {
+        let (covered, gaps) = IntervalSet::from_samples([0., 1., 1., 2.5, 3.], 1.);
+        assert_eq!(
+            covered,
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Closed(1.)),
+                Interval::new(Closed(2.5), Closed(3.)),
+            ])
+        );
+        assert_eq!(
+            gaps,
+            IntervalSet::from(&[Interval::new(Open(1.), Open(2.5))])
+        );
+        assert_eq!(
+            &covered | &gaps,
+            IntervalSet::from(&[Interval::new(Closed(0.), Closed(3.))])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_samples_3() {
+        IntervalSet::from_samples([1., 0.], 1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_samples_4() {
+        IntervalSet::from_samples([1., 1.], -1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_samples_5() {
+        IntervalSet::from_samples([1., 1.], f64::NAN);
+    }
+
+    #[test]
+    fn test_from_samples_6() {
+        // Repeated infinite samples continue their run
+        let inf = f64::INFINITY;
+        let (covered, gaps) = IntervalSet::from_samples([1., inf, inf], 1.);
+        assert_eq!(
+            covered,
+            IntervalSet::from(&[Interval::singleton(1.), Interval::singleton(inf)])
+        );
+        assert_eq!(covered.iter().count(), 2);
+        assert_eq!(
+            gaps,
+            IntervalSet::from(&[Interval::new(Open(1.), Open(inf))])
+        );
+        let (covered, gaps) = IntervalSet::from_samples([-inf, -inf, 0., 0.5], 1.);
+        assert_eq!(
+            covered,
+            IntervalSet::from(&[
+                Interval::singleton(-inf),
+                Interval::new(Closed(0.), Closed(0.5)),
+            ])
+        );
+        assert_eq!(
+            gaps,
+            IntervalSet::from(&[Interval::new(Open(-inf), Open(0.))])
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::sync::Arc;
+use core::fmt::Display;
+use core::ops::Deref;
+
+use super::IntervalSet;
+
+/// Copy-on-write handle over an `IntervalSet`
+///
+/// Cloning a handle is cheap: clones share the same set until one of them is edited through
+/// `make_mut`, which clones the set only if it is still shared. Read-only access goes through
+/// `Deref`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, SharedIntervalSet, Closed};
+///
+/// let a = SharedIntervalSet::from(IntervalSet::from(&[Interval::new(Closed(0.), Closed(1.))]));
+/// let mut b = a.clone();
+/// assert!(a.ptr_eq(&b));
+///
+/// let edited = b.make_mut();
+/// *edited = edited.union_interval(&Interval::singleton(2.));
+///
+/// assert!(!a.ptr_eq(&b));
+/// assert!(!a.contains(2.));
+/// assert!(b.contains(2.));
+/// ```
+///
+#[derive(Debug, Default, Clone)]
+pub struct SharedIntervalSet {
+    set: Arc<IntervalSet>,
+}
+
+impl SharedIntervalSet {
+    pub fn new(set: IntervalSet) -> Self {
+        SharedIntervalSet { set: Arc::new(set) }
+    }
+
+    /// Mutable access to set, cloning it first if other handles share it
+    ///
+    pub fn make_mut(&mut self) -> &mut IntervalSet {
+        Arc::make_mut(&mut self.set)
+    }
+
+    /// Check if both handles share the same set
+    ///
+    pub fn ptr_eq(&self, other: &SharedIntervalSet) -> bool {
+        Arc::ptr_eq(&self.set, &other.set)
+    }
+
+    /// Unwrap set, cloning it if other handles share it
+    ///
+    pub fn into_inner(self) -> IntervalSet {
+        Arc::try_unwrap(self.set).unwrap_or_else(|set| (*set).clone())
+    }
+}
+
+impl Deref for SharedIntervalSet {
+    type Target = IntervalSet;
+
+    fn deref(&self) -> &IntervalSet {
+        &self.set
+    }
+}
+
+impl From<IntervalSet> for SharedIntervalSet {
+    fn from(set: IntervalSet) -> Self {
+        SharedIntervalSet::new(set)
+    }
+}
+
+impl PartialEq for SharedIntervalSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || *self.set == *other.set
+    }
+}
+
+impl Display for SharedIntervalSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.set.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Interval};
+
+    #[test]
+    fn test_make_mut_1() {
+        let mut a = SharedIntervalSet::default();
+        let before = a.make_mut() as *const IntervalSet;
+        let after = a.make_mut() as *const IntervalSet;
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn test_make_mut_2() {
+        let a = SharedIntervalSet::new(IntervalSet::from(&[Interval::singleton(0.)]));
+        let mut b = a.clone();
+        *b.make_mut() = IntervalSet::new();
+        assert!(b.is_empty());
+        assert_eq!(*a, IntervalSet::from(&[Interval::singleton(0.)]));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_into_inner_1() {
+        let set = IntervalSet::from(&[Interval::new(Closed(0.), Closed(1.))]);
+        let a = SharedIntervalSet::from(set.clone());
+        let b = a.clone();
+        assert_eq!(a.into_inner(), set);
+        assert_eq!(b.into_inner(), set);
+    }
+}
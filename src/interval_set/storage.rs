@@ -0,0 +1,290 @@
+use core::iter::FromIterator;
+use core::ops::Range;
+use smallvec::SmallVec;
+
+use crate::{Bound, Closed, Interval, Open, Unbound};
+
+/// Kind of an endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BoundKind {
+    Open,
+    Closed,
+    Unbound,
+}
+
+/// Split bound into value and kind, `Unbound` being stored as `infinity`
+pub(super) fn split(bound: Bound, infinity: f64) -> (f64, BoundKind) {
+    match bound {
+        Open(k) => (k, BoundKind::Open),
+        Closed(k) => (k, BoundKind::Closed),
+        Unbound => (infinity, BoundKind::Unbound),
+    }
+}
+
+pub(super) fn join((k, kind): (f64, BoundKind)) -> Bound {
+    match kind {
+        BoundKind::Open => Open(k),
+        BoundKind::Closed => Closed(k),
+        BoundKind::Unbound => Unbound,
+    }
+}
+
+/// Number of components stored inline, without allocation
+const INLINE_COMPONENTS: usize = 3;
+
+/// Number of endpoint kinds packed in a word, two bits each
+const KINDS_PER_WORD: usize = 32;
+
+fn kind_to_bits(kind: BoundKind) -> u64 {
+    match kind {
+        BoundKind::Open => 0,
+        BoundKind::Closed => 1,
+        BoundKind::Unbound => 2,
+    }
+}
+
+fn bits_to_kind(bits: u64) -> BoundKind {
+    match bits {
+        0 => BoundKind::Open,
+        1 => BoundKind::Closed,
+        _ => BoundKind::Unbound,
+    }
+}
+
+/// Components of a set, stored as a flat sorted array of alternating left and right endpoints
+///
+/// Component `i` spans from endpoint `2i` to endpoint `2i + 1`. Unbound endpoints hold `-∞` or
+/// `+∞`, so that values are sorted along the whole array. Endpoint kinds are packed two bits
+/// each in a separate bitset: a component takes a little over 16 bytes, half the size of an
+/// `Interval`. Sets of up to `INLINE_COMPONENTS` components are stored inline and do not
+/// allocate.
+///
+#[derive(Debug, Default, Clone)]
+pub(super) struct Endpoints {
+    values: SmallVec<[f64; 2 * INLINE_COMPONENTS]>,
+    /// Kind of endpoint `i` in bits `2 (i % 32)` and `2 (i % 32) + 1` of word `i / 32`
+    kinds: SmallVec<[u64; 1]>,
+}
+
+impl Endpoints {
+    pub fn new() -> Self {
+        Endpoints {
+            values: SmallVec::new(),
+            kinds: SmallVec::new(),
+        }
+    }
+
+    /// Number of components
+    pub fn len(&self) -> usize {
+        self.values.len() / 2
+    }
+
+    /// Value and kind of endpoint `i`
+    fn end(&self, i: usize) -> (f64, BoundKind) {
+        let bits = self.kinds[i / KINDS_PER_WORD] >> (2 * (i % KINDS_PER_WORD));
+        (self.values[i], bits_to_kind(bits & 0b11))
+    }
+
+    /// Overwrite endpoint `i`
+    fn set_end(&mut self, i: usize, (k, kind): (f64, BoundKind)) {
+        let shift = 2 * (i % KINDS_PER_WORD);
+        let word = &mut self.kinds[i / KINDS_PER_WORD];
+        *word = *word & !(0b11 << shift) | kind_to_bits(kind) << shift;
+        self.values[i] = k;
+    }
+
+    fn push_end(&mut self, (k, kind): (f64, BoundKind)) {
+        let i = self.values.len();
+        if i.is_multiple_of(KINDS_PER_WORD) {
+            self.kinds.push(0);
+        }
+        self.values.push(k);
+        self.set_end(i, (k, kind));
+    }
+
+    /// Keep the first `n` endpoints
+    fn truncate_ends(&mut self, n: usize) {
+        self.values.truncate(n);
+        self.kinds.truncate(n.div_ceil(KINDS_PER_WORD));
+        if !n.is_multiple_of(KINDS_PER_WORD) {
+            // clear kinds of dropped endpoints, as `push_end` expects zero bits
+            let mask = (1 << (2 * (n % KINDS_PER_WORD))) - 1;
+            if let Some(word) = self.kinds.last_mut() {
+                *word &= mask;
+            }
+        }
+    }
+
+    pub fn get(&self, i: usize) -> Interval {
+        Interval::new(join(self.end(2 * i)), join(self.end(2 * i + 1)))
+    }
+
+    pub fn last(&self) -> Option<Interval> {
+        self.len().checked_sub(1).map(|i| self.get(i))
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Interval> + ExactSizeIterator + '_ {
+        (0..self.len()).map(|i| self.get(i))
+    }
+
+    /// Endpoints in order, as values and kinds
+    #[cfg(feature = "std")]
+    pub fn ends(&self) -> impl Iterator<Item = (f64, BoundKind)> + '_ {
+        (0..self.values.len()).map(|i| self.end(i))
+    }
+
+    /// Append a component lying after every existing one
+    pub fn push(&mut self, segment: Interval) {
+        self.push_end(split(segment.left(), f64::NEG_INFINITY));
+        self.push_end(split(segment.right(), f64::INFINITY));
+    }
+
+    /// Append components of `other` from index `from`
+    pub fn extend_from(&mut self, other: &Endpoints, from: usize) {
+        for i in 2 * from..other.values.len() {
+            self.push_end(other.end(i));
+        }
+    }
+
+    /// Replace components of indices in `range` by `segments`, lying between their neighbours
+    pub fn splice(&mut self, range: Range<usize>, segments: impl IntoIterator<Item = Interval>) {
+        let tail: SmallVec<[(f64, BoundKind); 2 * INLINE_COMPONENTS]> = (2 * range.end
+            ..self.values.len())
+            .map(|i| self.end(i))
+            .collect();
+        self.truncate_ends(2 * range.start);
+        for segment in segments {
+            self.push(segment);
+        }
+        for end in tail {
+            self.push_end(end);
+        }
+    }
+
+    /// Keep only the components for which `f` returns `true`, calling it once per component
+    pub fn retain(&mut self, mut f: impl FnMut(&Interval) -> bool) {
+        let mut kept = 0;
+        for i in 0..self.len() {
+            if f(&self.get(i)) {
+                let (left, right) = (self.end(2 * i), self.end(2 * i + 1));
+                self.set_end(2 * kept, left);
+                self.set_end(2 * kept + 1, right);
+                kept += 1;
+            }
+        }
+        self.truncate_ends(2 * kept);
+    }
+
+    /// Check if component `i` lies entirely before `x`
+    pub fn ends_before(&self, i: usize, x: f64) -> bool {
+        match self.end(2 * i + 1) {
+            (k, BoundKind::Closed) => k < x,
+            (k, BoundKind::Open) => k <= x,
+            (_, BoundKind::Unbound) => false,
+        }
+    }
+
+    /// Index of the first component whose right endpoint does not lie before `x`
+    pub fn locate(&self, x: f64) -> usize {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.ends_before(mid, x) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+impl FromIterator<Interval> for Endpoints {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut res = Endpoints::new();
+        for segment in iter {
+            res.push(segment);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::INFINITY;
+
+    #[test]
+    fn test_endpoints_1() {
+        let segments = [
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Open(3.), Unbound),
+        ];
+        let ends: Endpoints = segments.into_iter().collect();
+        assert_eq!(ends.len(), 3);
+        assert_eq!(ends.iter().collect::<Vec<_>>(), segments.to_vec());
+        assert_eq!(ends.last(), Some(segments[2]));
+        assert_eq!(ends.locate(0.), 1);
+        assert_eq!(ends.locate(2.), 1);
+        assert_eq!(ends.locate(2.5), 2);
+        assert_eq!(ends.locate(f64::INFINITY), 2);
+    }
+
+    #[test]
+    fn test_splice_1() {
+        let mut ends: Endpoints = [
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Open(3.), Unbound),
+        ]
+        .into_iter()
+        .collect();
+        ends.splice(1..2, [Interval::singleton(1.), Interval::singleton(2.)]);
+        assert_eq!(ends.len(), 4);
+        assert_eq!(ends.get(2), Interval::singleton(2.));
+        ends.splice(0..4, []);
+        assert_eq!(ends.len(), 0);
+    }
+
+    #[test]
+    fn test_endpoints_2() {
+        let ends: Endpoints = [INFINITY].into_iter().collect();
+        assert_eq!(ends.get(0), INFINITY);
+        assert_eq!(Endpoints::new().last(), None);
+    }
+
+    #[test]
+    fn test_endpoints_3() {
+        let mut ends: Endpoints = (0..INLINE_COMPONENTS)
+            .map(|i| Interval::singleton(i as f64))
+            .collect();
+        assert!(!ends.values.spilled() && !ends.kinds.spilled());
+        ends.push(Interval::singleton(INLINE_COMPONENTS as f64));
+        assert!(ends.values.spilled());
+        assert_eq!(ends.len(), INLINE_COMPONENTS + 1);
+    }
+
+    #[test]
+    fn test_kinds_1() {
+        // Kinds spanning several words survive splice and retain
+        let segment = |i: usize| match i % 3 {
+            0 => Interval::new(Closed(i as f64), Open(i as f64 + 0.5)),
+            1 => Interval::new(Open(i as f64), Closed(i as f64 + 0.5)),
+            _ => Interval::singleton(i as f64),
+        };
+        let mut ends: Endpoints = (0..40).map(segment).collect();
+        assert_eq!(ends.kinds.len(), 3);
+        assert!(ends.iter().eq((0..40).map(segment)));
+        ends.splice(10..30, [Interval::new(Closed(10.), Closed(29.5))]);
+        assert_eq!(ends.len(), 21);
+        assert_eq!(ends.get(11), segment(30));
+        assert_eq!(ends.get(20), segment(39));
+        ends.retain(|segment| !segment.is_singleton());
+        assert!(ends.iter().all(|segment| !segment.is_singleton()));
+        assert_eq!(ends.last(), Some(segment(39)));
+        assert_eq!(ends.len(), 15);
+        ends.push(Interval::new(Open(50.), Unbound));
+        assert_eq!(ends.last(), Some(Interval::new(Open(50.), Unbound)));
+    }
+}
@@ -0,0 +1,302 @@
+use super::{Interval, Left, EMPTY, INFINITY};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+type Link<V> = Option<Box<Node<V>>>;
+
+#[derive(Debug, Clone)]
+struct Node<V> {
+    key: Interval,
+    value: V,
+    span: Interval,
+    height: u32,
+    left: Link<V>,
+    right: Link<V>,
+}
+
+/// Check if non-empty intervals share at least one point
+fn intersect(a: &Interval, b: &Interval) -> bool {
+    !a.certainly_lt(b) && !b.certainly_lt(a)
+}
+
+fn height<V>(link: &Link<V>) -> u32 {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+impl<V> Node<V> {
+    fn new(key: Interval, value: V) -> Box<Self> {
+        Box::new(Node {
+            key,
+            value,
+            span: key,
+            height: 1,
+            left: None,
+            right: None,
+        })
+    }
+
+    /// Recompute height and span from children
+    fn update(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+        self.span = [&self.left, &self.right]
+            .into_iter()
+            .flatten()
+            .fold(self.key, |span, child| span.hull(child.span));
+    }
+
+    fn balance_factor(&self) -> i64 {
+        height(&self.left) as i64 - height(&self.right) as i64
+    }
+
+    fn rotate_right(mut self: Box<Self>) -> Box<Self> {
+        let mut pivot = self.left.take().expect("rotation needs a left child");
+        self.left = pivot.right.take();
+        self.update();
+        pivot.right = Some(self);
+        pivot.update();
+        pivot
+    }
+
+    fn rotate_left(mut self: Box<Self>) -> Box<Self> {
+        let mut pivot = self.right.take().expect("rotation needs a right child");
+        self.right = pivot.left.take();
+        self.update();
+        pivot.left = Some(self);
+        pivot.update();
+        pivot
+    }
+
+    fn rebalance(mut self: Box<Self>) -> Box<Self> {
+        self.update();
+        let balance = self.balance_factor();
+        if balance > 1 {
+            if self.left.as_ref().is_some_and(|l| l.balance_factor() < 0) {
+                self.left = self.left.take().map(Node::rotate_left);
+            }
+            self.rotate_right()
+        } else if balance < -1 {
+            if self.right.as_ref().is_some_and(|r| r.balance_factor() > 0) {
+                self.right = self.right.take().map(Node::rotate_right);
+            }
+            self.rotate_left()
+        } else {
+            self
+        }
+    }
+
+    fn insert(link: Link<V>, key: Interval, value: V) -> Box<Self> {
+        match link {
+            None => Node::new(key, value),
+            Some(mut node) => {
+                if Left(key.left()) < Left(node.key.left()) {
+                    node.left = Some(Node::insert(node.left.take(), key, value));
+                } else {
+                    node.right = Some(Node::insert(node.right.take(), key, value));
+                }
+                node.rebalance()
+            }
+        }
+    }
+}
+
+/// Collection of possibly overlapping intervals, each one carrying a value
+///
+/// Intervals are stored in a balanced binary search tree ordered by left endpoint, each node
+/// recording the hull of its subtree. Stabbing and overlap queries skip the subtrees whose hull
+/// misses the query, so that they run in `O(log n + k)` for `k` results in practice, instead of
+/// scanning all intervals.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalTree, Closed, Open};
+///
+/// let mut tree = IntervalTree::new();
+/// tree.insert(Interval::new(Closed(0.), Closed(10.)), "a");
+/// tree.insert(Interval::new(Closed(5.), Open(15.)), "b");
+/// tree.insert(Interval::new(Closed(20.), Closed(30.)), "c");
+///
+/// let values: Vec<&str> = tree.stab(7.).map(|(_, v)| *v).collect();
+/// assert_eq!(values, vec!["a", "b"]);
+///
+/// let query = Interval::new(Closed(12.), Closed(25.));
+/// let values: Vec<&str> = tree.query_overlapping(&query).map(|(_, v)| *v).collect();
+/// assert_eq!(values, vec!["b", "c"]);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct IntervalTree<V> {
+    root: Link<V>,
+    len: usize,
+}
+
+impl<V> Default for IntervalTree<V> {
+    fn default() -> Self {
+        IntervalTree::new()
+    }
+}
+
+impl<V> IntervalTree<V> {
+    pub fn new() -> Self {
+        IntervalTree { root: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert a value for a key, in `O(log n)`
+    ///
+    /// Keys may overlap or be equal. Inserting an empty key does nothing, since no point could
+    /// ever retrieve it.
+    ///
+    pub fn insert(&mut self, key: Interval, value: V) {
+        if key.is_empty() {
+            return;
+        }
+        self.root = Some(Node::insert(self.root.take(), key, value));
+        self.len += 1;
+    }
+
+    /// Iterate over entries whose key contains point `x`, in increasing order of left endpoints
+    ///
+    pub fn stab(&self, x: f64) -> impl Iterator<Item = (&Interval, &V)> {
+        let query = if x.is_nan() {
+            EMPTY
+        } else {
+            Interval::singleton(x)
+        };
+        self.query_overlapping(&query)
+    }
+
+    /// Iterate over entries whose key overlaps `query`, in increasing order of left endpoints
+    ///
+    pub fn query_overlapping(&self, query: &Interval) -> impl Iterator<Item = (&Interval, &V)> {
+        Overlapping {
+            stack: Vec::new(),
+            cursor: if query.is_empty() {
+                None
+            } else {
+                self.root.as_deref()
+            },
+            query: *query,
+        }
+    }
+
+    /// Iterate over all entries, in increasing order of left endpoints
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval, &V)> {
+        self.query_overlapping(&INFINITY)
+    }
+}
+
+impl<V> FromIterator<(Interval, V)> for IntervalTree<V> {
+    fn from_iter<I: IntoIterator<Item = (Interval, V)>>(iter: I) -> Self {
+        let mut tree = IntervalTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+/// In-order traversal of the entries overlapping a query
+struct Overlapping<'a, V> {
+    stack: Vec<&'a Node<V>>,
+    cursor: Option<&'a Node<V>>,
+    query: Interval,
+}
+
+impl<'a, V> Iterator for Overlapping<'a, V> {
+    type Item = (&'a Interval, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while let Some(node) = self.cursor.take() {
+                if intersect(&node.span, &self.query) {
+                    self.stack.push(node);
+                    self.cursor = node.left.as_deref();
+                }
+            }
+            let node = self.stack.pop()?;
+            self.cursor = node.right.as_deref();
+            if intersect(&node.key, &self.query) {
+                return Some((&node.key, &node.value));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound};
+
+    fn sample() -> IntervalTree<u32> {
+        [
+            (Interval::new(Closed(0.), Closed(10.)), 0),
+            (Interval::new(Open(5.), Closed(6.)), 1),
+            (Interval::new(Unbound, Open(1.)), 2),
+            (Interval::new(Closed(8.), Unbound), 3),
+            (Interval::singleton(10.), 4),
+            (Interval::new(Closed(0.), Closed(10.)), 5),
+            (EMPTY, 6),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn values<'a>(iter: impl Iterator<Item = (&'a Interval, &'a u32)>) -> Vec<u32> {
+        let mut values: Vec<u32> = iter.map(|(_, v)| *v).collect();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn test_insert_1() {
+        let tree = sample();
+        assert_eq!(tree.len(), 6);
+        let keys: Vec<Interval> = tree.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys[0], Interval::new(Unbound, Open(1.)));
+        assert_eq!(keys[5], Interval::singleton(10.));
+    }
+
+    #[test]
+    fn test_stab_1() {
+        let tree = sample();
+        assert_eq!(values(tree.stab(5.)), vec![0, 5]);
+        assert_eq!(values(tree.stab(6.)), vec![0, 1, 5]);
+        assert_eq!(values(tree.stab(10.)), vec![0, 3, 4, 5]);
+        assert_eq!(values(tree.stab(-1e300)), vec![2]);
+        assert_eq!(values(tree.stab(f64::NAN)), vec![]);
+    }
+
+    #[test]
+    fn test_query_overlapping_1() {
+        let tree = sample();
+        let query = Interval::new(Open(6.), Open(8.));
+        assert_eq!(values(tree.query_overlapping(&query)), vec![0, 5]);
+        let query = Interval::new(Open(10.), Unbound);
+        assert_eq!(values(tree.query_overlapping(&query)), vec![3]);
+        assert_eq!(values(tree.query_overlapping(&EMPTY)), vec![]);
+        assert_eq!(values(tree.query_overlapping(&INFINITY)).len(), 6);
+    }
+
+    #[test]
+    fn test_query_overlapping_2() {
+        let keys: Vec<Interval> = (0..1000)
+            .map(|i| Interval::new(Closed(i as f64), Open(i as f64 + 2.5)))
+            .collect();
+        let tree: IntervalTree<usize> = keys.iter().copied().zip(0..).collect();
+        let query = Interval::new(Closed(100.), Closed(110.));
+        let expected: Vec<usize> = (0..1000)
+            .filter(|&i| !keys[i].intersection(query).is_empty())
+            .collect();
+        let found: Vec<usize> = tree.query_overlapping(&query).map(|(_, v)| *v).collect();
+        assert_eq!(found, expected);
+    }
+}
@@ -28,10 +28,149 @@
 //! let s = Interval::singleton(42.); // {42}, equivalent to Interval::new(Closed(42.), Closed(42.))
 //! ```
 //!
+//! ## Parsing
 //!
+//! Intervals can be parsed from the notation used by `Display`, or from uncertainty notations.
+//!
+//! ```
+//! use interval::{Interval, Closed, Open};
+//!
+//! let a: Interval = "[0,42)".parse().unwrap();
+//! let b = Interval::parse_uncertainty("42.03(5)").unwrap(); // [41.98, 42.08]
+//!
+//! assert_eq!(a, Interval::new(Closed(0.), Open(42.)));
+//! ```
+//!
+//! ## Arithmetic
+//!
+//! With `std`, intervals support `+`, `-` and `*`, between them or with `f64` scalars: the result
+//! contains every `x op y` for `x` and `y` in the operands. Endpoints are rounded outward, so that
+//! results are verified enclosures despite floating-point rounding. Any operation involving
+//! `EMPTY` results in `EMPTY`. Elementary functions (`powi`, `sqrt`, `exp`, `ln`, `sin`, `cos`,
+//! `abs`) are methods of `Interval`, and module `expr` evaluates whole expressions parsed from
+//! text.
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use interval::{Interval, Closed, Open, Unbound};
+//!
+//! let a = Interval::new(Closed(1.), Open(2.));
+//! let b = Interval::new(Closed(-3.), Unbound);
+//!
+//! assert_eq!(a + b, Interval::new(Closed(-2.), Unbound));
+//! assert_eq!(a - a, Interval::new(Open(-1.), Open(1.)));
+//! assert_eq!(a * b, Interval::new(Closed(-6.), Unbound));
+//! assert_eq!(2. * a + 1., Interval::new(Closed(3.), Closed(5.)));
+//!
+//! // 0.1 + 0.2 is not exactly representable: result encloses it
+//! let c = Interval::singleton(0.1) + 0.2;
+//! assert!(c.inf().unwrap() < c.sup().unwrap());
+//! ```
+//!
+//! ## Features
+//!
+//! The crate is `no_std` when default features are disabled: `Interval` and its operations only
+//! need `core`.
+//!
+//! - `alloc`: collections of intervals (`IntervalSet`, `IntervalMap`, `IntervalTree`, `RectSet`, ...)
+//! - `std` (default): implies `alloc`, adds interval arithmetic, operators on `IntervalSet`,
+//!   binary layout writing, `AffineForm`, `DualInterval`, and modules `contract` and `expr`
+//! - `directed`: `DirectedInterval`, Kaucher arithmetic on directed intervals
+//! - `defmt`: `defmt::Format` implementations for `Bound` and `Interval`, for embedded logging
+//! - `ffi`: C interface to `Interval` in module `ffi`
+//! - `wasm`: JavaScript bindings to `Interval` and `IntervalSet` in module `wasm`
+//! - `rand`: random sampling of intervals and interval sets (uniform, custom density, truncated
+//!   normal with `std`), and seeded generators of adversarial inputs for tests in module `gen`
+//!
+//!
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "std")]
+mod affine;
+#[cfg(feature = "alloc")]
+mod circular;
+mod coalesce;
+mod context;
+#[cfg(feature = "std")]
+pub mod contract;
+#[cfg(feature = "alloc")]
+mod coverage;
+#[cfg(feature = "directed")]
+mod directed;
+#[cfg(feature = "std")]
+mod dual;
+mod error;
+#[cfg(feature = "std")]
+pub mod expr;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "rand")]
+pub mod gen;
 mod interval;
+mod interval_box;
+#[cfg(feature = "alloc")]
+mod interval_map;
+#[cfg(feature = "alloc")]
 mod interval_set;
+#[cfg(feature = "alloc")]
+mod interval_tree;
+mod math;
+#[cfg(feature = "alloc")]
+pub mod optimize;
+mod parse;
+#[cfg(feature = "alloc")]
+mod periodic;
+#[cfg(feature = "alloc")]
+mod piecewise_linear;
+#[cfg(feature = "alloc")]
+mod rect_set;
+#[cfg(feature = "alloc")]
+mod run_set;
+#[cfg(feature = "alloc")]
+pub mod scheduling;
+#[cfg(feature = "alloc")]
+mod step_function;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use interval::{Closed, Interval, Open, Unbound, EMPTY, INFINITY};
-pub use interval_set::IntervalSet;
+#[cfg(feature = "std")]
+pub use affine::AffineForm;
+#[cfg(feature = "alloc")]
+pub use circular::CircularInterval;
+pub use coalesce::CoalesceIntervals;
+pub use context::IntervalContext;
+#[cfg(feature = "alloc")]
+pub use coverage::Coverage;
+#[cfg(feature = "directed")]
+pub use directed::DirectedInterval;
+#[cfg(feature = "std")]
+pub use dual::DualInterval;
+pub use error::IntervalError;
+pub use interval::{
+    AllenRelation, Bound, Closed, IntegerPolicy, Interval, IntervalFormat, Left, Open,
+    PackedInterval, Right, RoundingMode, Unbound, EMPTY, INFINITY,
+};
+pub use interval_box::IntervalBox;
+#[cfg(feature = "alloc")]
+pub use interval_map::IntervalMap;
+#[cfg(feature = "alloc")]
+pub use interval_set::{
+    BoundPolicy, IntervalSet, IntervalSetRef, LayoutError, SetOp, SharedIntervalSet,
+};
+#[cfg(feature = "alloc")]
+pub use interval_tree::IntervalTree;
+pub use parse::ParseIntervalError;
+#[cfg(feature = "alloc")]
+pub use periodic::PeriodicSet;
+#[cfg(feature = "alloc")]
+pub use piecewise_linear::PiecewiseLinear;
+#[cfg(feature = "alloc")]
+pub use rect_set::RectSet;
+#[cfg(feature = "alloc")]
+pub use run_set::RunSet;
+#[cfg(feature = "alloc")]
+pub use step_function::StepFunction;
@@ -0,0 +1,88 @@
+//! Float functions missing from `core`
+//!
+//! With `std`, they forward to the inherent `f64` methods. Without it, they are computed from
+//! truncating casts, which are exact for every `f64` holding a fractional part (ie. below
+//! `2^52` in magnitude).
+
+#[cfg(feature = "std")]
+pub fn floor(x: f64) -> f64 {
+    x.floor()
+}
+
+#[cfg(feature = "std")]
+pub fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+#[cfg(feature = "std")]
+pub fn round(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(feature = "std")]
+pub fn powi(x: f64, n: i32) -> f64 {
+    x.powi(n)
+}
+
+/// Smallest magnitude from which every `f64` is an integer
+#[cfg(not(feature = "std"))]
+const TWO_POW_52: f64 = 4503599627370496.;
+
+/// Integral part of `x`, sign of zero preserved
+#[cfg(not(feature = "std"))]
+fn trunc(x: f64) -> f64 {
+    if !x.is_finite() || x.abs() >= TWO_POW_52 {
+        x
+    } else {
+        (x as i64 as f64).copysign(x)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub fn floor(x: f64) -> f64 {
+    let t = trunc(x);
+    if t > x {
+        t - 1.
+    } else {
+        t
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub fn ceil(x: f64) -> f64 {
+    let t = trunc(x);
+    if t < x {
+        t + 1.
+    } else {
+        t
+    }
+}
+
+/// Round half away from zero, like `f64::round`
+#[cfg(not(feature = "std"))]
+pub fn round(x: f64) -> f64 {
+    let t = trunc(x);
+    if (x - t).abs() >= 0.5 {
+        t + x.signum()
+    } else {
+        t
+    }
+}
+
+/// Exponentiation by squaring, like `f64::powi`
+#[cfg(not(feature = "std"))]
+pub fn powi(x: f64, n: i32) -> f64 {
+    let (mut base, mut exp, mut res) = (x, n.unsigned_abs(), 1.);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res *= base;
+        }
+        base *= base;
+        exp >>= 1;
+    }
+    if n < 0 {
+        1. / res
+    } else {
+        res
+    }
+}
@@ -0,0 +1,202 @@
+//! Global optimization over boxes
+//!
+//! Functions are given by an inclusion function: a function from boxes to intervals, such that
+//! `f(b)` contains the value of the objective at every point of box `b`. Interval arithmetic
+//! provides natural inclusion functions, by evaluating the expression of the objective on the
+//! axes of the box.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::{Closed, Interval, IntervalBox, Unbound, EMPTY};
+
+/// Box waiting to be processed, ordered so that the lowest lower bound comes out of the heap first
+struct Candidate<const N: usize> {
+    lower: f64,
+    domain: IntervalBox<N>,
+}
+
+impl<const N: usize> PartialEq for Candidate<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<const N: usize> Eq for Candidate<N> {}
+
+impl<const N: usize> PartialOrd for Candidate<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Candidate<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.lower.total_cmp(&self.lower)
+    }
+}
+
+/// Enclosure of the global minimum of `f` over `domain`, by interval branch-and-bound
+///
+/// Boxes are processed by increasing lower bound of `f`. The value of `f` at the midpoint of each
+/// box gives an upper bound of the minimum, and boxes whose lower bound exceeds the best upper
+/// bound are pruned. Remaining boxes are bisected along their widest dimension, until they are
+/// at most `tol` wide on every axis (or cannot be bisected any further).
+///
+/// Result is verified as long as `f` is a valid inclusion function: boxes are never discarded
+/// unless `f` proves they cannot hold the minimum.
+///
+/// # Returns
+///
+/// An interval containing the global minimum, and the hull of the remaining boxes, which contains
+/// every global minimizer. `(EMPTY, IntervalBox::empty())` if domain is empty, or if `f` is
+/// empty on every box.
+///
+/// # Panics
+///
+/// Panics if `tol` is not strictly positive.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalBox, Closed};
+/// use interval::optimize::minimize;
+///
+/// // |x - 1|, with global minimum 0 at x = 1
+/// let f = |b: &IntervalBox<1>| {
+///     let [x] = *b.axes();
+///     let (lo, hi) = (x.inf().unwrap() - 1., x.sup().unwrap() - 1.);
+///     Interval::new(Closed(x.distance_to_point(1.)), Closed(lo.abs().max(hi.abs())))
+/// };
+/// let domain = IntervalBox::new([Interval::new(Closed(-3.), Closed(2.))]);
+/// let (min, minimizers) = minimize(f, domain, 1e-6);
+///
+/// assert!(min.contains(0.) && min.width() < 1e-4);
+/// assert!(minimizers.contains([1.]));
+/// ```
+///
+pub fn minimize<const N: usize, F>(
+    f: F,
+    domain: IntervalBox<N>,
+    tol: f64,
+) -> (Interval, IntervalBox<N>)
+where
+    F: Fn(&IntervalBox<N>) -> Interval,
+{
+    assert!(tol > 0., "tol must be strictly positive");
+    let mut upper = f64::INFINITY;
+    let mut queue = BinaryHeap::new();
+    let mut done: Vec<Candidate<N>> = Vec::new();
+
+    let push = |queue: &mut BinaryHeap<Candidate<N>>, domain: IntervalBox<N>| {
+        if let Some(lower) = f(&domain).inf() {
+            queue.push(Candidate { lower, domain });
+        }
+    };
+    if !domain.is_empty() {
+        push(&mut queue, domain);
+    }
+
+    while let Some(candidate) = queue.pop() {
+        if candidate.lower > upper {
+            break;
+        }
+        if let Some(center) = candidate.domain.midpoint() {
+            let point = IntervalBox::new(center.map(Interval::singleton));
+            if let Some(value) = f(&point).sup() {
+                upper = upper.min(value);
+            }
+        }
+        let narrow = candidate
+            .domain
+            .axes()
+            .iter()
+            .all(|axis| axis.width() <= tol);
+        match candidate.domain.bisect() {
+            Some((lower, higher))
+                if !narrow && lower != candidate.domain && higher != candidate.domain =>
+            {
+                push(&mut queue, lower);
+                push(&mut queue, higher);
+            }
+            _ => done.push(candidate),
+        }
+    }
+
+    let kept = done.iter().filter(|candidate| candidate.lower <= upper);
+    let lower = kept
+        .clone()
+        .map(|candidate| candidate.lower)
+        .fold(f64::INFINITY, f64::min);
+    let hull = kept.fold(IntervalBox::empty(), |hull, candidate| {
+        hull.hull(&candidate.domain)
+    });
+    if hull.is_empty() {
+        return (EMPTY, hull);
+    }
+    let bound = |k: f64| if k.is_infinite() { Unbound } else { Closed(k) };
+    (Interval::new(bound(lower), bound(upper)), hull)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+    use crate::Open;
+
+    fn square(a: f64, b: f64) -> IntervalBox<2> {
+        let side = Interval::new(Closed(a), Closed(b));
+        IntervalBox::new([side, side])
+    }
+
+    #[test]
+    fn test_minimize_1() {
+        let f = |b: &IntervalBox<2>| {
+            let [x, y] = *b.axes();
+            (x - 1.).sqr() + (y + 2.).sqr() + 3.
+        };
+        let (min, minimizers) = minimize(f, square(-10., 10.), 1e-4);
+        assert!(min.contains(3.) && min.width() < 1e-6);
+        assert!(minimizers.contains([1., -2.]));
+        assert!(minimizers.axes().iter().all(|axis| axis.width() < 1e-2));
+    }
+
+    #[test]
+    fn test_minimize_2() {
+        // Minimum on the boundary of the domain
+        let f = |b: &IntervalBox<2>| {
+            let [x, y] = *b.axes();
+            x + y
+        };
+        let (min, minimizers) = minimize(f, square(0., 1.), 1e-3);
+        assert!(min.contains(0.) && min.width() < 1e-2);
+        assert!(minimizers.contains([0., 0.]));
+        assert_eq!(
+            minimize(f, IntervalBox::empty(), 1.),
+            (EMPTY, IntervalBox::empty())
+        );
+    }
+
+    #[test]
+    fn test_minimize_3() {
+        // Function only defined for x > 0
+        let f = |b: &IntervalBox<1>| {
+            let [x] = *b.axes();
+            x.intersection(Interval::new(Open(0.), Unbound)) * 2.
+        };
+        let domain = IntervalBox::new([Interval::new(Closed(-1.), Closed(1.))]);
+        let (min, minimizers) = minimize(f, domain, 1e-3);
+        assert!(min.contains(0.));
+        assert!(minimizers.axes()[0].sup().unwrap() <= 1e-2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_minimize_4() {
+        minimize(
+            |b: &IntervalBox<1>| b.axes()[0],
+            IntervalBox::infinity(),
+            0.,
+        );
+    }
+}
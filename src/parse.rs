@@ -0,0 +1,434 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::math;
+#[cfg(feature = "alloc")]
+use crate::IntervalSet;
+use crate::{Closed, Interval, Open, Unbound, EMPTY};
+
+/// Error returned when parsing an interval from text fails
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntervalError {
+    reason: &'static str,
+}
+
+impl ParseIntervalError {
+    fn new(reason: &'static str) -> Self {
+        ParseIntervalError { reason }
+    }
+}
+
+impl Display for ParseIntervalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid interval: {}", self.reason)
+    }
+}
+
+impl core::error::Error for ParseIntervalError {}
+
+/// Parse an endpoint value, infinities included (`∞`, `+∞`, `-∞`, `inf`, `-inf`, ...)
+fn parse_value(s: &str) -> Result<f64, ParseIntervalError> {
+    let value = match s.trim() {
+        "-∞" => f64::NEG_INFINITY,
+        "∞" | "+∞" => f64::INFINITY,
+        s => s
+            .parse::<f64>()
+            .map_err(|_| ParseIntervalError::new("invalid number"))?,
+    };
+    if value.is_nan() {
+        Err(ParseIntervalError::new("NaN endpoint"))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Parse a non-negative decimal number into its digits and its count of fractional digits
+fn parse_decimal(s: &str) -> Option<(i128, i32)> {
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    if !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) || s.len() > 30 {
+        return None;
+    }
+    let digits = int
+        .bytes()
+        .chain(frac.bytes())
+        .fold(0i128, |digits, c| 10 * digits + i128::from(c - b'0'));
+    Some((digits, frac.len() as i32))
+}
+
+/// Value of `digits * 10^exp`, correctly rounded as long as operands are exact
+fn scale(digits: i128, exp: i32) -> f64 {
+    // digits hold at most 39 figures: beyond, results overflow or underflow anyway
+    let exp = exp.clamp(-800, 800);
+    if digits == 0 {
+        0.
+    } else if exp < 0 {
+        digits as f64 / math::powi(10f64, -exp)
+    } else {
+        digits as f64 * math::powi(10f64, exp)
+    }
+}
+
+/// Parse concise uncertainty notation: `42.03(5)`, `42.03(0.05)`, `4.203(5)e1`
+fn parse_concise(s: &str) -> Result<Interval, ParseIntervalError> {
+    let invalid = || ParseIntervalError::new("invalid uncertainty notation");
+
+    let (value, rest) = s.split_once('(').ok_or_else(invalid)?;
+    let (uncertainty, exponent) = rest.split_once(')').ok_or_else(invalid)?;
+    let exponent = match exponent.trim() {
+        "" => 0,
+        e => e
+            .strip_prefix(['e', 'E'])
+            .and_then(|e| e.parse::<i32>().ok())
+            .ok_or_else(invalid)?,
+    };
+
+    let value = value.trim();
+    let (negative, magnitude) = match value.strip_prefix('-') {
+        Some(magnitude) => (true, magnitude),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (digits, decimals) = parse_decimal(magnitude).ok_or_else(invalid)?;
+    let digits = if negative { -digits } else { digits };
+    let (uncertainty, uncertainty_decimals) =
+        parse_decimal(uncertainty.trim()).ok_or_else(invalid)?;
+
+    let out_of_range = || ParseIntervalError::new("uncertainty notation out of range");
+    let shift = |digits: i128, by: i32| {
+        10i128
+            .checked_pow(by as u32)
+            .and_then(|power| digits.checked_mul(power))
+            .ok_or_else(out_of_range)
+    };
+    let (digits, uncertainty, decimals) = if uncertainty_decimals == 0 {
+        // uncertainty applies to the last digits of value
+        (digits, uncertainty, decimals)
+    } else {
+        // uncertainty is written with its own decimal point
+        let decimals_max = decimals.max(uncertainty_decimals);
+        (
+            shift(digits, decimals_max - decimals)?,
+            shift(uncertainty, decimals_max - uncertainty_decimals)?,
+            decimals_max,
+        )
+    };
+    let exp = exponent.checked_sub(decimals).ok_or_else(out_of_range)?;
+    let (lo, hi) = (
+        scale(
+            digits.checked_sub(uncertainty).ok_or_else(out_of_range)?,
+            exp,
+        ),
+        scale(
+            digits.checked_add(uncertainty).ok_or_else(out_of_range)?,
+            exp,
+        ),
+    );
+    if !lo.is_finite() || !hi.is_finite() {
+        return Err(out_of_range());
+    }
+    Ok(Interval::new(Closed(lo), Closed(hi)))
+}
+
+/// Parse plus-minus notation: `1.5 ± 0.2` or `1.5 +/- 0.2`
+fn parse_plus_minus(s: &str) -> Result<Interval, ParseIntervalError> {
+    let (center, half_width) = s
+        .split_once('±')
+        .or_else(|| s.split_once("+/-"))
+        .ok_or_else(|| ParseIntervalError::new("invalid uncertainty notation"))?;
+    let (center, half_width) = (parse_value(center)?, parse_value(half_width)?);
+    if half_width < 0. {
+        return Err(ParseIntervalError::new("negative uncertainty"));
+    }
+    Ok(Interval::from_midpoint(center, half_width))
+}
+
+impl Interval {
+    /// Parse a value with its uncertainty into a closed interval
+    ///
+    /// Both concise metrology notation (`42.03(5)`, where the uncertainty applies to the last
+    /// digits of value, or `42.03(0.05)`, with an optional exponent as in `4.203(5)e1`) and
+    /// plus-minus notation (`1.5 ± 0.2` or `1.5 +/- 0.2`) are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// assert_eq!(
+    ///     Interval::parse_uncertainty("42.03(5)"),
+    ///     Ok(Interval::new(Closed(41.98), Closed(42.08)))
+    /// );
+    /// assert_eq!(
+    ///     Interval::parse_uncertainty("1.5 ± 0.5"),
+    ///     Ok(Interval::new(Closed(1.), Closed(2.)))
+    /// );
+    /// assert!(Interval::parse_uncertainty("1.5").is_err());
+    /// ```
+    ///
+    pub fn parse_uncertainty(s: &str) -> Result<Interval, ParseIntervalError> {
+        let s = s.trim();
+        if s.contains('±') || s.contains("+/-") {
+            parse_plus_minus(s)
+        } else {
+            parse_concise(s)
+        }
+    }
+}
+
+/// Parse an interval using the notation produced by `Display` (`[1,2)`, `(-∞,3]`, `{42}`, `∅`)
+///
+/// Infinite endpoints may be written `∞`, `-∞`, `+∞`, `inf` or `-inf`, and are unbound
+/// whatever the bracket. Uncertainty notations of `Interval::parse_uncertainty` are accepted too.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed, Open, Unbound, EMPTY};
+///
+/// let a: Interval = "[1, 2.5)".parse().unwrap();
+/// let b: Interval = "(-∞,3]".parse().unwrap();
+///
+/// assert_eq!(a, Interval::new(Closed(1.), Open(2.5)));
+/// assert_eq!(b, Interval::new(Unbound, Closed(3.)));
+/// assert_eq!("∅".parse::<Interval>(), Ok(EMPTY));
+/// assert_eq!("{42}".parse::<Interval>(), Ok(Interval::singleton(42.)));
+/// ```
+///
+impl FromStr for Interval {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s == "∅" {
+            return Ok(EMPTY);
+        }
+        if let Some(k) = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            let k = parse_value(k)?;
+            return if k.is_infinite() {
+                Err(ParseIntervalError::new("infinite singleton"))
+            } else {
+                Ok(Interval::singleton(k))
+            };
+        }
+
+        let mut chars = s.chars();
+        let (first, last) = match (chars.next(), chars.next_back()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Err(ParseIntervalError::new("empty input")),
+        };
+        if !matches!(first, '[' | '(') {
+            return Interval::parse_uncertainty(s);
+        }
+        if !matches!(last, ']' | ')') {
+            return Err(ParseIntervalError::new("missing closing bracket"));
+        }
+
+        let (left, right) = chars
+            .as_str()
+            .split_once(',')
+            .ok_or_else(|| ParseIntervalError::new("missing comma"))?;
+        let (left, right) = (parse_value(left)?, parse_value(right)?);
+
+        let left = match (first, left) {
+            (_, k) if k == f64::NEG_INFINITY => Unbound,
+            (_, k) if k.is_infinite() => return Err(ParseIntervalError::new("invalid left bound")),
+            ('[', k) => Closed(k),
+            (_, k) => Open(k),
+        };
+        let right = match (last, right) {
+            (_, k) if k == f64::INFINITY => Unbound,
+            (_, k) if k.is_infinite() => {
+                return Err(ParseIntervalError::new("invalid right bound"))
+            }
+            (']', k) => Closed(k),
+            (_, k) => Open(k),
+        };
+        Ok(Interval::new(left, right))
+    }
+}
+
+/// Parse a set using the notation produced by `Display` (`[1,2) U (3,5]`, `∅`)
+///
+/// Components may be separated by `U` or `∪`, and are written as accepted by `Interval`'s
+/// `FromStr`. They need not be sorted nor disjoint: they are merged as in
+/// `IntervalSet::union_all`.
+///
+/// `Display` rounds endpoints to two decimals, so that its output only parses back to the same
+/// set for such values. Text written with `IntervalFormat::new().exact()` always does.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalFormat, IntervalSet, Closed, Open};
+///
+/// let a: IntervalSet = "[1,2) ∪ (3,5]".parse().unwrap();
+///
+/// assert_eq!(
+///     a,
+///     IntervalSet::from(&[
+///         Interval::new(Closed(1.), Open(2.)),
+///         Interval::new(Open(3.), Closed(5.)),
+///     ])
+/// );
+/// assert_eq!(a.to_string().parse::<IntervalSet>(), Ok(a));
+/// assert_eq!("∅".parse::<IntervalSet>(), Ok(IntervalSet::new()));
+///
+/// let b = IntervalSet::from(&[Interval::new(Closed(0.1 + 0.2), Open(1.))]);
+/// let text = IntervalFormat::new().exact().display_set(&b).to_string();
+///
+/// assert_eq!(text, "[0.30000000000000004,1)");
+/// assert_eq!(text.parse::<IntervalSet>(), Ok(b));
+/// ```
+///
+#[cfg(feature = "alloc")]
+impl FromStr for IntervalSet {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = s
+            .split(['U', '∪'])
+            .map(str::parse::<Interval>)
+            .collect::<Result<alloc::vec::Vec<_>, _>>()?;
+        Ok(IntervalSet::union_all(components))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::INFINITY;
+
+    #[test]
+    fn test_parse_1() {
+        let a = Interval::new(Open(42.), Closed(43.));
+        assert_eq!(format!("{a}").parse::<Interval>(), Ok(a));
+        assert_eq!(format!("{INFINITY}").parse::<Interval>(), Ok(INFINITY));
+        assert_eq!(format!("{EMPTY}").parse::<Interval>(), Ok(EMPTY));
+    }
+
+    #[test]
+    fn test_parse_2() {
+        let a = Interval::singleton(1.);
+        assert_eq!(format!("{a}").parse::<Interval>(), Ok(a));
+        assert_eq!(" [ -inf , inf ] ".parse::<Interval>(), Ok(INFINITY));
+        assert_eq!("[2,1]".parse::<Interval>(), Ok(EMPTY));
+    }
+
+    #[test]
+    fn test_parse_3() {
+        for s in [
+            "", "[", "[1,2", "[1;2]", "[a,2]", "[NaN,2]", "[+∞,2]", "{∞}", "1,2",
+        ] {
+            assert!(s.parse::<Interval>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_parse_set_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(-1.)),
+            Interval::singleton(0.5),
+            Interval::new(Closed(2.), Unbound),
+        ]);
+        assert_eq!(a.to_string().parse::<IntervalSet>(), Ok(a));
+        assert_eq!("∅".parse::<IntervalSet>(), Ok(IntervalSet::new()));
+        assert_eq!(
+            " [2,3]∪[0,1) U [1, 2) ".parse::<IntervalSet>(),
+            Ok(IntervalSet::from(&[Interval::new(Closed(0.), Closed(3.))]))
+        );
+        for s in ["", "[0,1] U", "[0,1] ∪ ∪ [2,3]", "[0,1] , [2,3]"] {
+            assert!(s.parse::<IntervalSet>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_parse_set_2() {
+        // Display rounds to two decimals, the exact format does not
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.1 + 0.2), Open(0.7)),
+            Interval::singleton(1. / 3. + 1.),
+            Interval::new(Open(5e-324), Unbound),
+        ]);
+        assert_ne!(a.to_string().parse::<IntervalSet>(), Ok(a.clone()));
+        let text = crate::IntervalFormat::new()
+            .exact()
+            .display_set(&a)
+            .to_string();
+        assert_eq!(text.parse::<IntervalSet>(), Ok(a));
+    }
+
+    #[test]
+    fn test_parse_uncertainty_1() {
+        assert_eq!(
+            Interval::parse_uncertainty("1.234(12)"),
+            Ok(Interval::new(Closed(1.222), Closed(1.246)))
+        );
+        assert_eq!(
+            Interval::parse_uncertainty("42(5)"),
+            Ok(Interval::new(Closed(37.), Closed(47.)))
+        );
+        assert_eq!(
+            Interval::parse_uncertainty("-42.03(0.5)"),
+            Ok(Interval::new(Closed(-42.53), Closed(-41.53)))
+        );
+    }
+
+    #[test]
+    fn test_parse_uncertainty_2() {
+        assert_eq!(
+            Interval::parse_uncertainty("4.203(5)e1"),
+            Ok(Interval::new(Closed(41.98), Closed(42.08)))
+        );
+        assert_eq!(
+            "1.5 +/- 0.25".parse::<Interval>(),
+            Ok(Interval::new(Closed(1.25), Closed(1.75)))
+        );
+        assert_eq!(
+            "42.03(5)".parse::<Interval>(),
+            Interval::parse_uncertainty("42.03(5)")
+        );
+    }
+
+    #[test]
+    fn test_parse_uncertainty_3() {
+        for s in [
+            "1.5",
+            "1.5(",
+            "1.5()",
+            "(5)",
+            "1.5(-5)",
+            "1.5(5)x",
+            "1.5 ± -1",
+            "1.5 ± x",
+        ] {
+            assert!(Interval::parse_uncertainty(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_parse_uncertainty_4() {
+        // Overflowing exponents and digits
+        for s in [
+            "1.5(5)e-2147483648",
+            "123456789012345678901234567890(0.000000000000000000000000001)",
+            "1.5(5)e2147483647",
+            "1.5(5)e400",
+        ] {
+            assert_eq!(
+                Interval::parse_uncertainty(s),
+                Err(ParseIntervalError::new("uncertainty notation out of range")),
+                "{s}"
+            );
+        }
+        assert_eq!(
+            Interval::parse_uncertainty("0(0)e2147483647"),
+            Ok(Interval::singleton(0.))
+        );
+    }
+}
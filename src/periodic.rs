@@ -0,0 +1,241 @@
+use core::fmt::Display;
+
+use super::{math, CircularInterval, Interval, IntervalError, IntervalSet, SetOp};
+
+/// Set made of a base set repeated every `period`: union of `base + k * period` for every integer
+/// `k`
+///
+/// The set is stored as its pattern over one period, the subset of `[0, period)` obtained by
+/// folding the base set modulo the period. Sets of the same period combine pattern by pattern.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, PeriodicSet, Closed, Open};
+///
+/// // Office hours, every day
+/// let office = PeriodicSet::new(&IntervalSet::from(&[Interval::new(Closed(9.), Open(17.))]), 24.);
+///
+/// assert!(office.contains(24. * 3. + 10.));
+/// assert!(!office.contains(-1.));
+/// assert_eq!(
+///     office.window(&Interval::new(Closed(12.), Closed(48.))),
+///     Some(IntervalSet::from(&[
+///         Interval::new(Closed(12.), Open(17.)),
+///         Interval::new(Closed(33.), Open(41.)),
+///     ]))
+/// );
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodicSet {
+    pattern: IntervalSet,
+    period: f64,
+}
+
+impl PeriodicSet {
+    /// Greatest number of periods a window may span (see `PeriodicSet::window`)
+    pub const MAX_WINDOW_PERIODS: u64 = 1 << 20;
+
+    /// Set made of `base` repeated every `period`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not finite and strictly positive.
+    ///
+    pub fn new(base: &IntervalSet, period: f64) -> Self {
+        let pattern = base
+            .iter()
+            .map(|component| CircularInterval::new(component, period).pieces())
+            .fold(IntervalSet::new(), |pattern, pieces| {
+                pattern.union_intervals(&pieces)
+            });
+        PeriodicSet { pattern, period }
+    }
+
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    /// Points of set within `[0, period)`
+    ///
+    pub fn pattern(&self) -> &IntervalSet {
+        &self.pattern
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_empty()
+    }
+
+    /// Check if `x` belongs to set
+    ///
+    pub fn contains(&self, x: f64) -> bool {
+        let mut y = x % self.period;
+        if y < 0. {
+            y += self.period;
+        }
+        if y >= self.period {
+            y = 0.;
+        }
+        self.pattern.contains(y)
+    }
+
+    /// Points of set within `window`, as a plain set
+    ///
+    /// Pattern is copied once per period spanned by window.
+    ///
+    /// # Returns
+    ///
+    /// `None` if window is unbounded, or spans more than `MAX_WINDOW_PERIODS` periods.
+    ///
+    pub fn window(&self, window: &Interval) -> Option<IntervalSet> {
+        if window.is_empty() || self.is_empty() {
+            return Some(IntervalSet::new());
+        }
+        if !window.is_bounded() {
+            return None;
+        }
+        let first = math::floor(window.inf()? / self.period);
+        let last = math::floor(window.sup()? / self.period);
+        let span = last - first;
+        if span.is_nan() || span >= Self::MAX_WINDOW_PERIODS as f64 {
+            return None;
+        }
+        let (first, last) = (first as i64, last as i64);
+        let window = IntervalSet::from(&[*window]);
+        let copies = (first..=last).flat_map(|k| {
+            let offset = k as f64 * self.period;
+            self.pattern
+                .iter()
+                .map(move |segment| segment.translate(offset))
+        });
+        Some(IntervalSet::union_all(copies).intersection(&window))
+    }
+
+    pub fn union(&self, other: &PeriodicSet) -> Self {
+        self.merge_with(other, SetOp::Union)
+    }
+
+    pub fn intersection(&self, other: &PeriodicSet) -> Self {
+        self.merge_with(other, SetOp::Intersection)
+    }
+
+    pub fn difference(&self, other: &PeriodicSet) -> Self {
+        self.merge_with(other, SetOp::Difference)
+    }
+
+    /// Combine the patterns of two sets with set operation `op`
+    ///
+    /// # Panics
+    ///
+    /// Panics if sets have distinct periods (see `PeriodicSet::try_merge_with` for a
+    /// non-panicking version).
+    ///
+    pub fn merge_with(&self, other: &PeriodicSet, op: SetOp) -> Self {
+        match self.try_merge_with(other, op) {
+            Ok(res) => res,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Combine the patterns of two sets with set operation `op`
+    ///
+    /// # Returns
+    ///
+    /// `IntervalError::IncompatibleOperands` if sets have distinct periods.
+    ///
+    pub fn try_merge_with(&self, other: &PeriodicSet, op: SetOp) -> Result<Self, IntervalError> {
+        if self.period != other.period {
+            return Err(IntervalError::IncompatibleOperands(
+                "periodic sets must share the same period",
+            ));
+        }
+        Ok(PeriodicSet {
+            pattern: self.pattern.merge_with(&other.pattern, op),
+            period: self.period,
+        })
+    }
+}
+
+impl Display for PeriodicSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} mod {}", self.pattern, self.period)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, INFINITY};
+
+    fn set(intervals: &[(f64, f64)]) -> IntervalSet {
+        let intervals: Vec<Interval> = intervals
+            .iter()
+            .map(|&(a, b)| Interval::new(Closed(a), Open(b)))
+            .collect();
+        IntervalSet::from(&intervals)
+    }
+
+    #[test]
+    fn test_new_1() {
+        let a = PeriodicSet::new(&set(&[(22., 26.), (-3., -2.)]), 24.);
+        assert_eq!(a.pattern(), &set(&[(0., 2.), (21., 24.)]));
+        assert!(a.contains(-0.5) && a.contains(1000. * 24. + 21.5));
+        let b = PeriodicSet::new(&IntervalSet::from(&[INFINITY]), 24.);
+        assert_eq!(b.pattern(), &set(&[(0., 24.)]));
+        assert!(PeriodicSet::new(&IntervalSet::new(), 1.).is_empty());
+    }
+
+    #[test]
+    fn test_window_1() {
+        let a = PeriodicSet::new(&set(&[(0., 1.)]), 10.);
+        assert_eq!(
+            a.window(&Interval::new(Closed(-15.), Closed(10.))),
+            Some(set(&[(-10., -9.), (0., 1.)]).union_interval(&Interval::singleton(10.)))
+        );
+        assert_eq!(a.window(&Interval::new(Closed(0.), Unbound)), None);
+        assert_eq!(
+            a.window(&Interval::new(Open(1.), Open(10.))),
+            Some(IntervalSet::new())
+        );
+    }
+
+    #[test]
+    fn test_window_2() {
+        // Windows spanning too many periods are refused
+        let a = PeriodicSet::new(&set(&[(0., 1.)]), 1e-300);
+        assert_eq!(a.window(&Interval::new(Closed(0.), Closed(1.))), None);
+        let b = PeriodicSet::new(&set(&[(0., 1.)]), 10.);
+        let wide = Interval::new(Closed(-f64::MAX), Closed(f64::MAX));
+        assert_eq!(b.window(&wide), None);
+        let last = (PeriodicSet::MAX_WINDOW_PERIODS - 1) as f64 * 10. + 5.;
+        let window = b.window(&Interval::new(Closed(0.), Open(last))).unwrap();
+        assert_eq!(
+            window.iter().count() as u64,
+            PeriodicSet::MAX_WINDOW_PERIODS
+        );
+        // Empty sets need no copy
+        let empty = PeriodicSet::new(&IntervalSet::new(), 10.);
+        assert_eq!(empty.window(&wide), Some(IntervalSet::new()));
+    }
+
+    #[test]
+    fn test_merge_with_1() {
+        let a = PeriodicSet::new(&set(&[(0., 4.)]), 10.);
+        let b = PeriodicSet::new(&set(&[(8., 12.)]), 10.);
+        assert_eq!(a.union(&b).pattern(), &set(&[(0., 4.), (8., 10.)]));
+        assert_eq!(a.intersection(&b).pattern(), &set(&[(0., 2.)]));
+        assert_eq!(a.difference(&b).pattern(), &set(&[(2., 4.)]));
+    }
+
+    #[test]
+    fn test_try_merge_with_1() {
+        let a = PeriodicSet::new(&set(&[(0., 1.)]), 2.);
+        let b = PeriodicSet::new(&set(&[(0., 1.)]), 3.);
+        assert!(matches!(
+            a.try_merge_with(&b, SetOp::Union),
+            Err(IntervalError::IncompatibleOperands(_))
+        ));
+        assert_eq!(a.try_merge_with(&a, SetOp::Union), Ok(a.clone()));
+    }
+}
@@ -0,0 +1,201 @@
+use alloc::vec::Vec;
+
+use super::{Closed, Interval, IntervalSet};
+
+/// Continuous function defined by linear interpolation between breakpoints
+///
+/// The function is defined over the closed interval spanned by its breakpoints. It is typically
+/// a measured profile (power over time, speed over distance, ...), to be aggregated over
+/// intervals or interval sets.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, PiecewiseLinear, Closed, Open};
+///
+/// // Power (kW) over time (h)
+/// let power = PiecewiseLinear::new(vec![(0., 0.), (2., 10.), (4., 10.), (5., 0.)]).unwrap();
+///
+/// assert_eq!(power.eval(1.), Some(5.));
+/// assert_eq!(power.eval(6.), None);
+/// // Energy (kWh) consumed during peak hours
+/// let peak = IntervalSet::from(&[
+///     Interval::new(Closed(1.), Open(2.)),
+///     Interval::new(Closed(3.), Open(8.)),
+/// ]);
+/// assert_eq!(power.integrate_over_set(&peak), 7.5 + 15.);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiecewiseLinear {
+    points: Vec<(f64, f64)>,
+}
+
+/// Value at `x` of the line through `(x0, y0)` and `(x1, y1)`
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 == x0 {
+        return y0;
+    }
+    y0 + (y1 - y0) * ((x - x0) / (x1 - x0))
+}
+
+impl PiecewiseLinear {
+    /// Function through breakpoints `(x, y)`
+    ///
+    /// # Returns
+    ///
+    /// `None` if there is no breakpoint, if a coordinate is not finite, or if abscissas are not
+    /// strictly increasing.
+    ///
+    pub fn new(points: Vec<(f64, f64)>) -> Option<Self> {
+        let finite = points.iter().all(|(x, y)| x.is_finite() && y.is_finite());
+        let increasing = points.windows(2).all(|pair| pair[0].0 < pair[1].0);
+        if points.is_empty() || !finite || !increasing {
+            return None;
+        }
+        Some(PiecewiseLinear { points })
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Closed interval over which function is defined
+    ///
+    pub fn domain(&self) -> Interval {
+        let first = self.points[0].0;
+        let last = self.points[self.points.len() - 1].0;
+        Interval::new(Closed(first), Closed(last))
+    }
+
+    /// Value of function at `x`
+    ///
+    /// # Returns
+    ///
+    /// `None` if `x` lies outside of domain.
+    ///
+    pub fn eval(&self, x: f64) -> Option<f64> {
+        if !self.domain().contains(x) {
+            return None;
+        }
+        let i = self.points.partition_point(|&(px, _)| px < x);
+        if i == 0 {
+            return Some(self.points[0].1);
+        }
+        Some(interpolate(self.points[i - 1], self.points[i], x))
+    }
+
+    /// Integral of function over the part of `interval` inside domain
+    ///
+    /// Open or closed endpoints make no difference, and the function counts as zero outside of
+    /// its domain.
+    ///
+    pub fn integrate_over(&self, interval: &Interval) -> f64 {
+        let (Some(a), Some(b)) = (interval.inf(), interval.sup()) else {
+            return 0.;
+        };
+        self.points
+            .windows(2)
+            .map(|pair| {
+                let (p, q) = (pair[0], pair[1]);
+                let (lo, hi) = (a.max(p.0), b.min(q.0));
+                if lo >= hi {
+                    return 0.;
+                }
+                let (y_lo, y_hi) = (interpolate(p, q, lo), interpolate(p, q, hi));
+                (hi - lo) * (y_lo + y_hi) / 2.
+            })
+            .sum()
+    }
+
+    /// Integral of function over the part of `set` inside domain
+    ///
+    pub fn integrate_over_set(&self, set: &IntervalSet) -> f64 {
+        set.iter()
+            .map(|component| self.integrate_over(&component))
+            .sum()
+    }
+
+    /// Restriction of function to the part of domain inside `interval`
+    ///
+    /// Breakpoints are added at the endpoints of the restricted domain.
+    ///
+    /// # Returns
+    ///
+    /// `None` if `interval` does not meet domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, PiecewiseLinear, Closed, Unbound};
+    ///
+    /// let f = PiecewiseLinear::new(vec![(0., 0.), (2., 4.), (4., 0.)]).unwrap();
+    /// let g = f.clip(&Interval::new(Closed(1.), Unbound)).unwrap();
+    ///
+    /// assert_eq!(g.points(), &[(1., 2.), (2., 4.), (4., 0.)]);
+    /// ```
+    ///
+    pub fn clip(&self, interval: &Interval) -> Option<Self> {
+        let clipped = self.domain().intersection(*interval);
+        let (a, b) = (clipped.inf()?, clipped.sup()?);
+        let mut points = Vec::with_capacity(self.points.len() + 2);
+        points.push((a, self.eval(a)?));
+        points.extend(self.points.iter().copied().filter(|&(x, _)| a < x && x < b));
+        if b > a {
+            points.push((b, self.eval(b)?));
+        }
+        Some(PiecewiseLinear { points })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_new_1() {
+        assert_eq!(PiecewiseLinear::new(vec![]), None);
+        assert_eq!(PiecewiseLinear::new(vec![(0., 0.), (0., 1.)]), None);
+        assert_eq!(PiecewiseLinear::new(vec![(0., f64::NAN)]), None);
+        let f = PiecewiseLinear::new(vec![(3., 1.)]).unwrap();
+        assert_eq!(f.domain(), Interval::singleton(3.));
+        assert_eq!(f.eval(3.), Some(1.));
+        assert_eq!(f.integrate_over(&INFINITY), 0.);
+    }
+
+    #[test]
+    fn test_eval_1() {
+        let f = PiecewiseLinear::new(vec![(-1., 2.), (1., 0.), (3., 1.)]).unwrap();
+        assert_eq!(f.eval(-1.), Some(2.));
+        assert_eq!(f.eval(0.), Some(1.));
+        assert_eq!(f.eval(1.), Some(0.));
+        assert_eq!(f.eval(2.), Some(0.5));
+        assert_eq!(f.eval(3.), Some(1.));
+        assert_eq!(f.eval(3.5), None);
+        assert_eq!(f.eval(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_integrate_over_1() {
+        let f = PiecewiseLinear::new(vec![(0., 0.), (2., 2.), (4., 0.)]).unwrap();
+        assert_eq!(f.integrate_over(&INFINITY), 4.);
+        assert_eq!(f.integrate_over(&Interval::new(Open(1.), Open(3.))), 3.);
+        assert_eq!(f.integrate_over(&Interval::new(Unbound, Closed(1.))), 0.5);
+        assert_eq!(f.integrate_over(&EMPTY), 0.);
+        assert_eq!(f.integrate_over(&Interval::singleton(2.)), 0.);
+    }
+
+    #[test]
+    fn test_clip_1() {
+        let f = PiecewiseLinear::new(vec![(0., 0.), (2., 2.), (4., 0.)]).unwrap();
+        let g = f.clip(&Interval::new(Open(1.), Open(3.))).unwrap();
+        assert_eq!(g.points(), &[(1., 1.), (2., 2.), (3., 1.)]);
+        assert_eq!(f.clip(&INFINITY), Some(f.clone()));
+        assert_eq!(
+            f.clip(&Interval::singleton(4.)).unwrap().points(),
+            &[(4., 0.)]
+        );
+        assert_eq!(f.clip(&Interval::new(Open(4.), Unbound)), None);
+    }
+}
@@ -0,0 +1,247 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{Bound, Interval, IntervalBox, IntervalSet, Open, SetOp, Unbound};
+
+/// Union of axis-aligned rectangles of the plane
+///
+/// The set is stored as sorted, disjoint x-slabs, each one carrying the set of ordinates covered
+/// over the whole slab. Consecutive adjacent slabs always carry distinct sets, so that equal sets
+/// have equal representations. Set operations overlay the slabs of both operands and combine
+/// their ordinates slab by slab, in `O(n + m)` slab merges.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalBox, RectSet, Closed, Open};
+///
+/// // Square [a,b) × [a,b)
+/// let square = |a: f64, b: f64| {
+///     let side = Interval::new(Closed(a), Open(b));
+///     IntervalBox::new([side, side])
+/// };
+///
+/// let a = RectSet::from_boxes([square(0., 2.), square(1., 3.)]);
+///
+/// assert_eq!(a.area(), 7.);
+/// assert!(a.contains([2.5, 2.5]));
+/// assert!(!a.contains([2.5, 0.5]));
+///
+/// let b = RectSet::from(square(0., 3.));
+/// assert_eq!(b.difference(&a).area(), 2.);
+/// assert_eq!(b.intersection(&a), a);
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RectSet {
+    slabs: Vec<(Interval, IntervalSet)>,
+}
+
+/// Elementary pieces of the x-axis cut at given sorted distinct values: gaps and single values
+pub(crate) fn pieces(values: &[f64]) -> impl Iterator<Item = Interval> + '_ {
+    let inner = values.iter().enumerate().flat_map(move |(i, &v)| {
+        let left = if i == 0 { Unbound } else { Open(values[i - 1]) };
+        [Interval::new(left, Open(v)), Interval::singleton(v)]
+    });
+    let last = match values.last() {
+        Some(&v) => Interval::new(Open(v), Unbound),
+        None => Interval::new(Unbound, Unbound),
+    };
+    inner.chain([last]).filter(|piece| !piece.is_empty())
+}
+
+/// Cursor over sorted slabs, looking up the ordinates of increasing pieces
+struct Lookup<'a> {
+    slabs: &'a [(Interval, IntervalSet)],
+    empty: &'a IntervalSet,
+}
+
+impl<'a> Lookup<'a> {
+    fn ordinates(&mut self, piece: &Interval) -> &'a IntervalSet {
+        while let Some(((x, _), rest)) = self.slabs.split_first() {
+            if !x.certainly_lt(piece) {
+                break;
+            }
+            self.slabs = rest;
+        }
+        match self.slabs.first() {
+            Some((x, ys)) if !x.intersection(*piece).is_empty() => ys,
+            _ => self.empty,
+        }
+    }
+}
+
+impl RectSet {
+    pub fn new() -> Self {
+        RectSet { slabs: Vec::new() }
+    }
+
+    /// Union of any number of rectangles
+    ///
+    pub fn from_boxes(boxes: impl IntoIterator<Item = IntervalBox<2>>) -> Self {
+        boxes
+            .into_iter()
+            .fold(RectSet::new(), |set, rect| set.union(&RectSet::from(rect)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slabs.is_empty()
+    }
+
+    /// Iterate over x-slabs in increasing order, with the ordinates covered over each of them
+    ///
+    pub fn slabs(&self) -> impl Iterator<Item = (Interval, &IntervalSet)> {
+        self.slabs.iter().map(|(x, ys)| (*x, ys))
+    }
+
+    /// Check if set contains point `[x, y]`
+    ///
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        let [x, y] = point;
+        let i = self
+            .slabs
+            .partition_point(|(sx, _)| sx.certainly_lt_scalar(x));
+        match self.slabs.get(i) {
+            Some((sx, ys)) => sx.contains(x) && ys.contains(y),
+            None => false,
+        }
+    }
+
+    /// Area of set
+    ///
+    /// Flat slabs (single abscissa or measure-zero ordinates) count for nothing, even if
+    /// unbounded.
+    ///
+    pub fn area(&self) -> f64 {
+        self.slabs
+            .iter()
+            .map(|(x, ys)| (x.width(), ys.measure()))
+            .filter(|(width, height)| *width != 0. && *height != 0.)
+            .map(|(width, height)| width * height)
+            .sum()
+    }
+
+    pub fn union(&self, other: &RectSet) -> Self {
+        self.merge_with(other, SetOp::Union)
+    }
+
+    pub fn intersection(&self, other: &RectSet) -> Self {
+        self.merge_with(other, SetOp::Intersection)
+    }
+
+    pub fn difference(&self, other: &RectSet) -> Self {
+        self.merge_with(other, SetOp::Difference)
+    }
+
+    /// Combine two sets slab by slab with set operation `op`
+    ///
+    pub fn merge_with(&self, other: &RectSet, op: SetOp) -> Self {
+        let mut values: Vec<f64> = [self, other]
+            .iter()
+            .flat_map(|set| set.slabs.iter())
+            .flat_map(|(x, _)| [x.left(), x.right()])
+            .filter_map(Bound::value)
+            .collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values.dedup();
+
+        let empty = IntervalSet::new();
+        let mut a = Lookup {
+            slabs: &self.slabs,
+            empty: &empty,
+        };
+        let mut b = Lookup {
+            slabs: &other.slabs,
+            empty: &empty,
+        };
+
+        let mut slabs: Vec<(Interval, IntervalSet)> = Vec::new();
+        let mut extend_last = false;
+        for piece in pieces(&values) {
+            let ys = a.ordinates(&piece).merge_with(b.ordinates(&piece), op);
+            if ys.is_empty() {
+                extend_last = false;
+                continue;
+            }
+            match slabs.last_mut() {
+                Some((x, last)) if extend_last && *last == ys => *x = x.hull(piece),
+                _ => slabs.push((piece, ys)),
+            }
+            extend_last = true;
+        }
+        RectSet { slabs }
+    }
+}
+
+impl From<IntervalBox<2>> for RectSet {
+    fn from(rect: IntervalBox<2>) -> Self {
+        if rect.is_empty() {
+            return RectSet::new();
+        }
+        let [x, y] = *rect.axes();
+        RectSet {
+            slabs: vec![(x, IntervalSet::from(&[y]))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, EMPTY, INFINITY};
+
+    fn rect(x: Interval, y: Interval) -> RectSet {
+        RectSet::from(IntervalBox::new([x, y]))
+    }
+
+    #[test]
+    fn test_union_1() {
+        let a = rect(
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(0.), Closed(1.)),
+        );
+        let b = rect(
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(0.), Closed(1.)),
+        );
+        assert_eq!(
+            a.union(&b),
+            rect(
+                Interval::new(Closed(0.), Closed(2.)),
+                Interval::new(Closed(0.), Closed(1.))
+            )
+        );
+        assert_eq!(a.union(&b).slabs().count(), 1);
+        assert!(RectSet::from(IntervalBox::new([INFINITY, EMPTY])).is_empty());
+    }
+
+    #[test]
+    fn test_difference_1() {
+        let a = rect(
+            Interval::new(Closed(0.), Closed(3.)),
+            Interval::new(Closed(0.), Closed(3.)),
+        );
+        let hole = rect(
+            Interval::new(Open(1.), Open(2.)),
+            Interval::new(Open(1.), Open(2.)),
+        );
+        let d = a.difference(&hole);
+        assert_eq!(d.area(), 8.);
+        assert!(d.contains([1., 1.5]));
+        assert!(!d.contains([1.5, 1.5]));
+        assert!(d.contains([1.5, 2.]));
+        assert_eq!(d.slabs().count(), 3);
+        assert_eq!(d.union(&hole), a);
+        assert!(d.intersection(&hole).is_empty());
+    }
+
+    #[test]
+    fn test_area_1() {
+        let line = rect(Interval::singleton(0.), INFINITY);
+        assert_eq!(line.area(), 0.);
+        assert!(line.contains([0., -1e300]));
+        let half_plane = rect(Interval::new(Closed(0.), Unbound), INFINITY);
+        assert_eq!(half_plane.area(), f64::INFINITY);
+        assert_eq!(RectSet::new().area(), 0.);
+    }
+}
@@ -0,0 +1,263 @@
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// Set of integer ids stored as sorted runs of consecutive values
+///
+/// Dense ranges of ids cost one run each, whatever their length. Runs are disjoint and never
+/// adjacent, so that the representation of a set is unique. Cumulative counts are kept along
+/// the runs, so that `rank` and `select` run in `O(log r)` for `r` runs.
+///
+/// # Example
+///
+/// ```
+/// use interval::RunSet;
+///
+/// let mut ids = RunSet::new();
+/// for id in (0..1000).chain(5000..6000) {
+///     ids.push(id);
+/// }
+///
+/// assert_eq!(ids.runs().count(), 2);
+/// assert_eq!(ids.len(), 2000);
+/// assert!(ids.contains(5500));
+/// assert_eq!(ids.rank(5000), 1001);
+/// assert_eq!(ids.select(1000), Some(5000));
+/// ```
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RunSet {
+    /// Sorted, disjoint and non adjacent inclusive runs `(first, last)`
+    runs: Vec<(i64, i64)>,
+    /// Number of ids in the runs before run `i`
+    before: Vec<u64>,
+}
+
+/// Number of ids in an inclusive run
+fn run_len((first, last): (i64, i64)) -> u64 {
+    last.abs_diff(first) + 1
+}
+
+impl RunSet {
+    pub fn new() -> Self {
+        RunSet {
+            runs: Vec::new(),
+            before: Vec::new(),
+        }
+    }
+
+    /// Number of ids in set
+    ///
+    pub fn len(&self) -> u64 {
+        match (self.runs.last(), self.before.last()) {
+            (Some(run), Some(before)) => before + run_len(*run),
+            _ => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    /// Iterate over runs of consecutive ids, as inclusive `(first, last)` pairs
+    ///
+    pub fn runs(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.runs.iter().copied()
+    }
+
+    /// Append an id not lower than every id of set, in `O(1)` amortized
+    ///
+    /// # Returns
+    ///
+    /// `false` (leaving set unchanged) if `id` is lower than the largest id of set; use
+    /// `insert` for arbitrary ids.
+    ///
+    pub fn push(&mut self, id: i64) -> bool {
+        match self.runs.last_mut() {
+            Some((_, last)) if id <= *last => {
+                return id == *last;
+            }
+            Some((_, last)) if id - 1 == *last => *last = id,
+            _ => {
+                self.before.push(self.len());
+                self.runs.push((id, id));
+            }
+        }
+        true
+    }
+
+    /// Insert any id, in `O(r)` for `r` runs
+    ///
+    /// # Returns
+    ///
+    /// `true` if `id` was not already in set.
+    ///
+    pub fn insert(&mut self, id: i64) -> bool {
+        let i = self.runs.partition_point(|(_, last)| *last < id);
+        match self.runs.get(i) {
+            Some((first, _)) if *first <= id => return false,
+            _ => {}
+        }
+        let joins_prev = i > 0 && self.runs[i - 1].1 == id - 1;
+        let joins_next = i < self.runs.len() && self.runs[i].0 == id + 1;
+        match (joins_prev, joins_next) {
+            (true, true) => {
+                self.runs[i - 1].1 = self.runs[i].1;
+                self.runs.remove(i);
+            }
+            (true, false) => self.runs[i - 1].1 = id,
+            (false, true) => self.runs[i].0 = id,
+            (false, false) => self.runs.insert(i, (id, id)),
+        }
+        self.recount();
+        true
+    }
+
+    /// Rebuild cumulative counts after an edit
+    fn recount(&mut self) {
+        let mut total = 0;
+        self.before = self
+            .runs
+            .iter()
+            .map(|run| {
+                let before = total;
+                total += run_len(*run);
+                before
+            })
+            .collect();
+    }
+
+    pub fn contains(&self, id: i64) -> bool {
+        let i = self.runs.partition_point(|(_, last)| *last < id);
+        self.runs.get(i).is_some_and(|(first, _)| *first <= id)
+    }
+
+    /// Number of ids of set lower than or equal to `id`
+    ///
+    pub fn rank(&self, id: i64) -> u64 {
+        let i = self.runs.partition_point(|(_, last)| *last < id);
+        match self.runs.get(i) {
+            Some((first, _)) if *first <= id => self.before[i] + id.abs_diff(*first) + 1,
+            Some(_) => self.before[i],
+            None => self.len(),
+        }
+    }
+
+    /// Id of set with `n` lower ids (ie. the `n`-th smallest one, counting from 0)
+    ///
+    /// # Returns
+    ///
+    /// `None` if set holds `n` ids or less.
+    ///
+    pub fn select(&self, n: u64) -> Option<i64> {
+        if n >= self.len() {
+            return None;
+        }
+        let i = self.before.partition_point(|before| *before <= n) - 1;
+        let (first, _) = self.runs[i];
+        Some(first.wrapping_add_unsigned(n - self.before[i]))
+    }
+
+    /// Ids belonging to both sets, in a single pass over both lists of runs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::RunSet;
+    ///
+    /// let a: RunSet = (0..10).chain(20..30).collect();
+    /// let b: RunSet = (5..25).collect();
+    ///
+    /// let both: Vec<(i64, i64)> = a.intersection(&b).runs().collect();
+    /// assert_eq!(both, vec![(5, 9), (20, 24)]);
+    /// ```
+    ///
+    pub fn intersection(&self, other: &RunSet) -> RunSet {
+        let mut res = RunSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.runs.len() && j < other.runs.len() {
+            let ((a1, a2), (b1, b2)) = (self.runs[i], other.runs[j]);
+            let (first, last) = (a1.max(b1), a2.min(b2));
+            if first <= last {
+                res.before.push(res.len());
+                res.runs.push((first, last));
+            }
+            if a2 < b2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        res
+    }
+}
+
+impl FromIterator<i64> for RunSet {
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        let mut res = RunSet::new();
+        for id in iter {
+            if !res.push(id) {
+                res.insert(id);
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_push_1() {
+        let mut a = RunSet::new();
+        assert!(a.push(3));
+        assert!(a.push(3));
+        assert!(a.push(4));
+        assert!(a.push(10));
+        assert!(!a.push(5));
+        assert_eq!(a.runs().collect::<Vec<_>>(), vec![(3, 4), (10, 10)]);
+        assert_eq!(a.len(), 3);
+    }
+
+    #[test]
+    fn test_insert_1() {
+        let mut a: RunSet = [1, 3, 7].into_iter().collect();
+        assert!(a.insert(2));
+        assert!(!a.insert(2));
+        assert!(a.insert(6));
+        assert!(a.insert(-1));
+        assert_eq!(a.runs().collect::<Vec<_>>(), vec![(-1, -1), (1, 3), (6, 7)]);
+        assert_eq!(a.len(), 6);
+        assert_eq!(a, [-1, 1, 2, 3, 6, 7].into_iter().collect());
+    }
+
+    #[test]
+    fn test_rank_select_1() {
+        let a: RunSet = (0..5).chain(10..15).chain([i64::MAX]).collect();
+        assert_eq!(a.rank(-1), 0);
+        assert_eq!(a.rank(0), 1);
+        assert_eq!(a.rank(7), 5);
+        assert_eq!(a.rank(12), 8);
+        assert_eq!(a.rank(i64::MAX), 11);
+        for n in 0..a.len() {
+            let id = a.select(n).unwrap();
+            assert!(a.contains(id));
+            assert_eq!(a.rank(id), n + 1);
+        }
+        assert_eq!(a.select(11), None);
+        assert_eq!(RunSet::new().select(0), None);
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        let a: RunSet = (0..10).chain(20..30).chain(40..50).collect();
+        let b: RunSet = (9..21).chain(29..41).collect();
+        let both = a.intersection(&b);
+        assert_eq!(
+            both.runs().collect::<Vec<_>>(),
+            vec![(9, 9), (20, 20), (29, 29), (40, 40)]
+        );
+        assert_eq!(both.len(), 4);
+        assert!(a.intersection(&RunSet::new()).is_empty());
+    }
+}
@@ -0,0 +1,310 @@
+//! Algorithms over collections of intervals
+//!
+//! Open and closed endpoints are honoured: `[0,1)` and `[1,2]` do not overlap, while `[0,1]` and
+//! `[1,2]` share point `1`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::{Interval, Left, Right};
+
+/// Largest set of pairwise disjoint intervals
+///
+/// Classic earliest-finish greedy algorithm: intervals are scanned by increasing right endpoint,
+/// and each one is kept if it lies entirely after the last kept one. Runs in `O(n log n)`.
+/// Empty intervals are never selected.
+///
+/// # Returns
+///
+/// Indices in `intervals` of the selected intervals, by increasing right endpoint.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed, Open};
+/// use interval::scheduling::select_max_disjoint;
+///
+/// let meetings = [
+///     Interval::new(Closed(9.), Open(12.)),
+///     Interval::new(Closed(9.), Open(10.)),
+///     Interval::new(Closed(10.), Open(11.)),
+///     Interval::new(Closed(11.), Closed(12.)),
+/// ];
+///
+/// assert_eq!(select_max_disjoint(&meetings), vec![1, 2, 3]);
+/// ```
+///
+pub fn select_max_disjoint(intervals: &[Interval]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..intervals.len())
+        .filter(|&i| !intervals[i].is_empty())
+        .collect();
+    // `k)` comes before `k]`
+    order.sort_by(|&i, &j| {
+        let (a, b) = (Right(intervals[i].right()), Right(intervals[j].right()));
+        a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+    });
+
+    let mut selected: Vec<usize> = Vec::new();
+    for i in order {
+        match selected.last() {
+            Some(&last) if !intervals[last].certainly_lt(&intervals[i]) => {}
+            _ => selected.push(i),
+        }
+    }
+    selected
+}
+
+/// Smallest set of points hitting every interval
+///
+/// Greedy algorithm: intervals are scanned by increasing right endpoint, and whenever one is not
+/// hit by the last chosen point, its largest point is chosen. For a closed right endpoint `k]`
+/// this is `k` itself; for an open one `k)` it is the largest `f64` below `k`; for an unbounded
+/// interval it is `f64::MAX`. Runs in `O(n log n)`.
+///
+/// Intervals holding no representable `f64` (such as `EMPTY`, or `(k, k.next_up())`) cannot be
+/// hit and are ignored.
+///
+/// # Returns
+///
+/// Chosen points, in increasing order.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed, Open};
+/// use interval::scheduling::min_piercing_points;
+///
+/// let windows = [
+///     Interval::new(Closed(0.), Closed(2.)),
+///     Interval::new(Closed(1.), Closed(3.)),
+///     Interval::new(Open(2.), Open(4.)),
+///     Interval::new(Closed(3.5), Closed(5.)),
+/// ];
+///
+/// assert_eq!(min_piercing_points(&windows), vec![2., 4f64.next_down()]);
+/// ```
+///
+pub fn min_piercing_points(intervals: &[Interval]) -> Vec<f64> {
+    let mut candidates: Vec<(f64, &Interval)> = intervals
+        .iter()
+        .filter_map(|segment| segment.clamp(f64::MAX).map(|x| (x, segment)))
+        .collect();
+    candidates.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+
+    let mut points: Vec<f64> = Vec::new();
+    for (x, segment) in candidates {
+        match points.last() {
+            Some(&last) if segment.contains(last) => {}
+            _ => points.push(x),
+        }
+    }
+    points
+}
+
+/// Edges of the interval graph: pairs of intervals sharing at least one point
+///
+/// Computed by a sweep over intervals sorted by left endpoint, keeping the set of intervals not
+/// yet ended: `O(n log n + k)` for `k` edges, instead of comparing all pairs. Empty intervals
+/// overlap nothing.
+///
+/// # Returns
+///
+/// Pairs of indices `(i, j)` with `i < j`, in lexicographic order.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed, Open};
+/// use interval::scheduling::interval_graph;
+///
+/// let intervals = [
+///     Interval::new(Closed(0.), Closed(2.)),
+///     Interval::new(Closed(1.), Open(3.)),
+///     Interval::new(Closed(3.), Closed(4.)),
+///     Interval::new(Closed(2.), Closed(5.)),
+/// ];
+///
+/// assert_eq!(interval_graph(&intervals), vec![(0, 1), (0, 3), (1, 3), (2, 3)]);
+/// ```
+///
+pub fn interval_graph(intervals: &[Interval]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..intervals.len())
+        .filter(|&i| !intervals[i].is_empty())
+        .collect();
+    order.sort_by(|&i, &j| {
+        let (a, b) = (Left(intervals[i].left()), Left(intervals[j].left()));
+        a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+    });
+
+    let mut edges = Vec::new();
+    let mut active: Vec<usize> = Vec::new();
+    for i in order {
+        active.retain(|&j| !intervals[j].certainly_lt(&intervals[i]));
+        edges.extend(active.iter().map(|&j| (i.min(j), i.max(j))));
+        active.push(i);
+    }
+    edges.sort();
+    edges
+}
+
+/// Group payloads whose intervals transitively overlap or are adjacent
+///
+/// Two items belong to the same cluster if there is a chain of items between them, each one
+/// overlapping or adjacent to the next (ie. their union is a single interval). Items with an
+/// empty interval belong to no cluster and are dropped.
+///
+/// # Returns
+///
+/// Clusters sorted by position, each one with the hull of its intervals and its payloads ordered
+/// by left endpoint.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed, Open};
+/// use interval::scheduling::cluster_overlapping;
+///
+/// let bookings = [
+///     (Interval::new(Closed(14.), Open(16.)), "carol"),
+///     (Interval::new(Closed(9.), Open(11.)), "alice"),
+///     (Interval::new(Closed(10.), Open(12.)), "bob"),
+///     (Interval::new(Closed(12.), Open(13.)), "dave"),
+/// ];
+///
+/// assert_eq!(
+///     cluster_overlapping(bookings),
+///     vec![
+///         (Interval::new(Closed(9.), Open(13.)), vec!["alice", "bob", "dave"]),
+///         (Interval::new(Closed(14.), Open(16.)), vec!["carol"]),
+///     ]
+/// );
+/// ```
+///
+pub fn cluster_overlapping<T>(
+    items: impl IntoIterator<Item = (Interval, T)>,
+) -> Vec<(Interval, Vec<T>)> {
+    let mut items: Vec<(Interval, T)> = items
+        .into_iter()
+        .filter(|(segment, _)| !segment.is_empty())
+        .collect();
+    items.sort_by(|(a, _), (b, _)| {
+        Left(a.left())
+            .partial_cmp(&Left(b.left()))
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut clusters: Vec<(Interval, Vec<T>)> = Vec::new();
+    for (segment, payload) in items {
+        if let Some((hull, payloads)) = clusters.last_mut() {
+            if let (merged, None) = hull.union(segment) {
+                *hull = merged;
+                payloads.push(payload);
+                continue;
+            }
+        }
+        clusters.push((segment, vec![payload]));
+    }
+    clusters
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_select_max_disjoint_1() {
+        assert!(select_max_disjoint(&[]).is_empty());
+        assert!(select_max_disjoint(&[EMPTY]).is_empty());
+        assert_eq!(select_max_disjoint(&[EMPTY, INFINITY]), vec![1]);
+    }
+
+    #[test]
+    fn test_select_max_disjoint_2() {
+        let intervals = [
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::new(Closed(2.), Unbound),
+        ];
+        assert_eq!(select_max_disjoint(&intervals), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_select_max_disjoint_3() {
+        let intervals = [
+            Interval::new(Unbound, Closed(0.)),
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Open(0.), Open(1.)),
+            Interval::singleton(1.),
+            Interval::new(Open(1.), Unbound),
+        ];
+        assert_eq!(select_max_disjoint(&intervals), vec![0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_min_piercing_points_1() {
+        assert!(min_piercing_points(&[]).is_empty());
+        assert!(
+            min_piercing_points(&[EMPTY, Interval::new(Open(1.), Open(1f64.next_up()))]).is_empty()
+        );
+        assert_eq!(min_piercing_points(&[INFINITY]), vec![f64::MAX]);
+    }
+
+    #[test]
+    fn test_min_piercing_points_2() {
+        let intervals = [
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Unbound, Closed(0.)),
+            Interval::new(Closed(1.5), Unbound),
+            Interval::new(Closed(5.), Unbound),
+        ];
+        assert_eq!(min_piercing_points(&intervals), vec![0., 2., f64::MAX]);
+    }
+
+    #[test]
+    fn test_interval_graph_1() {
+        assert!(interval_graph(&[]).is_empty());
+        assert!(interval_graph(&[EMPTY, EMPTY, INFINITY]).is_empty());
+        assert_eq!(interval_graph(&[INFINITY, INFINITY]), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_interval_graph_2() {
+        let intervals = [
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::singleton(1.),
+            Interval::new(Unbound, Closed(1.)),
+            Interval::new(Closed(2.), Unbound),
+        ];
+        assert_eq!(interval_graph(&intervals), vec![(0, 4), (1, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn test_cluster_overlapping_1() {
+        assert!(cluster_overlapping(Vec::<(Interval, ())>::new()).is_empty());
+        assert!(cluster_overlapping([(EMPTY, 0)]).is_empty());
+    }
+
+    #[test]
+    fn test_cluster_overlapping_2() {
+        let items = [
+            (Interval::new(Open(1.), Closed(2.)), 0),
+            (Interval::new(Closed(0.), Open(1.)), 1),
+            (Interval::new(Open(2.), Closed(3.)), 2),
+            (Interval::new(Unbound, Open(-1.)), 3),
+            (Interval::singleton(-1.), 4),
+        ];
+        assert_eq!(
+            cluster_overlapping(items),
+            vec![
+                (Interval::new(Unbound, Closed(-1.)), vec![3, 4]),
+                (Interval::new(Closed(0.), Open(1.)), vec![1]),
+                (Interval::new(Open(1.), Closed(3.)), vec![0, 2]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use alloc::vec::Vec;
+
+use super::rect_set::pieces;
+use super::{Closed, Interval, IntervalMap, Open, Unbound};
+
+/// Total function of the real line, constant on intervals
+///
+/// The function takes the value of the piece containing a point, or a default value outside of
+/// every piece. Pieces are disjoint intervals, as in an `IntervalMap`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, StepFunction, Closed, Open, Unbound};
+///
+/// // Unit price by quantity
+/// let price = StepFunction::from_pieces(
+///     [
+///         (Interval::new(Closed(100.), Open(1000.)), 9),
+///         (Interval::new(Closed(1000.), Unbound), 8),
+///     ],
+///     10,
+/// );
+/// // Discount for members
+/// let discount = StepFunction::from_pieces([(Interval::new(Closed(500.), Unbound), 1)], 0);
+///
+/// let member_price = price.combine(&discount, |p, d| p - d);
+/// assert_eq!(*member_price.eval(50.), 10);
+/// assert_eq!(*member_price.eval(600.), 8);
+/// assert_eq!(*member_price.eval(1000.), 7);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepFunction<V> {
+    pieces: IntervalMap<V>,
+    default: V,
+}
+
+/// Point of a non-empty elementary piece (gap between cuts, or single cut)
+///
+/// Unbounded pieces are sampled next to their finite endpoint, as `a + 1.` would round back to
+/// `a` for large endpoints.
+fn representative(piece: &Interval) -> f64 {
+    match (piece.left(), piece.right()) {
+        (Closed(_) | Open(_), Closed(_) | Open(_)) => piece.midpoint().unwrap_or(0.),
+        (Closed(a) | Open(a), Unbound) => a.next_up(),
+        (Unbound, Closed(b) | Open(b)) => b.next_down(),
+        (Unbound, Unbound) => 0.,
+    }
+}
+
+impl<V> StepFunction<V> {
+    /// Constant function
+    ///
+    pub fn new(default: V) -> Self {
+        StepFunction {
+            pieces: IntervalMap::new(),
+            default,
+        }
+    }
+
+    /// Function taking given values on given pieces, and `default` elsewhere
+    ///
+    /// Overlapping pieces are resolved in favor of the last one.
+    ///
+    pub fn from_pieces(pieces: impl IntoIterator<Item = (Interval, V)>, default: V) -> Self
+    where
+        V: Clone,
+    {
+        let mut function = StepFunction::new(default);
+        for (piece, value) in pieces {
+            function.set(piece, value);
+        }
+        function
+    }
+
+    /// Value outside of every piece
+    ///
+    pub fn default_value(&self) -> &V {
+        &self.default
+    }
+
+    /// Iterate over pieces and their values, in increasing order
+    ///
+    pub fn pieces(&self) -> impl Iterator<Item = (&Interval, &V)> {
+        self.pieces.iter()
+    }
+
+    /// Value at point `x`
+    ///
+    pub fn eval(&self, x: f64) -> &V {
+        self.pieces.get(x).unwrap_or(&self.default)
+    }
+
+    /// Set value on `interval`, overwriting previous values there
+    ///
+    pub fn set(&mut self, interval: Interval, value: V)
+    where
+        V: Clone,
+    {
+        self.pieces.insert(interval, value);
+    }
+
+    /// Merge adjacent pieces of equal values, and drop pieces taking the default value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, StepFunction, Closed, Open};
+    ///
+    /// let mut f = StepFunction::from_pieces(
+    ///     [
+    ///         (Interval::new(Closed(0.), Open(1.)), 'a'),
+    ///         (Interval::new(Closed(1.), Open(2.)), 'a'),
+    ///         (Interval::new(Closed(2.), Open(3.)), '-'),
+    ///     ],
+    ///     '-',
+    /// );
+    /// f.simplify();
+    ///
+    /// let pieces: Vec<(Interval, char)> = f.pieces().map(|(k, v)| (*k, *v)).collect();
+    /// assert_eq!(pieces, vec![(Interval::new(Closed(0.), Open(2.)), 'a')]);
+    /// ```
+    ///
+    pub fn simplify(&mut self)
+    where
+        V: PartialEq,
+    {
+        let old = core::mem::take(&mut self.pieces);
+        let mut merged: Vec<(Interval, V)> = Vec::new();
+        for (piece, value) in old.into_entries() {
+            if value == self.default {
+                continue;
+            }
+            match merged.last_mut() {
+                Some((last, last_value)) if *last_value == value && last.is_adjacent(&piece) => {
+                    *last = last.hull(piece);
+                }
+                _ => merged.push((piece, value)),
+            }
+        }
+        for (piece, value) in merged {
+            // pieces are sorted and disjoint: insertion cannot fail
+            let _ = self.pieces.try_insert(piece, value);
+        }
+    }
+
+    /// Pointwise combination `x ↦ f(self(x), other(x))`, simplified
+    ///
+    pub fn combine<W, U, F>(&self, other: &StepFunction<W>, f: F) -> StepFunction<U>
+    where
+        F: Fn(&V, &W) -> U,
+        U: PartialEq,
+    {
+        let mut cuts: Vec<f64> = self
+            .pieces()
+            .map(|(piece, _)| piece)
+            .chain(other.pieces().map(|(piece, _)| piece))
+            .flat_map(|piece| [piece.left().value(), piece.right().value()])
+            .flatten()
+            .collect();
+        cuts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        cuts.dedup();
+
+        let mut res = StepFunction::new(f(&self.default, &other.default));
+        let mut merged: Vec<(Interval, U)> = Vec::new();
+        for piece in pieces(&cuts) {
+            let x = representative(&piece);
+            let value = f(self.eval(x), other.eval(x));
+            match merged.last_mut() {
+                Some((last, last_value)) if *last_value == value => *last = last.hull(piece),
+                _ => merged.push((piece, value)),
+            }
+        }
+        for (piece, value) in merged {
+            if value != res.default {
+                let _ = res.pieces.try_insert(piece, value);
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::INFINITY;
+
+    #[test]
+    fn test_eval_1() {
+        let mut f = StepFunction::new(0);
+        f.set(Interval::new(Closed(0.), Closed(10.)), 1);
+        f.set(Interval::new(Open(2.), Open(3.)), 2);
+        assert_eq!(*f.eval(-1.), 0);
+        assert_eq!(*f.eval(2.), 1);
+        assert_eq!(*f.eval(2.5), 2);
+        assert_eq!(*f.eval(3.), 1);
+        assert_eq!(*f.eval(10.5), 0);
+        assert_eq!(f.pieces().count(), 3);
+    }
+
+    #[test]
+    fn test_combine_1() {
+        let a = StepFunction::from_pieces([(Interval::new(Closed(0.), Open(2.)), 1)], 0);
+        let b = StepFunction::from_pieces([(Interval::new(Closed(1.), Closed(3.)), 1)], 0);
+        let sum = a.combine(&b, |x, y| x + y);
+        let pieces: Vec<(Interval, i32)> = sum.pieces().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(
+            pieces,
+            vec![
+                (Interval::new(Closed(0.), Open(1.)), 1),
+                (Interval::new(Closed(1.), Open(2.)), 2),
+                (Interval::new(Closed(2.), Closed(3.)), 1),
+            ]
+        );
+        let overlap = a.combine(&b, |x, y| x * y);
+        assert_eq!(overlap.pieces().count(), 1);
+        assert_eq!(*overlap.eval(1.), 1);
+        assert_eq!(*overlap.eval(2.), 0);
+    }
+
+    #[test]
+    fn test_combine_2() {
+        let a = StepFunction::from_pieces([(INFINITY, true)], false);
+        let b = StepFunction::from_pieces([(Interval::singleton(0.), true)], false);
+        let c = a.combine(&b, |x, y| x & !y);
+        assert!(*c.eval(-1e300) && *c.eval(1.) && !*c.eval(0.));
+        assert_eq!(c.pieces().count(), 2);
+        let constant = StepFunction::<u8>::new(3).combine(&StepFunction::new(4), |x, y| x * y);
+        assert_eq!(constant, StepFunction::new(12));
+    }
+
+    #[test]
+    fn test_combine_3() {
+        // Cuts beyond 2^53, where `a + 1.` rounds back to `a`
+        for k in [1e20, -1e20, f64::MAX / 2.] {
+            let a = StepFunction::from_pieces([(Interval::singleton(k), 1)], 0);
+            let sum = a.combine(&StepFunction::new(0), |x, y| x + y);
+            assert_eq!(*sum.eval(k), 1);
+            for x in [0., 10. * k, k.next_up(), k.next_down()] {
+                assert_eq!(*sum.eval(x), 0, "{k} {x}");
+            }
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! WebAssembly bindings
+//!
+//! `wasm_bindgen` wrappers exported to JavaScript as `Interval` and `IntervalSet`, so that web
+//! front-ends share the semantics of the Rust types. Unbound endpoints are given as `undefined`
+//! from JavaScript.
+//!
+//! ```js
+//! const a = Interval.parse("[0,2)");
+//! const b = new Interval(1, true, undefined, false);
+//! a.intersection(b).toString(); // "[ 1.00, 2.00)"
+//! ```
+
+use alloc::string::{String, ToString};
+use wasm_bindgen::prelude::*;
+
+use super::{Bound, Closed, Interval, IntervalSet, Open, Unbound, EMPTY, INFINITY};
+
+fn bound(value: Option<f64>, closed: bool) -> Bound {
+    match value {
+        None => Unbound,
+        Some(k) if closed => Closed(k),
+        Some(k) => Open(k),
+    }
+}
+
+/// JavaScript handle on an `Interval`
+///
+#[wasm_bindgen(js_name = Interval)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JsInterval(Interval);
+
+#[wasm_bindgen(js_class = Interval)]
+impl JsInterval {
+    /// Build interval from endpoints (see `Interval::new`), `undefined` meaning unbound
+    ///
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        left: Option<f64>,
+        left_closed: bool,
+        right: Option<f64>,
+        right_closed: bool,
+    ) -> JsInterval {
+        JsInterval(Interval::new(
+            bound(left, left_closed),
+            bound(right, right_closed),
+        ))
+    }
+
+    pub fn singleton(k: f64) -> JsInterval {
+        JsInterval(Interval::singleton(k))
+    }
+
+    pub fn empty() -> JsInterval {
+        JsInterval(EMPTY)
+    }
+
+    pub fn infinity() -> JsInterval {
+        JsInterval(INFINITY)
+    }
+
+    /// Parse interval notation (see `Interval::from_str`)
+    ///
+    pub fn parse(s: &str) -> Result<JsInterval, JsError> {
+        Ok(JsInterval(s.parse()?))
+    }
+
+    /// Parse uncertainty notation (see `Interval::parse_uncertainty`)
+    ///
+    #[wasm_bindgen(js_name = parseUncertainty)]
+    pub fn parse_uncertainty(s: &str) -> Result<JsInterval, JsError> {
+        Ok(JsInterval(Interval::parse_uncertainty(s)?))
+    }
+
+    /// Left endpoint value, `undefined` if unbound
+    ///
+    #[wasm_bindgen(getter)]
+    pub fn left(&self) -> Option<f64> {
+        self.0.left().value()
+    }
+
+    #[wasm_bindgen(getter, js_name = leftClosed)]
+    pub fn left_closed(&self) -> bool {
+        matches!(self.0.left(), Closed(_))
+    }
+
+    /// Right endpoint value, `undefined` if unbound
+    ///
+    #[wasm_bindgen(getter)]
+    pub fn right(&self) -> Option<f64> {
+        self.0.right().value()
+    }
+
+    #[wasm_bindgen(getter, js_name = rightClosed)]
+    pub fn right_closed(&self) -> bool {
+        matches!(self.0.right(), Closed(_))
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.0.contains(x)
+    }
+
+    pub fn union(&self, other: &JsInterval) -> JsIntervalSet {
+        JsIntervalSet(IntervalSet::from(&[self.0, other.0]))
+    }
+
+    pub fn intersection(&self, other: &JsInterval) -> JsInterval {
+        JsInterval(self.0.intersection(other.0))
+    }
+
+    pub fn difference(&self, other: &JsInterval) -> JsIntervalSet {
+        JsIntervalSet(IntervalSet::from(&[self.0]).difference(&IntervalSet::from(&[other.0])))
+    }
+
+    pub fn equals(&self, other: &JsInterval) -> bool {
+        self.0 == other.0
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<Interval> for JsInterval {
+    fn from(interval: Interval) -> Self {
+        JsInterval(interval)
+    }
+}
+
+impl From<JsInterval> for Interval {
+    fn from(interval: JsInterval) -> Self {
+        interval.0
+    }
+}
+
+/// JavaScript handle on an `IntervalSet`
+///
+#[wasm_bindgen(js_name = IntervalSet)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsIntervalSet(IntervalSet);
+
+#[wasm_bindgen(js_class = IntervalSet)]
+impl JsIntervalSet {
+    /// Empty set
+    ///
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> JsIntervalSet {
+        JsIntervalSet(IntervalSet::new())
+    }
+
+    /// Set made of a single interval
+    ///
+    #[wasm_bindgen(js_name = fromInterval)]
+    pub fn from_interval(interval: &JsInterval) -> JsIntervalSet {
+        JsIntervalSet(IntervalSet::from(&[interval.0]))
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.0.contains(x)
+    }
+
+    pub fn measure(&self) -> f64 {
+        self.0.measure()
+    }
+
+    pub fn union(&self, other: &JsIntervalSet) -> JsIntervalSet {
+        JsIntervalSet(self.0.union_intervals(&other.0))
+    }
+
+    #[wasm_bindgen(js_name = unionInterval)]
+    pub fn union_interval(&self, interval: &JsInterval) -> JsIntervalSet {
+        JsIntervalSet(self.0.union_interval(&interval.0))
+    }
+
+    pub fn intersection(&self, other: &JsIntervalSet) -> JsIntervalSet {
+        JsIntervalSet(self.0.intersection(&other.0))
+    }
+
+    pub fn difference(&self, other: &JsIntervalSet) -> JsIntervalSet {
+        JsIntervalSet(self.0.difference(&other.0))
+    }
+
+    pub fn equals(&self, other: &JsIntervalSet) -> bool {
+        self.0 == other.0
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<IntervalSet> for JsIntervalSet {
+    fn from(set: IntervalSet) -> Self {
+        JsIntervalSet(set)
+    }
+}
+
+impl From<JsIntervalSet> for IntervalSet {
+    fn from(set: JsIntervalSet) -> Self {
+        set.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_1() {
+        let a = JsInterval::new(Some(0.), true, None, false);
+        assert_eq!(Interval::from(a), Interval::new(Closed(0.), Unbound));
+        assert_eq!((a.left(), a.left_closed()), (Some(0.), true));
+        assert_eq!((a.right(), a.right_closed()), (None, false));
+        let b = JsInterval::new(Some(1.), false, Some(0.), false);
+        assert!(b.is_empty());
+        assert!(b.equals(&JsInterval::empty()));
+    }
+
+    #[test]
+    fn test_algebra_1() {
+        let a = JsInterval::new(Some(0.), true, Some(2.), false);
+        let b = JsInterval::new(Some(1.), true, Some(3.), true);
+        assert_eq!(
+            Interval::from(a.intersection(&b)),
+            Interval::new(Closed(1.), Open(2.))
+        );
+        assert!(a
+            .union(&b)
+            .equals(&IntervalSet::from(&[Interval::new(Closed(0.), Closed(3.))]).into()));
+        let d = a.difference(&b);
+        assert!(d.contains(0.5) && !d.contains(1.));
+        assert_eq!(d.to_js_string(), "[ 0.00, 1.00)");
+    }
+}